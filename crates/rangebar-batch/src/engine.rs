@@ -3,10 +3,12 @@
 //! High-performance batch processing for historical analysis,
 //! backtesting, and research with exception-only failure handling.
 
+use crate::quantile::{P2Quantile, interpolate_sorted};
 use polars::frame::row::Row;
 use polars::prelude::*;
-use rangebar_core::RangeBar;
+use rangebar_core::{AggTrade, ProcessingError, RangeBar, RangeBarProcessor};
 use rangebar_io::formats::{ConversionError, DataFrameConverter};
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use thiserror::Error;
@@ -50,6 +52,24 @@ pub struct StatisticsConfig {
 
     /// Enable time-series resampling
     pub enable_resampling: bool,
+
+    /// Bar count up to which duration percentiles are computed exactly by sorting;
+    /// larger inputs use a constant-memory streaming estimator
+    #[serde(default = "default_exact_percentile_limit")]
+    pub exact_percentile_limit: usize,
+
+    /// Fraction of the threshold a bar may overshoot before it is reported by
+    /// [`BatchAnalysisEngine::detect_range_anomalies`] (0.5 = 1.5× threshold)
+    #[serde(default = "default_range_anomaly_tolerance")]
+    pub range_anomaly_tolerance: f64,
+}
+
+fn default_exact_percentile_limit() -> usize {
+    1_000_000
+}
+
+fn default_range_anomaly_tolerance() -> f64 {
+    0.5
 }
 
 impl Default for BatchConfig {
@@ -66,6 +86,8 @@ impl Default for BatchConfig {
                 quantile_levels: vec![0.1, 0.25, 0.5, 0.75, 0.9],
                 enable_correlations: true,
                 enable_resampling: true,
+                exact_percentile_limit: default_exact_percentile_limit(),
+                range_anomaly_tolerance: default_range_anomaly_tolerance(),
             },
         }
     }
@@ -134,6 +156,12 @@ impl BatchAnalysisEngine {
         // Microstructure analysis
         analysis_report.microstructure = self.compute_microstructure_analysis(&df)?;
 
+        // Bar duration distribution
+        analysis_report.duration_percentiles = compute_duration_percentiles(
+            range_bars,
+            self.config.statistics_config.exact_percentile_limit,
+        );
+
         Ok(BatchResult {
             symbol: symbol.to_string(),
             records_processed: range_bars.len(),
@@ -161,6 +189,93 @@ impl BatchAnalysisEngine {
         Ok(results)
     }
 
+    /// Flag bars whose move from open far exceeds the threshold they were built with
+    ///
+    /// A legitimate bar stays inside `open ± threshold` on its non-breaching side
+    /// and overshoots on the breaching side only by the final trade's gap. Bars
+    /// whose largest excursion from open exceeds
+    /// `threshold × (1 + range_anomaly_tolerance)` indicate a processing defect
+    /// (e.g. a 5.58% range at a 2.5% threshold).
+    pub fn detect_range_anomalies(
+        &self,
+        bars: &[RangeBar],
+        threshold_decimal_bps: u32,
+    ) -> Vec<AnomalyReport> {
+        let tolerance = self.config.statistics_config.range_anomaly_tolerance;
+        let expected_max_decimal_bps = threshold_decimal_bps as f64 * (1.0 + tolerance);
+
+        bars.iter()
+            .enumerate()
+            .filter_map(|(bar_index, bar)| {
+                let actual_decimal_bps = bar.max_excursion_decimal_bps();
+                (actual_decimal_bps > expected_max_decimal_bps).then(|| AnomalyReport {
+                    bar_index,
+                    expected_max_decimal_bps,
+                    actual_decimal_bps,
+                    range_decimal_bps: bar.range_decimal_bps(),
+                })
+            })
+            .collect()
+    }
+
+    /// Tag each bar with a volatility regime by tercile of its realized range
+    ///
+    /// Cutoffs are the 1/3 and 2/3 quantiles of
+    /// [`range_decimal_bps`](RangeBar::range_decimal_bps) across `bars`
+    /// (linear interpolation). Bars strictly below the lower cutoff are `Low`,
+    /// strictly above the upper cutoff `High`, and the rest `Normal`, so bars
+    /// with equal ranges always share a label and an all-equal dataset is all
+    /// `Normal`. Fewer than three bars cannot form terciles and are labeled
+    /// `Normal`.
+    pub fn label_regimes(&self, bars: &[RangeBar]) -> Vec<Regime> {
+        let ranges: Vec<f64> = bars.iter().map(RangeBar::range_decimal_bps).collect();
+        if ranges.len() < 3 {
+            return vec![Regime::Normal; ranges.len()];
+        }
+
+        let mut sorted = ranges.clone();
+        sorted.sort_by(f64::total_cmp);
+        let lower = interpolate_sorted(&sorted, 1.0 / 3.0);
+        let upper = interpolate_sorted(&sorted, 2.0 / 3.0);
+
+        ranges
+            .into_iter()
+            .map(|range| {
+                if range < lower {
+                    Regime::Low
+                } else if range > upper {
+                    Regime::High
+                } else {
+                    Regime::Normal
+                }
+            })
+            .collect()
+    }
+
+    /// Reprocess one trade set at each threshold to compare bar granularity
+    ///
+    /// Thresholds are processed in parallel; points are returned in the order of
+    /// `thresholds`. Only completed bars are counted, so the trailing incomplete
+    /// bar of each run is ignored.
+    pub fn threshold_sweep(
+        &self,
+        trades: &[AggTrade],
+        thresholds: &[u32],
+    ) -> Result<Vec<SweepPoint>, BatchError> {
+        thresholds
+            .par_iter()
+            .map(|&threshold_decimal_bps| {
+                let bars = RangeBarProcessor::new(threshold_decimal_bps)
+                    .and_then(|mut processor| processor.process_agg_trade_records(trades))
+                    .map_err(|source| BatchError::SweepFailed {
+                        threshold_decimal_bps,
+                        source,
+                    })?;
+                Ok(SweepPoint::from_bars(threshold_decimal_bps, &bars))
+            })
+            .collect()
+    }
+
     /// Compute basic statistics for range bar data
     fn compute_basic_statistics(&self, df: &DataFrame) -> Result<BasicStatistics, BatchError> {
         let lazy_df = df.clone().lazy();
@@ -520,6 +635,8 @@ pub struct AnalysisReport {
     pub price_analysis: PriceAnalysis,
     pub volume_analysis: VolumeAnalysis,
     pub microstructure: MicrostructureAnalysis,
+    #[serde(default)]
+    pub duration_percentiles: DurationPercentiles,
 }
 
 impl AnalysisReport {
@@ -532,6 +649,7 @@ impl AnalysisReport {
             price_analysis: PriceAnalysis::default(),
             volume_analysis: VolumeAnalysis::default(),
             microstructure: MicrostructureAnalysis::default(),
+            duration_percentiles: DurationPercentiles::default(),
         }
     }
 }
@@ -605,6 +723,116 @@ pub struct MicrostructureAnalysis {
     pub vwap_deviation_volatility: f64,
 }
 
+/// Bar whose realized move exceeds what its threshold allows
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnomalyReport {
+    /// Position of the bar in the analyzed slice
+    pub bar_index: usize,
+    /// Largest allowed move from open, threshold plus tolerance (decimal bps)
+    pub expected_max_decimal_bps: f64,
+    /// Realized largest move from open to high or low (decimal bps)
+    pub actual_decimal_bps: f64,
+    /// Realized high-low range relative to open (decimal bps)
+    pub range_decimal_bps: f64,
+}
+
+/// Coarse volatility bucket from [`BatchAnalysisEngine::label_regimes`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Regime {
+    /// Range in the bottom tercile of the dataset
+    Low,
+    /// Range in the middle tercile, or on a tercile cutoff
+    Normal,
+    /// Range in the top tercile of the dataset
+    High,
+}
+
+/// Bar statistics for one threshold of [`BatchAnalysisEngine::threshold_sweep`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SweepPoint {
+    pub threshold_decimal_bps: u32,
+    /// Number of completed bars
+    pub bar_count: usize,
+    /// Mean high-low range relative to open (decimal bps), 0 without bars
+    pub avg_range_decimal_bps: f64,
+    /// Mean `close_time - open_time` in microseconds, 0 without bars
+    pub avg_duration_us: f64,
+}
+
+impl SweepPoint {
+    fn from_bars(threshold_decimal_bps: u32, bars: &[RangeBar]) -> Self {
+        let mean = |total: f64| {
+            if bars.is_empty() {
+                0.0
+            } else {
+                total / bars.len() as f64
+            }
+        };
+        Self {
+            threshold_decimal_bps,
+            bar_count: bars.len(),
+            avg_range_decimal_bps: mean(bars.iter().map(|b| b.range_decimal_bps()).sum()),
+            avg_duration_us: mean(
+                bars.iter()
+                    .map(|b| (b.close_time - b.open_time) as f64)
+                    .sum(),
+            ),
+        }
+    }
+}
+
+/// Percentiles of bar duration (`close_time - open_time`) in microseconds
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct DurationPercentiles {
+    pub p25: f64,
+    pub p50: f64,
+    pub p75: f64,
+    pub p95: f64,
+    pub p99: f64,
+    /// True when computed by exact sort, false when estimated by streaming (P²)
+    pub exact: bool,
+}
+
+/// Percentile levels reported in [`DurationPercentiles`]
+const DURATION_LEVELS: [f64; 5] = [0.25, 0.5, 0.75, 0.95, 0.99];
+
+/// Exact sort up to `exact_limit` bars, P² streaming estimate above it
+fn compute_duration_percentiles(bars: &[RangeBar], exact_limit: usize) -> DurationPercentiles {
+    let durations = bars
+        .iter()
+        .map(|bar| (bar.close_time - bar.open_time) as f64);
+
+    let (values, exact) = if bars.len() <= exact_limit {
+        let mut sorted: Vec<f64> = durations.collect();
+        if sorted.is_empty() {
+            return DurationPercentiles::default();
+        }
+        sorted.sort_by(f64::total_cmp);
+        (
+            DURATION_LEVELS.map(|p| interpolate_sorted(&sorted, p)),
+            true,
+        )
+    } else {
+        let mut estimators = DURATION_LEVELS.map(P2Quantile::new);
+        for duration in durations {
+            for estimator in &mut estimators {
+                estimator.observe(duration);
+            }
+        }
+        (estimators.map(|e| e.estimate().unwrap_or(0.0)), false)
+    };
+
+    let [p25, p50, p75, p95, p99] = values;
+    DurationPercentiles {
+        p25,
+        p50,
+        p75,
+        p95,
+        p99,
+        exact,
+    }
+}
+
 /// Batch processing errors with rich context
 #[derive(Debug, Error)]
 pub enum BatchError {
@@ -628,6 +856,13 @@ pub enum BatchError {
         source: Box<dyn std::error::Error + Send + Sync>,
     },
 
+    #[error("Threshold sweep failed at {threshold_decimal_bps} decimal bps")]
+    SweepFailed {
+        threshold_decimal_bps: u32,
+        #[source]
+        source: ProcessingError,
+    },
+
     #[error("Value extraction failed for {operation}")]
     ValueExtractionFailed {
         operation: String,
@@ -678,6 +913,25 @@ mod tests {
     use super::*;
     use rangebar_core::{DataSource, FixedPoint, RangeBar};
 
+    /// Upward drift with a sawtooth overlay, 1ms apart
+    fn sweep_trades(count: i64) -> Vec<AggTrade> {
+        (0..count)
+            .map(|i| {
+                let price = 50_000.0 + i as f64 + ((i % 40) as f64 - 20.0) * 10.0;
+                AggTrade {
+                    agg_trade_id: i + 1,
+                    price: FixedPoint::from_str(&format!("{:.8}", price)).unwrap(),
+                    volume: FixedPoint::from_str("1.0").unwrap(),
+                    first_trade_id: i + 1,
+                    last_trade_id: i + 1,
+                    timestamp: 1_000_000 + i * 1_000,
+                    is_buyer_maker: i % 2 == 0,
+                    is_best_match: None,
+                }
+            })
+            .collect()
+    }
+
     fn create_test_range_bars() -> Vec<RangeBar> {
         vec![
             RangeBar {
@@ -701,6 +955,9 @@ mod tests {
                 vwap: FixedPoint(105000000),
                 buy_turnover: 630000000,
                 sell_turnover: 420000000,
+                threshold_upper: None,
+                threshold_lower: None,
+                close_reason: None,
             },
             RangeBar {
                 open_time: 1000002,
@@ -723,6 +980,9 @@ mod tests {
                 vwap: FixedPoint(110000000),
                 buy_turnover: 1320000000,
                 sell_turnover: 880000000,
+                threshold_upper: None,
+                threshold_lower: None,
+                close_reason: None,
             },
         ]
     }
@@ -778,6 +1038,129 @@ mod tests {
         assert!(matches!(result, Err(BatchError::EmptyData { .. })));
     }
 
+    /// Bars whose durations are a shuffled 1..=count milliseconds
+    fn bars_with_durations(count: i64) -> Vec<RangeBar> {
+        let template = create_test_range_bars().remove(0);
+        (0..count)
+            .map(|i| {
+                let mut bar = template.clone();
+                bar.open_time = 1_000_000 + i * 100_000_000;
+                bar.close_time = bar.open_time + ((i * 7_919) % count + 1) * 1_000;
+                bar
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_duration_percentiles_exact() {
+        let engine = BatchAnalysisEngine::new();
+        let bars = bars_with_durations(100);
+
+        let result = engine.analyze_single_symbol(&bars, "BTCUSDT").unwrap();
+        let durations = result.analysis.duration_percentiles;
+
+        assert!(durations.exact);
+        assert!((durations.p50 - 50_500.0).abs() < 1e-6);
+        assert!((durations.p95 - 95_050.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_duration_percentiles_streaming_above_limit() {
+        let bars = bars_with_durations(10_000);
+        let durations = compute_duration_percentiles(&bars, 1_000);
+
+        assert!(!durations.exact);
+        assert!((durations.p50 - 5_000_000.0).abs() < 100_000.0);
+        assert!((durations.p95 - 9_500_000.0).abs() < 100_000.0);
+        assert!(durations.p25 < durations.p50 && durations.p95 < durations.p99);
+    }
+
+    #[test]
+    fn test_detect_range_anomalies() {
+        let engine = BatchAnalysisEngine::new();
+        let mut normal = create_test_range_bars().remove(0);
+        normal.open = FixedPoint::from_str("50000.0").unwrap();
+        normal.low = FixedPoint::from_str("49000.0").unwrap();
+        normal.high = FixedPoint::from_str("51260.0").unwrap(); // 2.52%: small overshoot
+        normal.close = normal.high;
+
+        // Same bar but running 5.58% above open at a 2.5% threshold
+        let mut monster = normal.clone();
+        monster.high = FixedPoint::from_str("52790.0").unwrap();
+        monster.close = monster.high;
+
+        let anomalies = engine.detect_range_anomalies(&[normal, monster], 2_500);
+
+        assert_eq!(anomalies.len(), 1);
+        let report = &anomalies[0];
+        assert_eq!(report.bar_index, 1);
+        assert_eq!(report.expected_max_decimal_bps, 3_750.0);
+        assert!((report.actual_decimal_bps - 5_580.0).abs() < 1e-6);
+        assert!((report.range_decimal_bps - 7_580.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_label_regimes_separated_clusters() {
+        let engine = BatchAnalysisEngine::new();
+        let template = create_test_range_bars().remove(0);
+        let bar_with_range = |range_decimal_bps: i64| {
+            let mut bar = template.clone();
+            bar.open = FixedPoint::from_str("50000.0").unwrap();
+            bar.low = bar.open;
+            // 1 decimal bps of 50000 is 0.5
+            bar.high = FixedPoint(bar.open.0 + range_decimal_bps * 50_000_000);
+            bar.close = bar.high;
+            bar
+        };
+
+        // Interleaved clusters around 100, 1_000 and 10_000 decimal bps
+        let ranges = [1_020, 95, 9_800, 105, 10_300, 980, 100, 1_000, 10_000];
+        let bars: Vec<RangeBar> = ranges.iter().map(|&r| bar_with_range(r)).collect();
+
+        use Regime::{High, Low, Normal};
+        assert_eq!(
+            engine.label_regimes(&bars),
+            [Normal, Low, High, Low, High, Normal, Low, Normal, High]
+        );
+
+        // Ties share a label; tiny datasets fall back to Normal
+        let flat = vec![bar_with_range(500); 6];
+        assert_eq!(engine.label_regimes(&flat), vec![Normal; 6]);
+        assert_eq!(engine.label_regimes(&bars[..2]), [Normal, Normal]);
+        assert!(engine.label_regimes(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_threshold_sweep_bar_counts_non_increasing() {
+        let engine = BatchAnalysisEngine::new();
+        let trades = sweep_trades(5_000);
+        let thresholds = [100, 250, 500, 1000];
+
+        let sweep = engine.threshold_sweep(&trades, &thresholds).unwrap();
+
+        assert_eq!(
+            sweep
+                .iter()
+                .map(|p| p.threshold_decimal_bps)
+                .collect::<Vec<_>>(),
+            thresholds
+        );
+        assert!(sweep.last().unwrap().bar_count > 0);
+        for pair in sweep.windows(2) {
+            assert!(pair[0].bar_count >= pair[1].bar_count);
+            assert!(pair[0].avg_range_decimal_bps <= pair[1].avg_range_decimal_bps);
+            assert!(pair[0].avg_duration_us <= pair[1].avg_duration_us);
+        }
+
+        assert!(matches!(
+            engine.threshold_sweep(&trades, &[250, 0]),
+            Err(BatchError::SweepFailed {
+                threshold_decimal_bps: 0,
+                ..
+            })
+        ));
+    }
+
     #[test]
     fn test_no_symbol_data_error() {
         let engine = BatchAnalysisEngine::new();
@@ -4,6 +4,10 @@
 //! for research, backtesting, and advanced statistical analysis.
 
 pub mod engine;
+mod quantile;
 
 // Re-export commonly used types
-pub use engine::{AnalysisReport, BatchAnalysisEngine, BatchConfig, BatchError, BatchResult};
+pub use engine::{
+    AnalysisReport, AnomalyReport, BatchAnalysisEngine, BatchConfig, BatchError, BatchResult,
+    DurationPercentiles, Regime, SweepPoint,
+};
@@ -0,0 +1,147 @@
+//! Bounded-memory quantile estimation
+//!
+//! Implements the P² algorithm (Jain & Chlamtac, 1985): five markers track the
+//! minimum, the target quantile, two intermediate quantiles and the maximum,
+//! adjusted with piecewise-parabolic interpolation as observations arrive.
+
+/// Streaming estimator for a single quantile using constant memory
+#[derive(Debug, Clone)]
+pub(crate) struct P2Quantile {
+    /// Target quantile in [0, 1]
+    p: f64,
+    /// Marker heights
+    heights: [f64; 5],
+    /// Actual marker positions (1-based)
+    positions: [f64; 5],
+    /// Desired marker positions
+    desired: [f64; 5],
+    /// Desired position increments per observation
+    increments: [f64; 5],
+    /// Observations seen
+    count: usize,
+}
+
+impl P2Quantile {
+    pub(crate) fn new(p: f64) -> Self {
+        let p = p.clamp(0.0, 1.0);
+        Self {
+            p,
+            heights: [0.0; 5],
+            positions: [1.0, 2.0, 3.0, 4.0, 5.0],
+            desired: [1.0, 1.0 + 2.0 * p, 1.0 + 4.0 * p, 3.0 + 2.0 * p, 5.0],
+            increments: [0.0, p / 2.0, p, (1.0 + p) / 2.0, 1.0],
+            count: 0,
+        }
+    }
+
+    pub(crate) fn observe(&mut self, x: f64) {
+        if self.count < 5 {
+            self.heights[self.count] = x;
+            self.count += 1;
+            if self.count == 5 {
+                self.heights.sort_by(f64::total_cmp);
+            }
+            return;
+        }
+        self.count += 1;
+
+        // Locate the cell containing x, extending the extremes if needed
+        let cell = if x < self.heights[0] {
+            self.heights[0] = x;
+            0
+        } else if x >= self.heights[4] {
+            self.heights[4] = x;
+            3
+        } else {
+            (0..4).find(|&i| x < self.heights[i + 1]).unwrap_or(3)
+        };
+
+        for position in &mut self.positions[cell + 1..] {
+            *position += 1.0;
+        }
+        for (desired, increment) in self.desired.iter_mut().zip(self.increments) {
+            *desired += increment;
+        }
+
+        for i in 1..4 {
+            let offset = self.desired[i] - self.positions[i];
+            let room_up = self.positions[i + 1] - self.positions[i] > 1.0;
+            let room_down = self.positions[i - 1] - self.positions[i] < -1.0;
+            if (offset >= 1.0 && room_up) || (offset <= -1.0 && room_down) {
+                let step = offset.signum();
+                let candidate = self.parabolic(i, step);
+                self.heights[i] =
+                    if self.heights[i - 1] < candidate && candidate < self.heights[i + 1] {
+                        candidate
+                    } else {
+                        self.linear(i, step)
+                    };
+                self.positions[i] += step;
+            }
+        }
+    }
+
+    /// Current estimate, or `None` before any observation
+    pub(crate) fn estimate(&self) -> Option<f64> {
+        match self.count {
+            0 => None,
+            n if n < 5 => {
+                let mut seen = self.heights[..n].to_vec();
+                seen.sort_by(f64::total_cmp);
+                Some(interpolate_sorted(&seen, self.p))
+            }
+            _ => Some(self.heights[2]),
+        }
+    }
+
+    fn parabolic(&self, i: usize, step: f64) -> f64 {
+        let (q, n) = (&self.heights, &self.positions);
+        q[i] + step / (n[i + 1] - n[i - 1])
+            * ((n[i] - n[i - 1] + step) * (q[i + 1] - q[i]) / (n[i + 1] - n[i])
+                + (n[i + 1] - n[i] - step) * (q[i] - q[i - 1]) / (n[i] - n[i - 1]))
+    }
+
+    fn linear(&self, i: usize, step: f64) -> f64 {
+        let j = if step > 0.0 { i + 1 } else { i - 1 };
+        self.heights[i]
+            + step * (self.heights[j] - self.heights[i]) / (self.positions[j] - self.positions[i])
+    }
+}
+
+/// Linear-interpolated quantile of sorted values (same definition as Polars `Linear`)
+pub(crate) fn interpolate_sorted(sorted: &[f64], p: f64) -> f64 {
+    let rank = p.clamp(0.0, 1.0) * (sorted.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_p2_tracks_uniform_quantiles() {
+        let mut median = P2Quantile::new(0.5);
+        let mut p95 = P2Quantile::new(0.95);
+        // Deterministic permutation of 1..=10_000
+        for i in 0..10_000u64 {
+            let x = ((i * 7_919) % 10_000 + 1) as f64;
+            median.observe(x);
+            p95.observe(x);
+        }
+
+        assert!((median.estimate().unwrap() - 5_000.0).abs() < 100.0);
+        assert!((p95.estimate().unwrap() - 9_500.0).abs() < 100.0);
+    }
+
+    #[test]
+    fn test_p2_small_samples_are_exact() {
+        let mut median = P2Quantile::new(0.5);
+        assert_eq!(median.estimate(), None);
+        for x in [3.0, 1.0, 2.0] {
+            median.observe(x);
+        }
+        assert_eq!(median.estimate(), Some(2.0));
+    }
+}
@@ -153,6 +153,9 @@ fn load_range_bars(file_path: &str) -> Result<Vec<RangeBar>, Box<dyn std::error:
                 vwap: FixedPoint::from_str(&record[15])?,
                 buy_turnover: record[16].parse::<f64>()? as i128,
                 sell_turnover: record[17].parse::<f64>()? as i128,
+                threshold_upper: None,
+                threshold_lower: None,
+                close_reason: None,
             };
             range_bars.push(range_bar);
         }
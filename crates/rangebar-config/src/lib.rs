@@ -3,7 +3,7 @@
 //! Centralized configuration handling with support for:
 //! - Default values
 //! - Configuration files (TOML)
-//! - Environment variables
+//! - Environment variables (see [`Settings::with_env_overrides`])
 //! - Command-line arguments
 //!
 //! Configuration precedence (highest to lowest):
@@ -24,7 +24,8 @@ pub use data::DataConfig;
 pub use export::ExportConfig;
 
 use serde::{Deserialize, Serialize};
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
 /// Root configuration structure containing all configuration categories
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -76,6 +77,84 @@ impl Settings {
         config.try_deserialize()
     }
 
+    /// Re-read configuration from `path`, replacing `self` only if it is valid
+    ///
+    /// Intended for long-running services that reload thresholds and output
+    /// settings without restarting. On any parse or validation error the
+    /// current settings are left untouched.
+    pub fn reload_from_path(&mut self, path: &Path) -> Result<(), config::ConfigError> {
+        let reloaded = Self::load_from_file(path)?;
+        reloaded.validate()?;
+        *self = reloaded;
+        Ok(())
+    }
+
+    /// Override loaded values from `RANGEBAR_*` environment variables
+    ///
+    /// Recognised variables:
+    ///
+    /// | Variable | Field |
+    /// |----------|-------|
+    /// | `RANGEBAR_THRESHOLD_DECIMAL_BPS` | `algorithm.default_threshold_decimal_bps` |
+    /// | `RANGEBAR_OUTPUT_DIR` | `export.default_output_dir` |
+    /// | `RANGEBAR_BASE_URL` | `data.base_url` |
+    /// | `RANGEBAR_WORKER_THREADS` | `app.worker_threads` |
+    /// | `RANGEBAR_MAX_CONCURRENT_DOWNLOADS` | `data.max_concurrent_downloads` |
+    /// | `RANGEBAR_DEBUG` | `app.debug_mode` |
+    ///
+    /// Unset variables leave the current values intact. A value that fails to
+    /// parse, or a threshold outside the configured bounds, is an error naming
+    /// the variable.
+    pub fn with_env_overrides(mut self) -> Result<Self, config::ConfigError> {
+        if let Some(threshold) = env_override::<u32>("RANGEBAR_THRESHOLD_DECIMAL_BPS")? {
+            self.algorithm.validate_threshold(threshold).map_err(|e| {
+                config::ConfigError::Message(format!("RANGEBAR_THRESHOLD_DECIMAL_BPS: {e}"))
+            })?;
+            self.algorithm.default_threshold_decimal_bps = threshold;
+        }
+        if let Some(dir) = env_override::<PathBuf>("RANGEBAR_OUTPUT_DIR")? {
+            self.export.default_output_dir = dir;
+        }
+        if let Some(url) = env_override::<String>("RANGEBAR_BASE_URL")? {
+            self.data.base_url = url;
+        }
+        if let Some(threads) = env_override::<usize>("RANGEBAR_WORKER_THREADS")? {
+            self.app.worker_threads = Some(threads);
+        }
+        if let Some(downloads) = env_override::<usize>("RANGEBAR_MAX_CONCURRENT_DOWNLOADS")? {
+            self.data.max_concurrent_downloads = downloads;
+        }
+        if let Some(debug) = env_override::<bool>("RANGEBAR_DEBUG")? {
+            self.app.debug_mode = debug;
+        }
+        Ok(self)
+    }
+
+    /// Check invariants that deserialization alone cannot enforce
+    ///
+    /// The default threshold must be non-zero and within the configured
+    /// bounds, and the output directory must already exist.
+    pub fn validate(&self) -> Result<(), config::ConfigError> {
+        let threshold = self.algorithm.default_threshold_decimal_bps;
+        if threshold == 0 {
+            return Err(config::ConfigError::Message(
+                "algorithm.default_threshold_decimal_bps must be non-zero".to_string(),
+            ));
+        }
+        self.algorithm
+            .validate_threshold(threshold)
+            .map_err(config::ConfigError::Message)?;
+
+        if !self.export.default_output_dir.is_dir() {
+            return Err(config::ConfigError::Message(format!(
+                "export.default_output_dir does not exist: {}",
+                self.export.default_output_dir.display()
+            )));
+        }
+
+        Ok(())
+    }
+
     /// Merge command-line arguments into the loaded configuration
     pub fn merge_cli_args(mut self, cli_args: &dyn CliConfigMerge) -> Self {
         cli_args.merge_into_config(&mut self);
@@ -83,6 +162,21 @@ impl Settings {
     }
 }
 
+/// Read and parse an environment variable, `None` if unset
+fn env_override<T>(name: &str) -> Result<Option<T>, config::ConfigError>
+where
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    match std::env::var(name) {
+        Ok(raw) => raw.trim().parse().map(Some).map_err(|e| {
+            config::ConfigError::Message(format!("{name}: invalid value {raw:?}: {e}"))
+        }),
+        Err(std::env::VarError::NotPresent) => Ok(None),
+        Err(e) => Err(config::ConfigError::Message(format!("{name}: {e}"))),
+    }
+}
+
 /// Trait for merging CLI arguments into configuration
 pub trait CliConfigMerge {
     fn merge_into_config(&self, config: &mut Settings);
@@ -91,7 +185,50 @@ pub trait CliConfigMerge {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::path::PathBuf;
+    use std::sync::{Mutex, MutexGuard};
+
+    /// Serialises env-mutating tests and restores variables on drop
+    struct EnvGuard {
+        saved: Vec<(&'static str, Option<String>)>,
+        _lock: MutexGuard<'static, ()>,
+    }
+
+    static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+    impl EnvGuard {
+        fn set(vars: &[(&'static str, Option<&str>)]) -> Self {
+            let lock = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+            let saved = vars
+                .iter()
+                .map(|&(name, value)| {
+                    let previous = std::env::var(name).ok();
+                    // SAFETY: env access is serialised by ENV_LOCK
+                    unsafe {
+                        match value {
+                            Some(value) => std::env::set_var(name, value),
+                            None => std::env::remove_var(name),
+                        }
+                    }
+                    (name, previous)
+                })
+                .collect();
+            Self { saved, _lock: lock }
+        }
+    }
+
+    impl Drop for EnvGuard {
+        fn drop(&mut self) {
+            for (name, previous) in &self.saved {
+                // SAFETY: still holding ENV_LOCK
+                unsafe {
+                    match previous {
+                        Some(value) => std::env::set_var(name, value),
+                        None => std::env::remove_var(name),
+                    }
+                }
+            }
+        }
+    }
 
     #[test]
     fn test_default_settings() {
@@ -115,4 +252,81 @@ mod tests {
         let toml_str = toml::to_string(&settings).expect("Failed to serialize to TOML");
         let _: Settings = toml::from_str(&toml_str).expect("Failed to deserialize from TOML");
     }
+
+    #[test]
+    fn test_env_overrides_apply_and_absent_vars_keep_values() {
+        let _env = EnvGuard::set(&[
+            ("RANGEBAR_THRESHOLD_DECIMAL_BPS", Some("800")),
+            ("RANGEBAR_OUTPUT_DIR", Some("/tmp/rangebar-out")),
+            ("RANGEBAR_DEBUG", Some("true")),
+            ("RANGEBAR_BASE_URL", None),
+            ("RANGEBAR_WORKER_THREADS", None),
+            ("RANGEBAR_MAX_CONCURRENT_DOWNLOADS", None),
+        ]);
+
+        let defaults = Settings::default();
+        let settings = Settings::default().with_env_overrides().unwrap();
+        assert_eq!(settings.algorithm.default_threshold_decimal_bps, 800);
+        assert_eq!(
+            settings.export.default_output_dir,
+            PathBuf::from("/tmp/rangebar-out")
+        );
+        assert!(settings.app.debug_mode);
+        assert_eq!(settings.data.base_url, defaults.data.base_url);
+        assert_eq!(settings.app.worker_threads, defaults.app.worker_threads);
+    }
+
+    #[test]
+    fn test_env_overrides_reject_malformed_values() {
+        let env = EnvGuard::set(&[
+            ("RANGEBAR_THRESHOLD_DECIMAL_BPS", None),
+            ("RANGEBAR_WORKER_THREADS", Some("four")),
+        ]);
+        let err = Settings::default().with_env_overrides().unwrap_err();
+        assert!(err.to_string().contains("RANGEBAR_WORKER_THREADS"));
+        drop(env);
+
+        let _env = EnvGuard::set(&[("RANGEBAR_THRESHOLD_DECIMAL_BPS", Some("0"))]);
+        let err = Settings::default().with_env_overrides().unwrap_err();
+        assert!(err.to_string().contains("RANGEBAR_THRESHOLD_DECIMAL_BPS"));
+    }
+
+    #[test]
+    fn test_reload_keeps_old_settings_on_invalid_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("rangebar.toml");
+        let output_dir = dir.path().display().to_string().replace('\\', "/");
+        let write_config = |threshold: u32, output: &str| {
+            std::fs::write(
+                &path,
+                format!(
+                    "[algorithm]\ndefault_threshold_decimal_bps = {threshold}\n\n\
+                     [export]\ndefault_output_dir = \"{output}\"\n"
+                ),
+            )
+            .unwrap();
+        };
+
+        let mut settings = Settings::default();
+
+        write_config(500, &output_dir);
+        settings.reload_from_path(&path).unwrap();
+        assert_eq!(settings.algorithm.default_threshold_decimal_bps, 500);
+        assert_eq!(settings.export.default_output_dir, dir.path());
+
+        // Zero threshold is rejected and the previous values survive
+        write_config(0, &output_dir);
+        assert!(settings.reload_from_path(&path).is_err());
+        assert_eq!(settings.algorithm.default_threshold_decimal_bps, 500);
+
+        // Missing output directory is rejected too
+        write_config(800, &format!("{output_dir}/missing"));
+        assert!(settings.reload_from_path(&path).is_err());
+        assert_eq!(settings.algorithm.default_threshold_decimal_bps, 500);
+        assert_eq!(settings.export.default_output_dir, dir.path());
+
+        write_config(1000, &output_dir);
+        settings.reload_from_path(&path).unwrap();
+        assert_eq!(settings.algorithm.default_threshold_decimal_bps, 1000);
+    }
 }
@@ -0,0 +1,72 @@
+// Breach pre-scan benchmark: batch processing vs a scalar batch loop with the
+// same bar boundaries, checked for identical output before timing
+//
+// Run: cargo bench -p rangebar-core --bench breach_scan --features test-utils
+
+use criterion::{BenchmarkId, Criterion, Throughput, black_box, criterion_group, criterion_main};
+use rangebar_core::test_utils::{self, generators::process_scalar_batch};
+use rangebar_core::{AggTrade, FixedPoint, RangeBarProcessor};
+
+const TRADE_COUNT: usize = 10_000_000;
+
+/// Both paths must agree before their timings are comparable
+fn assert_same_output(trades: &[AggTrade], threshold: u32) {
+    let mut processor = RangeBarProcessor::new(threshold).unwrap();
+    let bars = processor.process_agg_trade_records(trades).unwrap();
+    let (expected, expected_incomplete) = process_scalar_batch(trades, threshold);
+
+    assert_eq!(
+        serde_json::to_string(&bars).unwrap(),
+        serde_json::to_string(&expected).unwrap(),
+        "pre-scan and scalar bars differ at threshold {threshold}"
+    );
+    assert_eq!(
+        serde_json::to_string(&processor.get_incomplete_bar()).unwrap(),
+        serde_json::to_string(&expected_incomplete).unwrap(),
+        "pre-scan and scalar open bars differ at threshold {threshold}"
+    );
+}
+
+fn bench_breach_scan(c: &mut Criterion) {
+    // Seeded random walk from 50,000 with ~0.6bps steps
+    let trades =
+        test_utils::synthetic_stream(0x12345678, TRADE_COUNT, FixedPoint(50_000 * 100_000_000), 6);
+
+    let mut group = c.benchmark_group("breach_scan");
+    group.sample_size(10);
+    group.throughput(Throughput::Elements(TRADE_COUNT as u64));
+
+    for threshold in [250u32, 1_000] {
+        assert_same_output(&trades, threshold);
+
+        // Pre-scan path
+        group.bench_with_input(
+            BenchmarkId::new("process_agg_trade_records", threshold),
+            &threshold,
+            |b, &threshold| {
+                b.iter(|| {
+                    let mut processor = RangeBarProcessor::new(threshold).unwrap();
+                    black_box(
+                        processor
+                            .process_agg_trade_records(black_box(&trades))
+                            .unwrap(),
+                    )
+                });
+            },
+        );
+
+        // Scalar reference: same batch semantics, is_breach evaluated on every record
+        group.bench_with_input(
+            BenchmarkId::new("scalar_batch", threshold),
+            &threshold,
+            |b, &threshold| {
+                b.iter(|| black_box(process_scalar_batch(black_box(&trades), threshold)));
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_breach_scan);
+criterion_main!(benches);
@@ -0,0 +1,77 @@
+// Throughput of the three trade-to-bar paths on one shared dataset
+//
+// Compares RangeBarProcessor (batch), ExportRangeBarProcessor (continuous
+// export) and RangeBarIterator (lazy, one trade at a time). All three use the
+// exact i128 turnover path; there is no f64 turnover variant left to compare.
+//
+// Run: cargo bench -p rangebar-core --bench processors --features test-utils
+
+use criterion::{BenchmarkId, Criterion, Throughput, black_box, criterion_group, criterion_main};
+use rangebar_core::{
+    ExportRangeBarProcessor, FixedPoint, RangeBarIterator, RangeBarProcessor, test_utils,
+};
+
+const TRADE_COUNT: usize = 5_000_000;
+
+const THRESHOLDS_DECIMAL_BPS: [u32; 3] = [100, 250, 1_000];
+
+fn bench_processors(c: &mut Criterion) {
+    // Seeded random walk from 50,000 with ~0.6bps steps
+    let trades = test_utils::synthetic_stream(
+        0x9e3779b97f4a7c15,
+        TRADE_COUNT,
+        FixedPoint(50_000 * 100_000_000),
+        6,
+    );
+
+    let mut group = c.benchmark_group("processors");
+    group.sample_size(10);
+    // Reported as elements/sec, i.e. trades/sec
+    group.throughput(Throughput::Elements(TRADE_COUNT as u64));
+
+    for threshold in THRESHOLDS_DECIMAL_BPS {
+        group.bench_with_input(
+            BenchmarkId::new("RangeBarProcessor", threshold),
+            &threshold,
+            |b, &threshold| {
+                b.iter(|| {
+                    let mut processor = RangeBarProcessor::new(threshold).unwrap();
+                    black_box(
+                        processor
+                            .process_agg_trade_records(black_box(&trades))
+                            .unwrap(),
+                    )
+                });
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("ExportRangeBarProcessor", threshold),
+            &threshold,
+            |b, &threshold| {
+                b.iter(|| {
+                    let mut processor = ExportRangeBarProcessor::new(threshold).unwrap();
+                    processor.process_trades_continuously(black_box(&trades));
+                    black_box(processor.get_all_completed_bars())
+                });
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("RangeBarIterator", threshold),
+            &threshold,
+            |b, &threshold| {
+                b.iter(|| {
+                    let processor = RangeBarProcessor::new(threshold).unwrap();
+                    let iter = RangeBarIterator::new(black_box(&trades).iter().cloned(), processor);
+                    black_box(iter.map(Result::unwrap).count())
+                });
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_processors);
+criterion_main!(benches);
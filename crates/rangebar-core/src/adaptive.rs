@@ -0,0 +1,276 @@
+//! Volatility-scaled range bar processing
+//!
+//! Variant of [`RangeBarProcessor`](crate::RangeBarProcessor) whose threshold is
+//! re-derived at each bar open from an EWMA of trade-to-trade price moves, so bar
+//! frequency stays roughly stable across volatility regimes. Once a bar opens its
+//! thresholds are fixed, preserving the non-lookahead guarantee.
+
+use crate::processor::{ProcessingError, RangeBarState, validate_trade_ordering};
+use crate::types::{AggTrade, RangeBar};
+
+/// Minimum threshold in decimal basis points (0.1bps)
+const MIN_THRESHOLD_DECIMAL_BPS: u32 = 1;
+
+/// Maximum threshold in decimal basis points (100%)
+const MAX_THRESHOLD_DECIMAL_BPS: u32 = 100_000;
+
+/// Completed bar together with the threshold it was built with
+#[derive(Debug, Clone)]
+pub struct AdaptiveRangeBar {
+    /// The completed range bar
+    pub bar: RangeBar,
+
+    /// Threshold in decimal basis points fixed at this bar's open
+    pub threshold_decimal_bps: u32,
+}
+
+/// Range bar processor with volatility-scaled thresholds
+///
+/// At each bar open the threshold is `multiplier × EWMA(|Δprice| / price)` in
+/// decimal basis points, clamped to 1..=100,000. Until the first price move has
+/// been observed the initial threshold is used.
+#[derive(Debug, Clone)]
+pub struct AdaptiveRangeBarProcessor {
+    /// Threshold used before any volatility has been observed
+    initial_threshold_decimal_bps: u32,
+
+    /// Scale from per-trade volatility (decimal bps) to bar threshold
+    multiplier: f64,
+
+    /// EWMA smoothing factor in (0, 1]
+    alpha: f64,
+
+    /// EWMA of absolute trade-to-trade moves in decimal basis points
+    volatility_decimal_bps: Option<f64>,
+
+    /// Previous trade price (for move calculation)
+    last_price: Option<i64>,
+
+    /// Bar under construction and the threshold it opened with
+    current: Option<(RangeBarState, u32)>,
+
+    /// Previous bar closed on the last trade; the next trade opens a new bar
+    defer_open: bool,
+}
+
+impl AdaptiveRangeBarProcessor {
+    /// Create an adaptive processor
+    ///
+    /// # Arguments
+    ///
+    /// * `initial_threshold_decimal_bps` - Threshold for the first bar(s), 1..=100,000
+    /// * `multiplier` - Positive factor applied to the volatility estimate
+    /// * `alpha` - EWMA smoothing factor in (0, 1]; higher reacts faster
+    pub fn new(
+        initial_threshold_decimal_bps: u32,
+        multiplier: f64,
+        alpha: f64,
+    ) -> Result<Self, ProcessingError> {
+        if !(MIN_THRESHOLD_DECIMAL_BPS..=MAX_THRESHOLD_DECIMAL_BPS)
+            .contains(&initial_threshold_decimal_bps)
+        {
+            return Err(ProcessingError::InvalidThreshold {
+                threshold_decimal_bps: initial_threshold_decimal_bps,
+            });
+        }
+        if !(multiplier.is_finite() && multiplier > 0.0) {
+            return Err(ProcessingError::InvalidParameter {
+                name: "multiplier",
+                value: multiplier,
+            });
+        }
+        if !(alpha > 0.0 && alpha <= 1.0) {
+            return Err(ProcessingError::InvalidParameter {
+                name: "alpha",
+                value: alpha,
+            });
+        }
+
+        Ok(Self {
+            initial_threshold_decimal_bps,
+            multiplier,
+            alpha,
+            volatility_decimal_bps: None,
+            last_price: None,
+            current: None,
+            defer_open: false,
+        })
+    }
+
+    /// Current EWMA volatility estimate in decimal basis points per trade
+    pub fn volatility_decimal_bps(&self) -> Option<f64> {
+        self.volatility_decimal_bps
+    }
+
+    /// Threshold the next bar would open with
+    pub fn next_threshold_decimal_bps(&self) -> u32 {
+        match self.volatility_decimal_bps {
+            None => self.initial_threshold_decimal_bps,
+            Some(volatility) => {
+                let scaled = (volatility * self.multiplier).round();
+                scaled.clamp(
+                    MIN_THRESHOLD_DECIMAL_BPS as f64,
+                    MAX_THRESHOLD_DECIMAL_BPS as f64,
+                ) as u32
+            }
+        }
+    }
+
+    /// Process sorted trades, returning bars completed in this call
+    ///
+    /// State (volatility estimate and incomplete bar) carries over between calls.
+    pub fn process_agg_trade_records(
+        &mut self,
+        agg_trade_records: &[AggTrade],
+    ) -> Result<Vec<AdaptiveRangeBar>, ProcessingError> {
+        validate_trade_ordering(agg_trade_records)?;
+
+        let mut bars = Vec::new();
+        for agg_record in agg_trade_records {
+            if let Some(bar) = self.process_trade(agg_record) {
+                bars.push(bar);
+            }
+        }
+        Ok(bars)
+    }
+
+    /// Get any incomplete bar currently being processed
+    pub fn get_incomplete_bar(&self) -> Option<AdaptiveRangeBar> {
+        self.current
+            .as_ref()
+            .map(|(state, threshold_decimal_bps)| AdaptiveRangeBar {
+                bar: state.bar.clone(),
+                threshold_decimal_bps: *threshold_decimal_bps,
+            })
+    }
+
+    fn process_trade(&mut self, trade: &AggTrade) -> Option<AdaptiveRangeBar> {
+        let completed = match self.current.as_mut() {
+            Some((state, threshold_decimal_bps)) if !self.defer_open => {
+                state.bar.update_with_trade(trade);
+                if state
+                    .bar
+                    .is_breach(trade.price, state.upper_threshold, state.lower_threshold)
+                {
+                    self.defer_open = true;
+                    Some(AdaptiveRangeBar {
+                        bar: state.bar.clone(),
+                        threshold_decimal_bps: *threshold_decimal_bps,
+                    })
+                } else {
+                    None
+                }
+            }
+            _ => {
+                // Threshold comes from volatility observed strictly before this trade
+                let threshold_decimal_bps = self.next_threshold_decimal_bps();
+                self.current = Some((
+                    RangeBarState::new(trade, threshold_decimal_bps),
+                    threshold_decimal_bps,
+                ));
+                self.defer_open = false;
+                None
+            }
+        };
+
+        if completed.is_some() {
+            self.current = None;
+        }
+        self.observe_price(trade.price.0);
+        completed
+    }
+
+    /// Fold the move from the previous trade into the EWMA
+    fn observe_price(&mut self, price: i64) {
+        if let Some(last_price) = self.last_price.filter(|&p| p > 0) {
+            let move_decimal_bps =
+                (price - last_price).unsigned_abs() as f64 * 100_000.0 / last_price as f64;
+            self.volatility_decimal_bps = Some(match self.volatility_decimal_bps {
+                None => move_decimal_bps,
+                Some(ewma) => self.alpha * move_decimal_bps + (1.0 - self.alpha) * ewma,
+            });
+        }
+        self.last_price = Some(price);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+
+    /// Zig-zag walk with the given step size in price units
+    fn zigzag(count: i64, step: f64) -> Vec<AggTrade> {
+        (0..count)
+            .map(|i| {
+                let direction = if (i / 5) % 2 == 0 { 1.0 } else { -1.0 };
+                let drift = (i % 5) as f64 * step * direction + (i / 10) as f64 * step;
+                test_utils::create_test_agg_trade(
+                    i + 1,
+                    &format!("{:.8}", 50_000.0 + drift),
+                    "1.0",
+                    1_000 + i,
+                )
+            })
+            .collect()
+    }
+
+    fn mean_threshold(bars: &[AdaptiveRangeBar]) -> f64 {
+        bars.iter()
+            .map(|b| b.threshold_decimal_bps as f64)
+            .sum::<f64>()
+            / bars.len() as f64
+    }
+
+    #[test]
+    fn test_higher_volatility_widens_thresholds() {
+        let mut calm = AdaptiveRangeBarProcessor::new(250, 20.0, 0.1).unwrap();
+        let calm_bars = calm.process_agg_trade_records(&zigzag(2_000, 1.0)).unwrap();
+
+        let mut volatile = AdaptiveRangeBarProcessor::new(250, 20.0, 0.1).unwrap();
+        let volatile_bars = volatile
+            .process_agg_trade_records(&zigzag(2_000, 10.0))
+            .unwrap();
+
+        assert!(calm_bars.len() > 1 && volatile_bars.len() > 1);
+        assert!(volatile.volatility_decimal_bps() > calm.volatility_decimal_bps());
+        assert!(mean_threshold(&volatile_bars[1..]) > mean_threshold(&calm_bars[1..]));
+    }
+
+    #[test]
+    fn test_bars_close_on_their_own_fixed_threshold() {
+        let mut processor = AdaptiveRangeBarProcessor::new(250, 20.0, 0.2).unwrap();
+        let bars = processor
+            .process_agg_trade_records(&zigzag(1_000, 5.0))
+            .unwrap();
+        assert!(!bars.is_empty());
+        assert_eq!(bars[0].threshold_decimal_bps, 250);
+
+        for adaptive in &bars {
+            let (upper, lower) = adaptive
+                .bar
+                .open
+                .compute_range_thresholds(adaptive.threshold_decimal_bps);
+            assert!(adaptive.bar.close >= upper || adaptive.bar.close <= lower);
+        }
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert!(matches!(
+            AdaptiveRangeBarProcessor::new(0, 20.0, 0.1),
+            Err(ProcessingError::InvalidThreshold { .. })
+        ));
+        assert!(matches!(
+            AdaptiveRangeBarProcessor::new(250, 0.0, 0.1),
+            Err(ProcessingError::InvalidParameter {
+                name: "multiplier",
+                ..
+            })
+        ));
+        assert!(matches!(
+            AdaptiveRangeBarProcessor::new(250, 20.0, 1.5),
+            Err(ProcessingError::InvalidParameter { name: "alpha", .. })
+        ));
+    }
+}
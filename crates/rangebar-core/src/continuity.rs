@@ -0,0 +1,75 @@
+//! Post-hoc price continuity repair for stitched bar sequences
+
+use crate::types::RangeBar;
+
+/// Force each bar to open at the previous bar's close, returning the repair count
+///
+/// For every `i` where `bars[i + 1].open != bars[i].close`, the open is
+/// overwritten with the previous close and `high`/`low` are widened if the new
+/// open falls outside them. Intended for concatenating bars from independent
+/// processors into a single price-continuous series for research.
+///
+/// # Lossy, research only
+///
+/// The repaired open is not a traded price of that bar, its thresholds no
+/// longer derive from its open, and VWAP, volumes and trade counts are left
+/// untouched. Note that a single processor's bars already open on the trade
+/// after the previous close, so even unstitched output is usually "repaired".
+/// Never feed repaired bars back into production analytics or validation.
+pub fn repair_continuity(bars: &mut [RangeBar]) -> usize {
+    let mut repairs = 0;
+    for i in 1..bars.len() {
+        let previous_close = bars[i - 1].close;
+        let bar = &mut bars[i];
+        if bar.open == previous_close {
+            continue;
+        }
+
+        bar.open = previous_close;
+        bar.high = bar.high.max(previous_close);
+        bar.low = bar.low.min(previous_close);
+        repairs += 1;
+    }
+    repairs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::create_test_range_bar;
+
+    /// Test bar with all volume on the buy side, so `validate()` only checks OHLC
+    fn bar(open_time: i64, open: &str, high: &str, low: &str, close: &str) -> RangeBar {
+        let mut bar =
+            create_test_range_bar(open_time, open_time + 1, open, high, low, close, "1.0", 1);
+        bar.buy_volume = bar.volume;
+        bar
+    }
+
+    #[test]
+    fn test_repair_continuity_broken_sequence() {
+        let mut bars = vec![
+            bar(1, "100.0", "101.0", "99.0", "101.0"),
+            // Continuous: opens at previous close
+            bar(3, "101.0", "102.0", "100.5", "100.5"),
+            // Gap up from 100.5: new open lies below the bar's low
+            bar(5, "103.0", "104.0", "102.5", "104.0"),
+            // Gap down from 104.0: new open lies above the bar's high
+            bar(7, "102.0", "103.0", "101.0", "101.0"),
+        ];
+
+        assert_eq!(repair_continuity(&mut bars), 2);
+
+        for pair in bars.windows(2) {
+            assert_eq!(pair[1].open, pair[0].close);
+        }
+        assert_eq!(bars[1].low.to_string(), "100.50000000");
+        assert_eq!(bars[2].low.to_string(), "100.50000000");
+        assert_eq!(bars[3].high.to_string(), "104.00000000");
+        assert!(bars.iter().all(|bar| bar.validate().is_ok()));
+
+        // Already continuous, nothing left to repair
+        assert_eq!(repair_continuity(&mut bars), 0);
+        assert_eq!(repair_continuity(&mut []), 0);
+    }
+}
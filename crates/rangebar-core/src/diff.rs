@@ -0,0 +1,139 @@
+//! Field-level comparison of two bar sequences for regression testing
+
+use crate::types::RangeBar;
+
+/// One field that differs between two bars at the same index
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldDiff {
+    /// Column name as in [`RangeBar::csv_header`], or an annotation field
+    pub field: &'static str,
+    /// Value in the left sequence
+    pub left: String,
+    /// Value in the right sequence
+    pub right: String,
+}
+
+/// Result of [`diff_bars`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BarDiff {
+    /// Number of bars in the left sequence
+    pub left_len: usize,
+    /// Number of bars in the right sequence
+    pub right_len: usize,
+    /// Index of the first bar that differs within the common prefix
+    pub first_difference: Option<usize>,
+    /// Differing fields of the bar at `first_difference`
+    pub fields: Vec<FieldDiff>,
+}
+
+impl BarDiff {
+    /// Both sequences have the same length and identical bars
+    pub fn is_identical(&self) -> bool {
+        self.left_len == self.right_len && self.first_difference.is_none()
+    }
+
+    /// The sequences contain a different number of bars
+    pub fn count_mismatch(&self) -> bool {
+        self.left_len != self.right_len
+    }
+}
+
+/// Compare two bar sequences bar by bar
+///
+/// Every exported column is compared in its canonical string form (see
+/// [`RangeBar::to_csv_row`]), plus the optional `threshold_upper`,
+/// `threshold_lower` and `close_reason` annotations. Only the first differing
+/// bar is detailed, since later bars usually differ as a consequence; a length
+/// mismatch is reported even when the common prefix is identical.
+pub fn diff_bars(left: &[RangeBar], right: &[RangeBar]) -> BarDiff {
+    let mut diff = BarDiff {
+        left_len: left.len(),
+        right_len: right.len(),
+        first_difference: None,
+        fields: Vec::new(),
+    };
+
+    for (index, (a, b)) in left.iter().zip(right).enumerate() {
+        let fields: Vec<FieldDiff> = bar_fields(a)
+            .into_iter()
+            .zip(bar_fields(b))
+            .filter(|((_, x), (_, y))| x != y)
+            .map(|((field, left), (_, right))| FieldDiff { field, left, right })
+            .collect();
+
+        if !fields.is_empty() {
+            diff.first_difference = Some(index);
+            diff.fields = fields;
+            break;
+        }
+    }
+    diff
+}
+
+/// Named string values of every compared field
+fn bar_fields(bar: &RangeBar) -> Vec<(&'static str, String)> {
+    let annotations = [
+        ("threshold_upper", format!("{:?}", bar.threshold_upper)),
+        ("threshold_lower", format!("{:?}", bar.threshold_lower)),
+        ("close_reason", format!("{:?}", bar.close_reason)),
+    ];
+    RangeBar::csv_header()
+        .iter()
+        .copied()
+        .zip(bar.to_csv_row())
+        .chain(annotations)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixed_point::FixedPoint;
+    use crate::processor::RangeBarProcessor;
+    use crate::test_utils;
+
+    fn bars() -> Vec<RangeBar> {
+        let trades: Vec<_> = (0..500)
+            .map(|i| {
+                let price = 50_000.0 + ((i % 30) as f64 - 15.0) * 20.0 + i as f64;
+                test_utils::create_test_agg_trade(i + 1, &format!("{price:.1}"), "1.0", 1_000 + i)
+            })
+            .collect();
+        RangeBarProcessor::new(100)
+            .unwrap()
+            .process_agg_trade_records(&trades)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_identical_sets_have_no_diff() {
+        let diff = diff_bars(&bars(), &bars());
+        assert!(diff.is_identical());
+        assert!(!diff.count_mismatch());
+        assert!(diff.fields.is_empty());
+    }
+
+    #[test]
+    fn test_single_close_price_difference() {
+        let left = bars();
+        assert!(left.len() > 3);
+        let mut right = left.clone();
+        right[2].close = FixedPoint(right[2].close.0 + 1);
+
+        let diff = diff_bars(&left, &right);
+        assert!(!diff.is_identical());
+        assert_eq!(diff.first_difference, Some(2));
+        assert_eq!(
+            diff.fields,
+            vec![FieldDiff {
+                field: "close",
+                left: left[2].close.to_string(),
+                right: right[2].close.to_string(),
+            }]
+        );
+
+        let truncated = diff_bars(&left[..left.len() - 1], &left);
+        assert!(truncated.count_mismatch());
+        assert_eq!(truncated.first_difference, None);
+    }
+}
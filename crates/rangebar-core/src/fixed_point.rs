@@ -1,14 +1,22 @@
 //! Fixed-point arithmetic for precise decimal calculations without floating point errors
 
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::num::{IntErrorKind, ParseIntError};
+use core::str::FromStr;
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
+#[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
-use std::fmt;
-use std::str::FromStr;
 
 /// Scale factor for 8 decimal places (100,000,000)
 pub const SCALE: i64 = 100_000_000;
 
+/// Scale of turnover values (`price.0 * volume.0`): SCALE² = 1e16
+pub const TURNOVER_SCALE: i128 = SCALE as i128 * SCALE as i128;
+
 /// Scale factor for decimal basis points (v3.0.0: 100,000)
 /// Prior to v3.0.0, this was 10,000 (1bps units). Now 100,000 (decimal bps).
 /// Migration: multiply all threshold_decimal_bps values by 10.
@@ -22,11 +30,35 @@ pub const BASIS_POINTS_SCALE: u32 = 100_000;
 /// Example:
 /// - 50000.12345678 → 5000012345678
 /// - 1.5 → 150000000
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct FixedPoint(pub i64);
 
 impl FixedPoint {
+    /// Scale factor of the raw representation (1e8, i.e. 8 decimal places)
+    ///
+    /// A value `v` is stored as `v × SCALE`; same as the module-level [`SCALE`].
+    pub const SCALE: i64 = SCALE;
+
+    /// Wrap an already-scaled integer (e.g. an Arrow/NumPy `i64` column) without parsing
+    ///
+    /// `raw` must be scaled by [`FixedPoint::SCALE`]: `from_scaled(150_000_000)` is 1.5.
+    pub const fn from_scaled(raw: i64) -> Self {
+        FixedPoint(raw)
+    }
+
+    /// Underlying integer scaled by [`FixedPoint::SCALE`]
+    pub const fn raw(&self) -> i64 {
+        self.0
+    }
+
+    /// Scale factor of the raw representation, [`FixedPoint::SCALE`]
+    pub const fn scale() -> i64 {
+        Self::SCALE
+    }
+
     /// Create FixedPoint from string representation
     ///
     /// # Arguments
@@ -35,49 +67,57 @@ impl FixedPoint {
     ///
     /// # Returns
     ///
-    /// Result containing FixedPoint or parse error
+    /// Result containing FixedPoint or a [`FixedPointParseError`] classifying
+    /// the failure (empty, non-numeric, too many decimals, out of range)
     #[allow(clippy::should_implement_trait)]
-    pub fn from_str(s: &str) -> Result<Self, FixedPointError> {
+    pub fn from_str(s: &str) -> Result<Self, FixedPointParseError> {
         // Handle empty string
-        if s.is_empty() {
-            return Err(FixedPointError::InvalidFormat);
+        if s.trim().is_empty() {
+            return Err(FixedPointParseError::Empty);
         }
+        let non_numeric = || FixedPointParseError::NonNumeric { input: snippet(s) };
+        let out_of_range = || FixedPointParseError::OutOfRange { input: snippet(s) };
 
         // Split on decimal point
         let parts: Vec<&str> = s.split('.').collect();
         if parts.len() > 2 {
-            return Err(FixedPointError::InvalidFormat);
+            return Err(non_numeric());
         }
 
         // Parse integer part
         let integer_part: i64 = parts[0]
             .parse()
-            .map_err(|_| FixedPointError::InvalidFormat)?;
+            .map_err(|e: ParseIntError| match e.kind() {
+                IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => out_of_range(),
+                _ => non_numeric(),
+            })?;
 
         // Parse fractional part (if exists)
         let fractional_part = if parts.len() == 2 {
             let frac_str = parts[1];
+            if !frac_str.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(non_numeric());
+            }
             if frac_str.len() > 8 {
-                return Err(FixedPointError::TooManyDecimals);
+                return Err(FixedPointParseError::TooManyDecimals { input: snippet(s) });
             }
 
             // Pad with zeros to get exactly 8 decimals
             let padded = format!("{:0<8}", frac_str);
-            padded
-                .parse::<i64>()
-                .map_err(|_| FixedPointError::InvalidFormat)?
+            padded.parse::<i64>().map_err(|_| non_numeric())?
         } else {
             0
         };
 
-        // Combine parts with proper sign handling
-        let result = if integer_part >= 0 {
-            integer_part * SCALE + fractional_part
+        // Combine parts with proper sign handling ("-0.5" has integer part 0)
+        let scaled = integer_part.checked_mul(SCALE).ok_or_else(out_of_range)?;
+        let result = if !parts[0].starts_with('-') {
+            scaled.checked_add(fractional_part)
         } else {
-            integer_part * SCALE - fractional_part
+            scaled.checked_sub(fractional_part)
         };
 
-        Ok(FixedPoint(result))
+        result.map(FixedPoint).ok_or_else(out_of_range)
     }
 
     /// Convert FixedPoint to string representation with 8 decimal places
@@ -124,6 +164,131 @@ impl FixedPoint {
     pub fn to_f64(&self) -> f64 {
         self.0 as f64 / SCALE as f64
     }
+
+    /// Restrict value to the inclusive range `[min, max]`
+    ///
+    /// Ordering is the total order of the underlying scaled `i64`, so negative
+    /// intermediate values (e.g. an underflowed lower threshold) compare correctly.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min > max`, matching [`Ord::clamp`].
+    pub fn clamp(self, min: FixedPoint, max: FixedPoint) -> FixedPoint {
+        Ord::clamp(self, min, max)
+    }
+
+    /// Smaller of two values
+    pub fn min(self, other: FixedPoint) -> FixedPoint {
+        Ord::min(self, other)
+    }
+
+    /// Larger of two values
+    pub fn max(self, other: FixedPoint) -> FixedPoint {
+        Ord::max(self, other)
+    }
+
+    /// Round to the nearest multiple of `tick`, ties away from zero
+    ///
+    /// Idempotent: a value already on the tick grid is returned unchanged. A
+    /// non-positive `tick` disables snapping. Near the i64 limits, where the
+    /// nearest tick is unrepresentable, the next tick toward zero is returned.
+    pub fn snap_to_tick(self, tick: FixedPoint) -> FixedPoint {
+        if tick.0 <= 0 {
+            return self;
+        }
+        let tick = tick.0 as i128;
+        let value = self.0 as i128;
+        let half = tick / 2;
+        let ticks = if value >= 0 {
+            (value + half) / tick
+        } else {
+            (value - half) / tick
+        };
+        let snapped = ticks * tick;
+        // One tick toward zero never exceeds |self|, so it always fits
+        let fallback = || i64::try_from(snapped - ticks.signum() * tick).unwrap_or(self.0);
+        FixedPoint(i64::try_from(snapped).unwrap_or_else(|_| fallback()))
+    }
+
+    /// Divide by `divisor`, rounding the 9th decimal and beyond per `mode`
+    ///
+    /// The dividend is widened to i128 and pre-scaled, so the quotient keeps
+    /// the full 8-decimal precision without passing through f64.
+    pub fn div_round(
+        self,
+        divisor: FixedPoint,
+        mode: RoundingMode,
+    ) -> Result<FixedPoint, ArithmeticError> {
+        if divisor.0 == 0 {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+
+        let numerator = self.0 as i128 * SCALE as i128;
+        let denominator = divisor.0 as i128;
+        let quotient = numerator / denominator;
+        let remainder = numerator % denominator;
+
+        let quotient = if remainder == 0 {
+            quotient
+        } else {
+            let negative = (numerator < 0) != (denominator < 0);
+            let away = if negative { quotient - 1 } else { quotient + 1 };
+            let twice_remainder = remainder.abs() * 2;
+            match mode {
+                RoundingMode::TowardZero => quotient,
+                RoundingMode::Floor if negative => away,
+                RoundingMode::Floor => quotient,
+                RoundingMode::Ceiling if negative => quotient,
+                RoundingMode::Ceiling => away,
+                RoundingMode::HalfAwayFromZero if twice_remainder >= denominator.abs() => away,
+                RoundingMode::HalfAwayFromZero => quotient,
+                RoundingMode::HalfEven => match twice_remainder.cmp(&denominator.abs()) {
+                    core::cmp::Ordering::Greater => away,
+                    core::cmp::Ordering::Equal if quotient % 2 != 0 => away,
+                    _ => quotient,
+                },
+            }
+        };
+
+        i64::try_from(quotient)
+            .map(FixedPoint)
+            .map_err(|_| ArithmeticError::Overflow)
+    }
+
+    /// `1 / self`, rounded per `mode`
+    pub fn reciprocal(self, mode: RoundingMode) -> Result<FixedPoint, ArithmeticError> {
+        FixedPoint(SCALE).div_round(self, mode)
+    }
+}
+
+/// Whether `price` breaches a bar's thresholds
+///
+/// Comparisons are inclusive: a price exactly on a threshold is a breach, so
+/// the touching trade closes (and is included in) the bar. Thresholds come from
+/// [`FixedPoint::compute_range_thresholds`] on the bar's open price.
+#[inline]
+pub fn is_breach(
+    price: FixedPoint,
+    upper_threshold: FixedPoint,
+    lower_threshold: FixedPoint,
+) -> bool {
+    price >= upper_threshold || price <= lower_threshold
+}
+
+/// Rounding applied to digits beyond the 8th decimal in [`FixedPoint::div_round`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RoundingMode {
+    /// Truncate (round toward zero)
+    TowardZero,
+    /// Round toward negative infinity
+    Floor,
+    /// Round toward positive infinity
+    Ceiling,
+    /// Round to nearest, ties away from zero (matches [`FixedPoint::snap_to_tick`])
+    #[default]
+    HalfAwayFromZero,
+    /// Round to nearest, ties to even (banker's rounding)
+    HalfEven,
 }
 
 impl fmt::Display for FixedPoint {
@@ -133,13 +298,81 @@ impl fmt::Display for FixedPoint {
 }
 
 impl FromStr for FixedPoint {
-    type Err = FixedPointError;
+    type Err = FixedPointParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         FixedPoint::from_str(s)
     }
 }
 
+/// Decimal places carried by [`FixedPoint`]
+pub const MAX_DECIMALS: u8 = 8;
+
+/// [`FixedPoint`] tagged with an instrument's quoted precision
+///
+/// Core arithmetic always stays on the inner 8-decimal [`FixedPoint`]; the
+/// scale only governs parsing and formatting at I/O boundaries, so values from
+/// instruments quoted at different precisions (EURUSD at 5 decimals, USDJPY at
+/// 3) share one raw integer representation and are never reinterpreted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScaledFixedPoint {
+    value: FixedPoint,
+    scale: u8,
+}
+
+impl ScaledFixedPoint {
+    /// Tag `value` with `scale` decimal places (capped at [`MAX_DECIMALS`])
+    pub fn new(value: FixedPoint, scale: u8) -> Self {
+        Self {
+            value,
+            scale: scale.min(MAX_DECIMALS),
+        }
+    }
+
+    /// Parse a decimal string quoted with at most `scale` decimal places
+    pub fn parse(s: &str, scale: u8) -> Result<Self, FixedPointParseError> {
+        let decimals = s.split_once('.').map_or(0, |(_, frac)| frac.len());
+        if decimals > scale as usize {
+            return Err(FixedPointParseError::TooManyDecimals { input: snippet(s) });
+        }
+        Ok(Self::new(FixedPoint::from_str(s)?, scale))
+    }
+
+    /// Underlying 8-decimal value for arithmetic
+    pub fn value(&self) -> FixedPoint {
+        self.value
+    }
+
+    /// Quoted decimal places
+    pub fn scale(&self) -> u8 {
+        self.scale
+    }
+
+    /// Format with exactly `scale` decimals (capped at 8), rounding half away from zero
+    pub fn to_string_at_scale(&self, scale: u8) -> String {
+        let scale = scale.min(MAX_DECIMALS);
+        let unit = 10_i64.pow((MAX_DECIMALS - scale) as u32);
+        let rounded = self.value.snap_to_tick(FixedPoint(unit)).0;
+
+        let sign = if rounded < 0 { "-" } else { "" };
+        let integer_part = rounded.abs() / SCALE;
+        if scale == 0 {
+            return format!("{sign}{integer_part}");
+        }
+        let fractional_part = (rounded.abs() % SCALE) / unit;
+        format!(
+            "{sign}{integer_part}.{fractional_part:0width$}",
+            width = scale as usize
+        )
+    }
+}
+
+impl fmt::Display for ScaledFixedPoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_string_at_scale(self.scale))
+    }
+}
+
 /// Fixed-point arithmetic errors
 #[derive(Debug, Clone, PartialEq)]
 pub enum FixedPointError {
@@ -161,7 +394,109 @@ impl fmt::Display for FixedPointError {
     }
 }
 
-impl std::error::Error for FixedPointError {}
+impl core::error::Error for FixedPointError {}
+
+impl From<FixedPointParseError> for FixedPointError {
+    fn from(err: FixedPointParseError) -> Self {
+        match err {
+            FixedPointParseError::Empty | FixedPointParseError::NonNumeric { .. } => {
+                FixedPointError::InvalidFormat
+            }
+            FixedPointParseError::TooManyDecimals { .. } => FixedPointError::TooManyDecimals,
+            FixedPointParseError::OutOfRange { .. } => FixedPointError::Overflow,
+        }
+    }
+}
+
+/// Longest input prefix kept in a [`FixedPointParseError`]
+const MAX_SNIPPET_CHARS: usize = 32;
+
+/// Bounded copy of the rejected input for error messages
+fn snippet(s: &str) -> String {
+    match s.char_indices().nth(MAX_SNIPPET_CHARS) {
+        Some((end, _)) => format!("{}…", &s[..end]),
+        None => s.to_string(),
+    }
+}
+
+/// [`FixedPoint::from_str`] failures, classified for skip-vs-abort decisions
+///
+/// Variants other than `Empty` carry the rejected input (truncated to 32
+/// characters) for logging.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FixedPointParseError {
+    /// Empty or whitespace-only input
+    Empty,
+    /// Not a plain decimal number (letters, exponent, repeated `.`, stray sign)
+    NonNumeric { input: String },
+    /// More than 8 decimal places, or more than a [`ScaledFixedPoint`]'s scale
+    TooManyDecimals { input: String },
+    /// Magnitude does not fit in the i64 representation (about ±92 billion)
+    OutOfRange { input: String },
+}
+
+impl FixedPointParseError {
+    /// Whether the error is confined to one malformed row
+    ///
+    /// `Empty` and `NonNumeric` are typical of isolated bad rows and can be
+    /// skipped. `TooManyDecimals` and `OutOfRange` usually mean the whole
+    /// source is on the wrong scale or schema, so bulk loaders should abort.
+    pub fn is_skippable(&self) -> bool {
+        matches!(
+            self,
+            FixedPointParseError::Empty | FixedPointParseError::NonNumeric { .. }
+        )
+    }
+
+    /// Rejected input snippet, `None` for `Empty`
+    pub fn input(&self) -> Option<&str> {
+        match self {
+            FixedPointParseError::Empty => None,
+            FixedPointParseError::NonNumeric { input }
+            | FixedPointParseError::TooManyDecimals { input }
+            | FixedPointParseError::OutOfRange { input } => Some(input),
+        }
+    }
+}
+
+impl fmt::Display for FixedPointParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FixedPointParseError::Empty => write!(f, "Empty number"),
+            FixedPointParseError::NonNumeric { input } => {
+                write!(f, "Invalid number format: '{}'", input)
+            }
+            FixedPointParseError::TooManyDecimals { input } => {
+                write!(f, "Too many decimal places: '{}'", input)
+            }
+            FixedPointParseError::OutOfRange { input } => {
+                write!(f, "Number out of range: '{}'", input)
+            }
+        }
+    }
+}
+
+impl core::error::Error for FixedPointParseError {}
+
+/// Fixed-point division errors
+#[derive(Debug, Clone, PartialEq)]
+pub enum ArithmeticError {
+    /// Divisor was zero
+    DivisionByZero,
+    /// Result does not fit in the i64 representation
+    Overflow,
+}
+
+impl fmt::Display for ArithmeticError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArithmeticError::DivisionByZero => write!(f, "Division by zero"),
+            ArithmeticError::Overflow => write!(f, "Arithmetic overflow"),
+        }
+    }
+}
+
+impl core::error::Error for ArithmeticError {}
 
 #[cfg(feature = "python")]
 impl From<FixedPointError> for PyErr {
@@ -180,6 +515,18 @@ impl From<FixedPointError> for PyErr {
     }
 }
 
+#[cfg(feature = "python")]
+impl From<FixedPointParseError> for PyErr {
+    fn from(err: FixedPointParseError) -> PyErr {
+        match err {
+            FixedPointParseError::OutOfRange { .. } => {
+                pyo3::exceptions::PyOverflowError::new_err(err.to_string())
+            }
+            _ => pyo3::exceptions::PyValueError::new_err(err.to_string()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -196,6 +543,70 @@ mod tests {
         assert_eq!(FixedPoint::from_str("-1.5").unwrap().0, -SCALE - SCALE / 2);
     }
 
+    #[test]
+    fn test_scaled_fixed_point_formatting() {
+        let eurusd = ScaledFixedPoint::parse("1.08457", 5).unwrap();
+        assert_eq!(eurusd.value(), FixedPoint(108_457_000));
+        assert_eq!(eurusd.to_string(), "1.08457");
+        assert_eq!(eurusd.to_string_at_scale(4), "1.0846");
+
+        let usdjpy = ScaledFixedPoint::new(FixedPoint::from_str("151.23456").unwrap(), 3);
+        assert_eq!(usdjpy.to_string(), "151.235");
+        assert_eq!(usdjpy.to_string_at_scale(0), "151");
+        assert_eq!(usdjpy.to_string_at_scale(12), "151.23456000");
+
+        let negative = ScaledFixedPoint::new(FixedPoint::from_str("-0.0005").unwrap(), 3);
+        assert_eq!(negative.to_string(), "-0.001");
+
+        assert_eq!(
+            ScaledFixedPoint::parse("151.2345", 3),
+            Err(FixedPointParseError::TooManyDecimals {
+                input: "151.2345".to_string()
+            })
+        );
+        assert!(matches!(
+            ScaledFixedPoint::parse("1.5x", 3),
+            Err(FixedPointParseError::NonNumeric { .. })
+        ));
+    }
+
+    #[test]
+    fn test_from_str_error_variants() {
+        assert_eq!(FixedPoint::from_str(""), Err(FixedPointParseError::Empty));
+        assert_eq!(FixedPoint::from_str("  "), Err(FixedPointParseError::Empty));
+
+        for input in ["abc", "1.2.3", "1e5", "1.-5", "NaN", "."] {
+            assert_eq!(
+                FixedPoint::from_str(input),
+                Err(FixedPointParseError::NonNumeric {
+                    input: input.to_string()
+                }),
+                "{input}"
+            );
+        }
+
+        assert_eq!(
+            FixedPoint::from_str("1.123456789"),
+            Err(FixedPointParseError::TooManyDecimals {
+                input: "1.123456789".to_string()
+            })
+        );
+
+        // Overflows the i64 parse, and overflows only after scaling by 1e8
+        for input in ["99999999999999999999", "-100000000000"] {
+            let err = FixedPoint::from_str(input).unwrap_err();
+            assert!(matches!(err, FixedPointParseError::OutOfRange { .. }));
+            assert_eq!(err.input(), Some(input));
+            assert!(!err.is_skippable());
+        }
+
+        assert!(FixedPointParseError::Empty.is_skippable());
+        let long = "x".repeat(100);
+        let err = FixedPoint::from_str(&long).unwrap_err();
+        assert!(err.is_skippable());
+        assert_eq!(err.input().unwrap().chars().count(), MAX_SNIPPET_CHARS + 1);
+    }
+
     #[test]
     fn test_to_string() {
         assert_eq!(FixedPoint(0).to_string(), "0.00000000");
@@ -227,6 +638,70 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_from_scaled_raw_round_trip() {
+        assert_eq!(FixedPoint::scale(), 100_000_000);
+        assert_eq!(FixedPoint::SCALE, SCALE);
+
+        for raw in [
+            0,
+            1,
+            -1,
+            150_000_000,
+            5_000_012_345_678,
+            i64::MAX,
+            i64::MIN + 1,
+        ] {
+            let fp = FixedPoint::from_scaled(raw);
+            assert_eq!(fp.0, raw);
+            assert_eq!(fp.raw(), raw);
+            assert_eq!(FixedPoint::from_str(&fp.to_string()).unwrap(), fp);
+        }
+        assert_eq!(
+            FixedPoint::from_scaled(150_000_000).to_string(),
+            "1.50000000"
+        );
+    }
+
+    #[test]
+    fn test_div_round() {
+        let fp = |s: &str| FixedPoint::from_str(s).unwrap();
+        let half_up = RoundingMode::HalfAwayFromZero;
+
+        assert_eq!(
+            fp("50125.0").div_round(fp("2.0"), half_up),
+            Ok(fp("25062.5"))
+        );
+
+        // Non-terminating quotient: 0.333333333...
+        let third = |mode| fp("1").div_round(fp("3"), mode).unwrap();
+        assert_eq!(third(half_up), fp("0.33333333"));
+        assert_eq!(third(RoundingMode::TowardZero), fp("0.33333333"));
+        assert_eq!(third(RoundingMode::Ceiling), fp("0.33333334"));
+        assert_eq!(
+            fp("-1").div_round(fp("3"), RoundingMode::Floor),
+            Ok(fp("-0.33333334"))
+        );
+        assert_eq!(fp("2").div_round(fp("3"), half_up), Ok(fp("0.66666667")));
+
+        // Exact ties: 0.000000005 and 0.000000015
+        let tie = |raw, mode| FixedPoint(raw).div_round(fp("2"), mode).unwrap().0;
+        assert_eq!(tie(1, half_up), 1);
+        assert_eq!(tie(1, RoundingMode::HalfEven), 0);
+        assert_eq!(tie(3, RoundingMode::HalfEven), 2);
+        assert_eq!(tie(-1, half_up), -1);
+
+        assert_eq!(fp("4").reciprocal(half_up), Ok(fp("0.25")));
+        assert_eq!(
+            fp("1").div_round(FixedPoint(0), half_up),
+            Err(ArithmeticError::DivisionByZero)
+        );
+        assert_eq!(
+            FixedPoint(i64::MAX).div_round(fp("0.5"), half_up),
+            Err(ArithmeticError::Overflow)
+        );
+    }
+
     #[test]
     fn test_compute_thresholds() {
         let price = FixedPoint::from_str("50000.0").unwrap();
@@ -237,6 +712,18 @@ mod tests {
         assert_eq!(lower.to_string(), "49875.00000000");
     }
 
+    #[test]
+    fn test_is_breach_inclusive() {
+        let (upper, lower) = FixedPoint::from_str("50000.0")
+            .unwrap()
+            .compute_range_thresholds(250);
+
+        assert!(is_breach(upper, upper, lower));
+        assert!(is_breach(lower, upper, lower));
+        assert!(!is_breach(FixedPoint(upper.0 - 1), upper, lower));
+        assert!(!is_breach(FixedPoint(lower.0 + 1), upper, lower));
+    }
+
     #[test]
     fn test_error_cases() {
         assert!(FixedPoint::from_str("").is_err());
@@ -256,4 +743,117 @@ mod tests {
         assert!(c < a);
         assert_eq!(a, a);
     }
+
+    #[test]
+    fn test_total_ordering() {
+        use std::cmp::Ordering;
+
+        let values = [
+            FixedPoint(i64::MIN),
+            FixedPoint::from_str("-50000.5").unwrap(),
+            FixedPoint::from_str("-0.00000001").unwrap(),
+            FixedPoint(0),
+            FixedPoint::from_str("0.00000001").unwrap(),
+            FixedPoint::from_str("50000.0").unwrap(),
+            FixedPoint(i64::MAX),
+        ];
+
+        for (i, a) in values.iter().enumerate() {
+            for (j, b) in values.iter().enumerate() {
+                // PartialOrd agrees with Ord and with the index order
+                assert_eq!(a.partial_cmp(b), Some(a.cmp(b)));
+                assert_eq!(a.cmp(b), i.cmp(&j));
+                // Antisymmetry
+                assert_eq!(a.cmp(b), b.cmp(a).reverse());
+            }
+        }
+
+        let mut shuffled = vec![
+            values[3], values[6], values[0], values[5], values[1], values[4], values[2],
+        ];
+        shuffled.sort();
+        assert_eq!(shuffled, values);
+        assert_eq!(values[2].cmp(&values[2]), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_clamp_min_max() {
+        let low = FixedPoint::from_str("49875.0").unwrap();
+        let high = FixedPoint::from_str("50125.0").unwrap();
+
+        assert_eq!(
+            FixedPoint::from_str("50000.0")
+                .unwrap()
+                .clamp(low, high)
+                .to_string(),
+            "50000.00000000"
+        );
+        assert_eq!(
+            FixedPoint::from_str("60000.0").unwrap().clamp(low, high),
+            high
+        );
+        assert_eq!(FixedPoint::from_str("-1.0").unwrap().clamp(low, high), low);
+        assert_eq!(
+            FixedPoint(i64::MIN).clamp(FixedPoint(0), FixedPoint(0)),
+            FixedPoint(0)
+        );
+
+        assert_eq!(low.min(high), low);
+        assert_eq!(low.max(high), high);
+        assert_eq!(FixedPoint(-5).max(FixedPoint(0)), FixedPoint(0));
+        assert_eq!(FixedPoint(-5).min(FixedPoint(0)), FixedPoint(-5));
+    }
+
+    #[test]
+    fn test_snap_to_tick() {
+        let tick = FixedPoint::from_str("0.01").unwrap();
+        let snapped = FixedPoint::from_str("50000.007")
+            .unwrap()
+            .snap_to_tick(tick);
+        assert_eq!(snapped.to_string(), "50000.01000000");
+        assert_eq!(snapped.snap_to_tick(tick), snapped);
+
+        assert_eq!(
+            FixedPoint::from_str("50000.004")
+                .unwrap()
+                .snap_to_tick(tick)
+                .to_string(),
+            "50000.00000000"
+        );
+        assert_eq!(
+            FixedPoint::from_str("-1.005").unwrap().snap_to_tick(tick),
+            FixedPoint::from_str("-1.01").unwrap()
+        );
+
+        let price = FixedPoint::from_str("123.456").unwrap();
+        assert_eq!(price.snap_to_tick(FixedPoint(0)), price);
+    }
+
+    #[test]
+    fn test_snap_to_tick_saturates_at_i64_limits() {
+        // i64::MAX ends in ...807 and i64::MIN in ...808: both round away past the limit
+        assert_eq!(
+            FixedPoint(i64::MAX).snap_to_tick(FixedPoint(10)),
+            FixedPoint(i64::MAX - 7)
+        );
+        assert_eq!(
+            FixedPoint(i64::MIN).snap_to_tick(FixedPoint(10)),
+            FixedPoint(i64::MIN + 8)
+        );
+        assert_eq!(
+            FixedPoint(i64::MAX).snap_to_tick(FixedPoint(SCALE)),
+            FixedPoint(i64::MAX / SCALE * SCALE)
+        );
+        // Rounding down near the limit is unaffected
+        assert_eq!(
+            FixedPoint(i64::MAX - 7).snap_to_tick(FixedPoint(10)),
+            FixedPoint(i64::MAX - 7)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_clamp_inverted_bounds_panics() {
+        FixedPoint(0).clamp(FixedPoint(10), FixedPoint(-10));
+    }
 }
@@ -0,0 +1,166 @@
+//! Lazy conversion of trade iterators into range bars
+
+use crate::processor::{ProcessingError, RangeBarProcessor};
+use crate::types::{AggTrade, RangeBar};
+
+/// Iterator adapter yielding completed range bars from an `AggTrade` iterator
+///
+/// Trades are pulled only as needed to complete the next bar, so unbounded
+/// provider streams can be converted without buffering. Bars are identical to
+/// [`RangeBarProcessor::process_agg_trade_records`] over the same trades.
+/// Out-of-order trades and processor errors (gap policy, strict volume) are
+/// yielded once as `Err`, after which the iterator is exhausted.
+///
+/// # Example
+///
+/// ```ignore
+/// let processor = RangeBarProcessor::new(250)?;
+/// let mut bars = RangeBarIterator::new(provider_trades.into_iter(), processor);
+/// for bar in bars.by_ref() {
+///     handle(bar?);
+/// }
+/// let trailing = bars.into_incomplete();
+/// ```
+pub struct RangeBarIterator<I> {
+    trades: I,
+    processor: RangeBarProcessor,
+    consumed: usize,
+    failed: bool,
+}
+
+impl<I: Iterator<Item = AggTrade>> RangeBarIterator<I> {
+    /// Wrap `trades`, building bars with `processor`
+    pub fn new(trades: I, processor: RangeBarProcessor) -> Self {
+        Self {
+            trades,
+            processor,
+            consumed: 0,
+            failed: false,
+        }
+    }
+
+    /// Underlying processor (anomaly summary, gap warnings, checkpoints)
+    pub fn processor(&self) -> &RangeBarProcessor {
+        &self.processor
+    }
+
+    /// Consume the adapter, returning the bar still open when input ended
+    pub fn into_incomplete(self) -> Option<RangeBar> {
+        self.processor.get_incomplete_bar()
+    }
+}
+
+impl<I: Iterator<Item = AggTrade>> Iterator for RangeBarIterator<I> {
+    type Item = Result<RangeBar, ProcessingError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        for trade in self.trades.by_ref() {
+            let index = self.consumed;
+            self.consumed += 1;
+            match self.processor.process_next_trade(index, &trade) {
+                Ok(Some(bar)) => return Some(Ok(bar)),
+                Ok(None) => {}
+                Err(e) => {
+                    self.failed = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+
+    #[test]
+    fn test_iterator_matches_batch_processing() {
+        let start = crate::FixedPoint::from_str("50000.0").unwrap();
+        let trades = test_utils::synthetic_stream(7, 3_000, start, 40);
+
+        let mut batch_processor = RangeBarProcessor::new(250).unwrap();
+        let expected = batch_processor.process_agg_trade_records(&trades).unwrap();
+        assert!(expected.len() > 2);
+
+        let mut iter = RangeBarIterator::new(
+            trades.clone().into_iter(),
+            RangeBarProcessor::new(250).unwrap(),
+        );
+        let bars: Vec<RangeBar> = iter.by_ref().map(Result::unwrap).collect();
+
+        assert_eq!(bars.len(), expected.len());
+        for (bar, want) in bars.iter().zip(&expected) {
+            assert_eq!(
+                (
+                    bar.open_time,
+                    bar.close_time,
+                    bar.open,
+                    bar.high,
+                    bar.low,
+                    bar.close
+                ),
+                (
+                    want.open_time,
+                    want.close_time,
+                    want.open,
+                    want.high,
+                    want.low,
+                    want.close
+                )
+            );
+            assert_eq!(bar.volume, want.volume);
+        }
+
+        let incomplete = iter.into_incomplete().unwrap();
+        let batch_incomplete = batch_processor.get_incomplete_bar().unwrap();
+        assert_eq!(incomplete.open_time, batch_incomplete.open_time);
+        assert_eq!(incomplete.volume, batch_incomplete.volume);
+    }
+
+    #[test]
+    fn test_iterator_continues_checkpoint_bar() {
+        let start = crate::FixedPoint::from_str("50000.0").unwrap();
+        let trades = test_utils::synthetic_stream(11, 2_000, start, 40);
+        let (head, tail) = trades.split_at(1_000);
+
+        let mut processor = RangeBarProcessor::new(250).unwrap();
+        processor.process_agg_trade_records(head).unwrap();
+        let checkpoint = processor.create_checkpoint("BTCUSDT");
+        assert!(checkpoint.incomplete_bar.is_some());
+
+        let mut batch = RangeBarProcessor::from_checkpoint(checkpoint.clone()).unwrap();
+        let expected = batch.process_agg_trade_records(tail).unwrap();
+
+        let iter = RangeBarIterator::new(
+            tail.iter().cloned(),
+            RangeBarProcessor::from_checkpoint(checkpoint).unwrap(),
+        );
+        let bars: Vec<RangeBar> = iter.map(Result::unwrap).collect();
+
+        assert_eq!(bars.len(), expected.len());
+        for (bar, want) in bars.iter().zip(&expected) {
+            assert_eq!(
+                (bar.open_time, bar.close_time),
+                (want.open_time, want.close_time)
+            );
+            assert_eq!(bar.volume, want.volume);
+        }
+    }
+
+    #[test]
+    fn test_iterator_stops_on_unsorted_input() {
+        let trades = test_utils::scenarios::unsorted_sequence();
+        let mut iter =
+            RangeBarIterator::new(trades.into_iter(), RangeBarProcessor::new(250).unwrap());
+        assert!(matches!(
+            iter.next(),
+            Some(Err(ProcessingError::UnsortedTrades { index: 1, .. }))
+        ));
+        assert!(iter.next().is_none());
+    }
+}
@@ -9,25 +9,90 @@
 //! - Fixed thresholds: Never recalculated during bar lifetime
 //! - Temporal integrity: Guaranteed correct historical simulation
 //! - **Cross-file checkpoints**: Seamless continuation across file boundaries (v6.1.0+)
+//!
+//! ## `no_std`
+//!
+//! With default features off, only the [`fixed_point`] arithmetic subset
+//! (`FixedPoint`, `compute_range_thresholds`, [`is_breach`]) is compiled, using
+//! `core` and `alloc`. The `std` feature (on by default) enables everything else.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
 
+#[cfg(feature = "std")]
+pub mod adaptive;
+#[cfg(feature = "std")]
 pub mod checkpoint;
+#[cfg(feature = "std")]
+pub mod continuity;
+#[cfg(feature = "std")]
+pub mod diff;
 pub mod fixed_point;
+#[cfg(feature = "std")]
+pub mod iter;
+#[cfg(feature = "std")]
+pub mod merge;
+#[cfg(feature = "std")]
 pub mod processor;
+#[cfg(feature = "std")]
+pub mod resample;
+#[cfg(feature = "schema")]
+pub mod schema;
+#[cfg(feature = "std")]
+pub mod threshold;
+#[cfg(feature = "std")]
 pub mod timestamp;
+#[cfg(feature = "std")]
 pub mod types;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 // Test utilities (only available in test builds or with test-utils feature)
-#[cfg(any(test, feature = "test-utils"))]
+#[cfg(all(feature = "std", any(test, feature = "test-utils")))]
 pub mod test_utils;
 
-#[cfg(any(test, feature = "test-utils"))]
+#[cfg(all(feature = "std", any(test, feature = "test-utils")))]
 pub mod test_data_loader;
 
 // Re-export commonly used types
+pub use fixed_point::{
+    ArithmeticError, FixedPoint, FixedPointParseError, RoundingMode, ScaledFixedPoint, is_breach,
+};
+
+#[cfg(feature = "std")]
+pub use adaptive::{AdaptiveRangeBar, AdaptiveRangeBarProcessor};
+#[cfg(feature = "std")]
 pub use checkpoint::{AnomalySummary, Checkpoint, CheckpointError, PositionVerification};
-pub use fixed_point::FixedPoint;
-pub use processor::{ExportRangeBarProcessor, ProcessingError, RangeBarProcessor};
+#[cfg(feature = "std")]
+pub use continuity::repair_continuity;
+#[cfg(feature = "std")]
+pub use diff::{BarDiff, FieldDiff, diff_bars};
+#[cfg(feature = "std")]
+pub use iter::RangeBarIterator;
+#[cfg(feature = "std")]
+pub use merge::merge_bars;
+#[cfg(feature = "metrics")]
+pub use processor::ProcessorMetrics;
+#[cfg(feature = "std")]
+pub use processor::{
+    ExportRangeBarProcessor, GapPolicy, ProcessingError, RangeBarProcessor,
+    RangeBarProcessorBuilder, TradeIdGap, sort_trades_stable,
+};
+#[cfg(feature = "std")]
+pub use resample::resample_time_bars;
+#[cfg(feature = "std")]
+pub use threshold::{AMBIGUOUS_THRESHOLD_LIMIT, ThresholdUnit, resolve_threshold_decimal_bps};
+#[cfg(feature = "std")]
 pub use timestamp::{
-    create_aggtrade_with_normalized_timestamp, normalize_timestamp, validate_timestamp,
+    TimeUnit, TimestampError, TimestampValidator, create_aggtrade_with_normalized_timestamp,
+    detect_time_unit, normalize_aggtrade_batch, normalize_timestamp, normalize_timestamp_with_unit,
+    validate_timestamp, validate_timestamp_in_range,
+};
+#[cfg(feature = "std")]
+pub use types::{
+    AggTrade, BarValidationError, BinanceAggTradeJson, CloseReason, ConversionError, DataSource,
+    Market, Provider, RangeBar,
 };
-pub use types::{AggTrade, DataSource, RangeBar};
+#[cfg(feature = "wasm")]
+pub use wasm::wasm_process;
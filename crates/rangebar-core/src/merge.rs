@@ -0,0 +1,140 @@
+//! Coarsening completed range bars without reprocessing trades
+
+use crate::fixed_point::FixedPoint;
+use crate::types::RangeBar;
+
+/// Merge every `factor` consecutive bars into one coarser bar
+///
+/// OHLC combines as open of the first bar, close of the last, max high and min
+/// low; volumes, turnovers and trade counts are summed and VWAP is recomputed
+/// from the merged turnover. A trailing group shorter than `factor` is merged
+/// as-is. `factor` of 0 or 1 returns the bars unchanged.
+///
+/// # Approximation
+///
+/// Merged bars are **not** identical to reprocessing at a wider threshold: a
+/// coarse bar opens at the first trade after the previous coarse close and its
+/// thresholds derive from that open, whereas merged boundaries can only fall on
+/// fine-bar closes. Per-bar `threshold_upper`/`threshold_lower` annotations are
+/// dropped since no single threshold applies to a merged bar.
+pub fn merge_bars(bars: &[RangeBar], factor: usize) -> Vec<RangeBar> {
+    if factor <= 1 {
+        return bars.to_vec();
+    }
+
+    bars.chunks(factor)
+        .map(|group| {
+            let mut merged = group[0].clone();
+            merged.threshold_upper = None;
+            merged.threshold_lower = None;
+            for bar in &group[1..] {
+                absorb(&mut merged, bar);
+            }
+            merged
+        })
+        .collect()
+}
+
+/// Fold a later bar into `merged`
+fn absorb(merged: &mut RangeBar, bar: &RangeBar) {
+    merged.high = merged.high.max(bar.high);
+    merged.low = merged.low.min(bar.low);
+    merged.close = bar.close;
+    merged.close_time = bar.close_time;
+    merged.last_trade_id = bar.last_trade_id;
+    merged.close_reason = bar.close_reason;
+
+    merged.volume = FixedPoint(merged.volume.0 + bar.volume.0);
+    merged.turnover += bar.turnover;
+    merged.individual_trade_count += bar.individual_trade_count;
+    merged.agg_record_count += bar.agg_record_count;
+
+    merged.buy_volume = FixedPoint(merged.buy_volume.0 + bar.buy_volume.0);
+    merged.sell_volume = FixedPoint(merged.sell_volume.0 + bar.sell_volume.0);
+    merged.buy_trade_count += bar.buy_trade_count;
+    merged.sell_trade_count += bar.sell_trade_count;
+    merged.buy_turnover += bar.buy_turnover;
+    merged.sell_turnover += bar.sell_turnover;
+
+    if merged.volume.0 > 0 {
+        merged.vwap = FixedPoint((merged.turnover / merged.volume.0 as i128) as i64);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::processor::RangeBarProcessor;
+    use crate::test_utils;
+    use crate::types::AggTrade;
+
+    /// Slow upward drift with oscillation so both thresholds produce several bars
+    fn drifting_trades(count: i64) -> Vec<AggTrade> {
+        (0..count)
+            .map(|i| {
+                let price = 50_000.0 + (i as f64) * 2.0 + ((i % 9) as f64 - 4.0) * 15.0;
+                test_utils::create_test_agg_trade(
+                    i + 1,
+                    &format!("{:.8}", price),
+                    if i % 2 == 0 { "1.0" } else { "0.5" },
+                    1_000 + i,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_merge_bars_combines_ohlcv() {
+        let trades = drifting_trades(3_000);
+        let fine = RangeBarProcessor::new(100)
+            .unwrap()
+            .process_agg_trade_records(&trades)
+            .unwrap();
+        assert!(fine.len() >= 6);
+
+        let merged = merge_bars(&fine, 3);
+        assert_eq!(merged.len(), fine.len().div_ceil(3));
+
+        for (group, bar) in fine.chunks(3).zip(&merged) {
+            let last = group.last().unwrap();
+            assert_eq!(bar.open, group[0].open);
+            assert_eq!(bar.open_time, group[0].open_time);
+            assert_eq!(bar.close, last.close);
+            assert_eq!(bar.close_time, last.close_time);
+            assert_eq!(bar.high, group.iter().map(|b| b.high).max().unwrap());
+            assert_eq!(bar.low, group.iter().map(|b| b.low).min().unwrap());
+            assert_eq!(bar.turnover, group.iter().map(|b| b.turnover).sum::<i128>());
+            assert_eq!(
+                bar.agg_record_count,
+                group.iter().map(|b| b.agg_record_count).sum::<u32>()
+            );
+            assert_eq!(bar.validate(), Ok(()));
+        }
+
+        assert_eq!(merge_bars(&fine, 1).len(), fine.len());
+    }
+
+    #[test]
+    fn test_merge_bars_approximates_coarse_run() {
+        let trades = drifting_trades(3_000);
+        let fine = RangeBarProcessor::new(100)
+            .unwrap()
+            .process_agg_trade_records(&trades)
+            .unwrap();
+        let coarse = RangeBarProcessor::new(200)
+            .unwrap()
+            .process_agg_trade_records(&trades)
+            .unwrap();
+        let merged = merge_bars(&fine, 2);
+
+        // Same starting point and trade coverage, comparable granularity
+        assert_eq!(merged[0].open, coarse[0].open);
+        assert_eq!(merged[0].open_time, coarse[0].open_time);
+        assert!(coarse.len() <= fine.len());
+        assert!(merged.len().abs_diff(coarse.len()) <= fine.len() / 2);
+
+        // Merging preserves totals exactly
+        let total = |bars: &[RangeBar]| bars.iter().map(|b| b.volume.0).sum::<i64>();
+        assert_eq!(total(&merged), total(&fine));
+    }
+}
@@ -7,7 +7,7 @@ use crate::checkpoint::{
     AnomalySummary, Checkpoint, CheckpointError, PositionVerification, PriceWindow,
 };
 use crate::fixed_point::FixedPoint;
-use crate::types::{AggTrade, RangeBar};
+use crate::types::{AggTrade, BarValidationError, CloseReason, DataSource, RangeBar};
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 use thiserror::Error;
@@ -36,6 +36,122 @@ pub struct RangeBarProcessor {
     /// Flag indicating this processor was created from a checkpoint
     /// When true, process_agg_trade_records will continue from existing bar state
     resumed_from_checkpoint: bool,
+
+    /// Reconcile buy + sell volume against total volume on every completed bar
+    strict_volume: bool,
+
+    /// Record each bar's fixed breach thresholds on the bar itself
+    annotate_thresholds: bool,
+
+    /// Record why each bar closed
+    record_close_reason: bool,
+
+    /// How to handle discontinuous individual trade IDs
+    gap_policy: GapPolicy,
+
+    /// `last_trade_id` of the previous record, for trade ID continuity checks
+    prev_last_trade_id: Option<i64>,
+
+    /// Gaps collected under [`GapPolicy::Warn`]
+    gap_warnings: Vec<TradeIdGap>,
+
+    /// Skip already-seen aggTrades in `process_single_trade`
+    dedup: bool,
+
+    /// Trades skipped by the duplicate guard
+    duplicates_skipped: u64,
+
+    /// Origin recorded on every emitted bar
+    data_source: DataSource,
+
+    /// Force-close bars holding this many aggTrade records (analysis mode)
+    max_trades_per_bar: Option<u32>,
+
+    /// Force-close bars at the UTC day boundary (analysis mode)
+    daily_utc_close: bool,
+
+    /// Cumulative processing counters
+    #[cfg(feature = "metrics")]
+    metrics: ProcessorMetrics,
+}
+
+/// Handling of missing individual trade IDs between consecutive aggTrades
+///
+/// Binance aggTrades cover contiguous individual trade ID ranges, so each
+/// record's `first_trade_id` should equal the previous record's
+/// `last_trade_id + 1`. A jump means trades are missing from the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GapPolicy {
+    /// Do not check continuity (default)
+    #[default]
+    Ignore,
+
+    /// Continue processing and collect each gap (see [`RangeBarProcessor::gap_warnings`])
+    Warn,
+
+    /// Fail with [`ProcessingError::TradeIdGap`] on the first gap
+    Error,
+}
+
+/// Discontinuity between consecutive aggTrades' individual trade IDs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TradeIdGap {
+    /// `agg_trade_id` of the record following the gap
+    pub agg_trade_id: i64,
+
+    /// Expected `first_trade_id` (previous `last_trade_id + 1`)
+    pub expected_first_trade_id: i64,
+
+    /// Observed `first_trade_id`
+    pub actual_first_trade_id: i64,
+}
+
+/// Cumulative counters for throughput profiling (`metrics` feature)
+///
+/// Counters accumulate across every processing call for the lifetime of the
+/// processor. Incomplete bars returned by
+/// [`process_agg_trade_records_with_incomplete`](RangeBarProcessor::process_agg_trade_records_with_incomplete)
+/// are not counted as emitted.
+#[cfg(feature = "metrics")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProcessorMetrics {
+    /// Trades consumed
+    pub trades_processed: u64,
+
+    /// Completed bars emitted
+    pub bars_emitted: u64,
+
+    /// Bars closed by breaching the upper threshold
+    pub breaches_up: u64,
+
+    /// Bars closed by breaching the lower threshold
+    pub breaches_down: u64,
+
+    /// Bars force-closed by `max_trades_per_bar`
+    pub trade_cap_closes: u64,
+
+    /// Bars force-closed at a session boundary
+    pub session_closes: u64,
+
+    /// Largest `individual_trade_count` of any emitted bar
+    pub max_bar_trade_count: u32,
+}
+
+#[cfg(feature = "metrics")]
+impl ProcessorMetrics {
+    /// Count a completed bar
+    fn record_bar(&mut self, bar: &RangeBar, reason: CloseReason) {
+        self.bars_emitted += 1;
+        match reason {
+            CloseReason::UpwardBreach => self.breaches_up += 1,
+            CloseReason::DownwardBreach => self.breaches_down += 1,
+            CloseReason::TradeCap => self.trade_cap_closes += 1,
+            CloseReason::SessionBoundary => self.session_closes += 1,
+            // Trailing incomplete bars are never recorded as completed
+            CloseReason::EndOfData => {}
+        }
+        self.max_bar_trade_count = self.max_bar_trade_count.max(bar.individual_trade_count);
+    }
 }
 
 impl RangeBarProcessor {
@@ -75,9 +191,208 @@ impl RangeBarProcessor {
             last_timestamp_us: 0,
             anomaly_summary: AnomalySummary::default(),
             resumed_from_checkpoint: false,
+            strict_volume: false,
+            annotate_thresholds: false,
+            record_close_reason: false,
+            gap_policy: GapPolicy::default(),
+            prev_last_trade_id: None,
+            gap_warnings: Vec::new(),
+            dedup: false,
+            duplicates_skipped: 0,
+            data_source: DataSource::default(),
+            max_trades_per_bar: None,
+            daily_utc_close: false,
+            #[cfg(feature = "metrics")]
+            metrics: ProcessorMetrics::default(),
         })
     }
 
+    /// Start a [`RangeBarProcessorBuilder`] for combining several options
+    pub fn builder(threshold_decimal_bps: u32) -> RangeBarProcessorBuilder {
+        RangeBarProcessorBuilder::new(threshold_decimal_bps)
+    }
+
+    /// Data source recorded on emitted bars (default [`DataSource::default`])
+    ///
+    /// Providers expose the value for their trades (e.g. Binance
+    /// `MarketType::data_source`), so bars from several providers can later be
+    /// partitioned by [`DataSource::provider`] and [`DataSource::market`].
+    pub fn with_data_source(mut self, data_source: DataSource) -> Self {
+        self.data_source = data_source;
+        self
+    }
+
+    /// Enable strict volume reconciliation (off by default)
+    ///
+    /// When enabled, every completed bar is checked with
+    /// [`RangeBar::validate_volume`] and processing fails with
+    /// [`ProcessingError::VolumeReconciliation`] on mismatch. `bar_index` is the
+    /// bar's position in the returned batch (always 0 for `process_single_trade`).
+    ///
+    /// The check runs on the completed bar, after the closing trade has been
+    /// folded in and position tracking has advanced past it. A processor that
+    /// returned this error is left mid-bar and must be discarded (or replaced
+    /// from a [`Checkpoint`] taken before the failing call).
+    pub fn with_strict_volume(mut self, strict_volume: bool) -> Self {
+        self.strict_volume = strict_volume;
+        self
+    }
+
+    /// Populate `threshold_upper` / `threshold_lower` on emitted bars (off by default)
+    ///
+    /// Lets downstream validators audit each close against the exact thresholds
+    /// the bar was built with. Off by default so serialized output is unchanged.
+    pub fn with_threshold_annotation(mut self, annotate_thresholds: bool) -> Self {
+        self.annotate_thresholds = annotate_thresholds;
+        self
+    }
+
+    /// Populate `close_reason` on emitted bars (off by default)
+    ///
+    /// Breach closes are marked up/down; the trailing bar returned by
+    /// [`process_agg_trade_records_with_incomplete`](Self::process_agg_trade_records_with_incomplete)
+    /// is marked [`CloseReason::EndOfData`].
+    pub fn with_close_reason(mut self, record_close_reason: bool) -> Self {
+        self.record_close_reason = record_close_reason;
+        self
+    }
+
+    /// Force-close any bar that accumulates `max` aggTrade records (off by default)
+    ///
+    /// **Analysis mode.** Capped bars close without a threshold breach, which
+    /// relaxes the range bar invariant; they are marked
+    /// [`CloseReason::TradeCap`] when [`with_close_reason`](Self::with_close_reason)
+    /// is enabled. A breach on the capping record takes precedence. The next
+    /// bar opens on the following trade, as after a breach in batch processing.
+    /// Caps below 2 are raised to 2 (the opening record plus one more).
+    pub fn with_max_trades_per_bar(mut self, max: Option<u32>) -> Self {
+        self.max_trades_per_bar = max.map(|max| max.max(2));
+        self
+    }
+
+    /// Close the open bar when a trade arrives on a later UTC day (off by default)
+    ///
+    /// **Analysis mode.** Keeps bars from spanning the daily session boundary
+    /// used for equity/forex reporting: the open bar closes *before* the first
+    /// trade of the new day, without a breach, and that trade opens the next
+    /// bar. Such bars are marked [`CloseReason::SessionBoundary`] when
+    /// [`with_close_reason`](Self::with_close_reason) is enabled.
+    pub fn with_daily_utc_close(mut self, daily_utc_close: bool) -> Self {
+        self.daily_utc_close = daily_utc_close;
+        self
+    }
+
+    /// Check individual trade ID continuity across records (off by default)
+    ///
+    /// Continuity is tracked across calls, so chunked input is checked at chunk
+    /// boundaries too. Detected gaps are also counted in
+    /// [`anomaly_summary`](Self::anomaly_summary).
+    pub fn with_gap_policy(mut self, gap_policy: GapPolicy) -> Self {
+        self.gap_policy = gap_policy;
+        self
+    }
+
+    /// Skip replayed aggTrades in [`process_single_trade`](Self::process_single_trade) (off by default)
+    ///
+    /// A trade whose `agg_trade_id` is not greater than the last processed one
+    /// is dropped and counted (see [`duplicates_skipped`](Self::duplicates_skipped))
+    /// instead of being folded into the bar again. Meant for replayed or
+    /// backfilled streams; batch methods keep rejecting such input with
+    /// [`ProcessingError::UnsortedTrades`].
+    pub fn with_dedup(mut self, dedup: bool) -> Self {
+        self.dedup = dedup;
+        self
+    }
+
+    /// Number of trades skipped by the duplicate guard
+    pub fn duplicates_skipped(&self) -> u64 {
+        self.duplicates_skipped
+    }
+
+    /// Gaps collected under [`GapPolicy::Warn`], oldest first
+    pub fn gap_warnings(&self) -> &[TradeIdGap] {
+        &self.gap_warnings
+    }
+
+    /// Drain collected gap warnings
+    pub fn take_gap_warnings(&mut self) -> Vec<TradeIdGap> {
+        std::mem::take(&mut self.gap_warnings)
+    }
+
+    /// Cumulative processing counters
+    #[cfg(feature = "metrics")]
+    pub fn metrics(&self) -> &ProcessorMetrics {
+        &self.metrics
+    }
+
+    /// Apply the gap policy to `trade`; `index` is its position in the current call
+    fn check_trade_id_gap(
+        &mut self,
+        index: usize,
+        trade: &AggTrade,
+    ) -> Result<(), ProcessingError> {
+        if self.gap_policy != GapPolicy::Ignore
+            && let Some(prev_last) = self.prev_last_trade_id
+            && trade.first_trade_id != prev_last + 1
+        {
+            let gap = TradeIdGap {
+                agg_trade_id: trade.agg_trade_id,
+                expected_first_trade_id: prev_last + 1,
+                actual_first_trade_id: trade.first_trade_id,
+            };
+            self.anomaly_summary.record_gap();
+            match self.gap_policy {
+                GapPolicy::Error => {
+                    return Err(ProcessingError::TradeIdGap {
+                        index,
+                        expected: gap.expected_first_trade_id,
+                        actual: gap.actual_first_trade_id,
+                    });
+                }
+                _ => self.gap_warnings.push(gap),
+            }
+        }
+        self.prev_last_trade_id = Some(trade.last_trade_id);
+        Ok(())
+    }
+
+    /// Open a new bar on `trade`, annotating thresholds if enabled
+    fn open_bar(&self, trade: &AggTrade) -> RangeBarState {
+        let mut state = RangeBarState::new(trade, self.threshold_decimal_bps);
+        state.bar.data_source = self.data_source;
+        if self.annotate_thresholds {
+            state.bar.threshold_upper = Some(state.upper_threshold);
+            state.bar.threshold_lower = Some(state.lower_threshold);
+        }
+        state
+    }
+
+    /// Bookkeeping for every completed bar: metrics counters and a trace event
+    #[cfg_attr(
+        not(any(feature = "metrics", feature = "tracing")),
+        allow(unused_variables)
+    )]
+    fn record_close(&mut self, bar: &RangeBar, reason: CloseReason) {
+        #[cfg(feature = "metrics")]
+        self.metrics.record_bar(bar, reason);
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            open_time = bar.open_time,
+            close_time = bar.close_time,
+            agg_records = bar.agg_record_count,
+            reason = ?reason,
+            "range bar closed"
+        );
+    }
+
+    /// Run the strict-mode volume check on a completed bar
+    fn reconcile_volume(&self, bar_index: usize, bar: &RangeBar) -> Result<(), ProcessingError> {
+        if !self.strict_volume {
+            return Ok(());
+        }
+        check_volume_reconciliation(bar_index, bar)
+    }
+
     /// Process a single trade and return completed bar if any
     ///
     /// Maintains internal state for streaming use case. State persists across calls
@@ -100,6 +415,21 @@ impl RangeBarProcessor {
         &mut self,
         trade: AggTrade,
     ) -> Result<Option<RangeBar>, ProcessingError> {
+        if self.dedup
+            && self
+                .last_trade_id
+                .is_some_and(|last| trade.agg_trade_id <= last)
+        {
+            self.duplicates_skipped += 1;
+            self.anomaly_summary.record_overlap();
+            return Ok(None);
+        }
+        self.check_trade_id_gap(0, &trade)?;
+        #[cfg(feature = "metrics")]
+        {
+            self.metrics.trades_processed += 1;
+        }
+
         // Track price and position for checkpoint
         self.price_window.push(trade.price);
         self.last_trade_id = Some(trade.agg_trade_id);
@@ -108,10 +438,20 @@ impl RangeBarProcessor {
         match &mut self.current_bar_state {
             None => {
                 // First trade - initialize new bar
-                self.current_bar_state =
-                    Some(RangeBarState::new(&trade, self.threshold_decimal_bps));
+                self.current_bar_state = Some(self.open_bar(&trade));
                 Ok(None)
             }
+            Some(bar_state) if self.daily_utc_close && crosses_utc_day(&bar_state.bar, &trade) => {
+                // Session boundary - close before this trade, which opens the next bar
+                if self.record_close_reason {
+                    bar_state.bar.close_reason = Some(CloseReason::SessionBoundary);
+                }
+                let completed_bar = bar_state.bar.clone();
+                self.current_bar_state = Some(self.open_bar(&trade));
+                self.reconcile_volume(0, &completed_bar)?;
+                self.record_close(&completed_bar, CloseReason::SessionBoundary);
+                Ok(Some(completed_bar))
+            }
             Some(bar_state) => {
                 // Check for threshold breach
                 if bar_state.bar.is_breach(
@@ -121,6 +461,10 @@ impl RangeBarProcessor {
                 ) {
                     // Breach detected - close current bar
                     bar_state.bar.update_with_trade(&trade);
+                    let reason = CloseReason::from_breach(trade.price, bar_state.upper_threshold);
+                    if self.record_close_reason {
+                        bar_state.bar.close_reason = Some(reason);
+                    }
 
                     // Validation: Ensure high/low include open/close extremes
                     debug_assert!(
@@ -129,16 +473,29 @@ impl RangeBarProcessor {
                     debug_assert!(bar_state.bar.low <= bar_state.bar.open.min(bar_state.bar.close));
 
                     let completed_bar = bar_state.bar.clone();
+                    self.reconcile_volume(0, &completed_bar)?;
+                    self.record_close(&completed_bar, reason);
 
                     // Start new bar with breaching trade
-                    self.current_bar_state =
-                        Some(RangeBarState::new(&trade, self.threshold_decimal_bps));
+                    self.current_bar_state = Some(self.open_bar(&trade));
 
                     Ok(Some(completed_bar))
                 } else {
                     // No breach - update existing bar
                     bar_state.bar.update_with_trade(&trade);
-                    Ok(None)
+                    if !at_trade_cap(self.max_trades_per_bar, &bar_state.bar) {
+                        return Ok(None);
+                    }
+
+                    // Trade cap reached - force-close; next trade opens a new bar
+                    if self.record_close_reason {
+                        bar_state.bar.close_reason = Some(CloseReason::TradeCap);
+                    }
+                    let completed_bar = bar_state.bar.clone();
+                    self.current_bar_state = None;
+                    self.reconcile_volume(0, &completed_bar)?;
+                    self.record_close(&completed_bar, CloseReason::TradeCap);
+                    Ok(Some(completed_bar))
                 }
             }
         }
@@ -149,6 +506,10 @@ impl RangeBarProcessor {
     /// Returns clone of current bar state for inspection without consuming it.
     /// Useful for final bar at stream end or progress monitoring.
     ///
+    /// Also valid after strict batch calls such as
+    /// [`process_agg_trade_records`](Self::process_agg_trade_records): the
+    /// trailing bar is kept until the next batch call starts fresh.
+    ///
     /// # Returns
     ///
     /// `Some(RangeBar)` if bar is in progress, `None` if no active bar
@@ -158,6 +519,17 @@ impl RangeBarProcessor {
             .map(|state| state.bar.clone())
     }
 
+    /// Fixed `(upper, lower)` breach thresholds of the bar in progress
+    ///
+    /// Thresholds are computed once from the bar's open and never move, so a
+    /// bar can only close on a trade at or beyond one of these prices.
+    /// `None` when no bar is open.
+    pub fn current_thresholds(&self) -> Option<(FixedPoint, FixedPoint)> {
+        self.current_bar_state
+            .as_ref()
+            .map(|state| (state.upper_threshold, state.lower_threshold))
+    }
+
     /// Process AggTrade records into range bars including incomplete bars for analysis
     ///
     /// # Arguments
@@ -193,6 +565,8 @@ impl RangeBarProcessor {
     ///
     /// Vector of completed range bars (ONLY bars that breached thresholds).
     /// Each bar tracks both individual trade count and AggTrade record count.
+    /// The trailing incomplete bar is not returned but remains available via
+    /// [`get_incomplete_bar`](Self::get_incomplete_bar).
     pub fn process_agg_trade_records(
         &mut self,
         agg_trade_records: &[AggTrade],
@@ -202,7 +576,9 @@ impl RangeBarProcessor {
 
     /// Process AggTrade records with options for including incomplete bars
     ///
-    /// Batch processing mode: Clears any existing state before processing.
+    /// Batch processing mode: Clears any existing state before processing, but
+    /// not after, so the bar still open at the end stays readable via
+    /// [`get_incomplete_bar`](Self::get_incomplete_bar).
     /// Use process_single_trade() for stateful streaming instead.
     ///
     /// # Parameters
@@ -213,13 +589,107 @@ impl RangeBarProcessor {
     /// # Returns
     ///
     /// Vector of range bars (completed + incomplete if requested)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(threshold_decimal_bps = self.threshold_decimal_bps)
+        )
+    )]
     pub fn process_agg_trade_records_with_options(
         &mut self,
         agg_trade_records: &[AggTrade],
         include_incomplete: bool,
     ) -> Result<Vec<RangeBar>, ProcessingError> {
+        #[cfg(feature = "tracing")]
+        let started = std::time::Instant::now();
+
+        let mut bars = Vec::with_capacity(agg_trade_records.len() / 100); // Heuristic capacity
+        self.process_with_sink(agg_trade_records, include_incomplete, |bar| {
+            bars.push(bar.clone())
+        })?;
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            trades = agg_trade_records.len(),
+            bars = bars.len(),
+            elapsed_us = started.elapsed().as_micros() as u64,
+            "processed trade batch"
+        );
+        Ok(bars)
+    }
+
+    /// Process AggTrade records, appending completed bars to a caller-owned buffer
+    ///
+    /// Same semantics as [`process_agg_trade_records`](Self::process_agg_trade_records),
+    /// but `out` is never cleared, so chunked callers can reuse one allocation.
+    /// On error, bars completed earlier in this call may already have been appended.
+    pub fn process_agg_trade_records_into(
+        &mut self,
+        agg_trade_records: &[AggTrade],
+        out: &mut Vec<RangeBar>,
+    ) -> Result<(), ProcessingError> {
+        self.process_with_sink(agg_trade_records, false, |bar| out.push(bar.clone()))
+    }
+
+    /// Process AggTrade records, invoking `on_bar` as each bar completes
+    ///
+    /// Same semantics as [`process_agg_trade_records`](Self::process_agg_trade_records)
+    /// without collecting an output vector, for unbounded streams and
+    /// write-through exporters. On error, `on_bar` may already have been called
+    /// for bars completed earlier in this call.
+    pub fn process_agg_trade_records_each(
+        &mut self,
+        agg_trade_records: &[AggTrade],
+        on_bar: impl FnMut(&RangeBar),
+    ) -> Result<(), ProcessingError> {
+        self.process_with_sink(agg_trade_records, false, on_bar)
+    }
+
+    /// Feed one trade with batch semantics, carrying the open bar across calls
+    ///
+    /// Backs [`RangeBarIterator`](crate::RangeBarIterator). Unlike
+    /// [`process_single_trade`](Self::process_single_trade), the bar after a
+    /// breach opens on the *next* trade, so output matches
+    /// [`process_agg_trade_records`](Self::process_agg_trade_records) over the
+    /// same sequence. `index` is the trade's position in the overall sequence.
+    pub(crate) fn process_next_trade(
+        &mut self,
+        index: usize,
+        trade: &AggTrade,
+    ) -> Result<Option<RangeBar>, ProcessingError> {
+        if let Some(prev_id) = self.last_trade_id
+            && (trade.timestamp < self.last_timestamp_us
+                || (trade.timestamp == self.last_timestamp_us && trade.agg_trade_id <= prev_id))
+        {
+            return Err(ProcessingError::UnsortedTrades {
+                index,
+                prev_time: self.last_timestamp_us,
+                prev_id,
+                curr_time: trade.timestamp,
+                curr_id: trade.agg_trade_id,
+            });
+        }
+
+        // Continue from the stored bar state instead of starting fresh
+        let open_bar = self.current_bar_state.take();
+        let mut completed = None;
+        self.process_from_state(std::slice::from_ref(trade), open_bar, false, |bar| {
+            completed = Some(bar.clone())
+        })?;
+        Ok(completed)
+    }
+
+    /// Shared batch loop; `bar_index` in errors is relative to this call
+    fn process_with_sink(
+        &mut self,
+        agg_trade_records: &[AggTrade],
+        include_incomplete: bool,
+        emit: impl FnMut(&RangeBar),
+    ) -> Result<(), ProcessingError> {
         if agg_trade_records.is_empty() {
-            return Ok(Vec::new());
+            return Ok(());
         }
 
         // Validate records are sorted
@@ -227,7 +697,7 @@ impl RangeBarProcessor {
 
         // Use existing bar state if resuming from checkpoint, otherwise start fresh
         // This is CRITICAL for cross-file continuation (Issues #2, #3)
-        let mut current_bar: Option<RangeBarState> = if self.resumed_from_checkpoint {
+        let current_bar: Option<RangeBarState> = if self.resumed_from_checkpoint {
             // Continue from checkpoint's incomplete bar
             self.resumed_from_checkpoint = false; // Consume the flag
             self.current_bar_state.take()
@@ -237,10 +707,35 @@ impl RangeBarProcessor {
             None
         };
 
-        let mut bars = Vec::with_capacity(agg_trade_records.len() / 100); // Heuristic capacity
+        self.process_from_state(agg_trade_records, current_bar, include_incomplete, emit)
+    }
+
+    /// Batch loop continuing `current_bar`; callers decide whether to resume or start fresh
+    fn process_from_state(
+        &mut self,
+        agg_trade_records: &[AggTrade],
+        mut current_bar: Option<RangeBarState>,
+        include_incomplete: bool,
+        mut emit: impl FnMut(&RangeBar),
+    ) -> Result<(), ProcessingError> {
+        let mut emitted = 0;
         let mut defer_open = false;
 
-        for agg_record in agg_trade_records {
+        // Index of the record that closes the open bar, found by a bulk pre-scan
+        // so the per-record loop below never evaluates the breach branch itself
+        let mut breach_index = current_bar
+            .as_ref()
+            .map_or(agg_trade_records.len(), |state| {
+                first_breach_index(agg_trade_records, 0, state)
+            });
+
+        for (index, agg_record) in agg_trade_records.iter().enumerate() {
+            self.check_trade_id_gap(index, agg_record)?;
+            #[cfg(feature = "metrics")]
+            {
+                self.metrics.trades_processed += 1;
+            }
+
             // Track price and position for checkpoint
             self.price_window.push(agg_record.price);
             self.last_trade_id = Some(agg_record.agg_trade_id);
@@ -248,7 +743,9 @@ impl RangeBarProcessor {
 
             if defer_open {
                 // Previous bar closed, this agg_record opens new bar
-                current_bar = Some(RangeBarState::new(agg_record, self.threshold_decimal_bps));
+                let state = self.open_bar(agg_record);
+                breach_index = first_breach_index(agg_trade_records, index + 1, &state);
+                current_bar = Some(state);
                 defer_open = false;
                 continue;
             }
@@ -256,17 +753,42 @@ impl RangeBarProcessor {
             match current_bar {
                 None => {
                     // First bar initialization
-                    current_bar = Some(RangeBarState::new(agg_record, self.threshold_decimal_bps));
+                    let state = self.open_bar(agg_record);
+                    breach_index = first_breach_index(agg_trade_records, index + 1, &state);
+                    current_bar = Some(state);
+                }
+                Some(ref mut bar_state)
+                    if self.daily_utc_close && crosses_utc_day(&bar_state.bar, agg_record) =>
+                {
+                    // Session boundary: close before this record, which opens the next bar
+                    if self.record_close_reason {
+                        bar_state.bar.close_reason = Some(CloseReason::SessionBoundary);
+                    }
+                    self.reconcile_volume(emitted, &bar_state.bar)?;
+                    self.record_close(&bar_state.bar, CloseReason::SessionBoundary);
+                    emit(&bar_state.bar);
+                    emitted += 1;
+
+                    let state = self.open_bar(agg_record);
+                    breach_index = first_breach_index(agg_trade_records, index + 1, &state);
+                    current_bar = Some(state);
                 }
                 Some(ref mut bar_state) => {
                     // Check if this AggTrade record breaches the threshold
-                    if bar_state.bar.is_breach(
-                        agg_record.price,
-                        bar_state.upper_threshold,
-                        bar_state.lower_threshold,
-                    ) {
+                    if index == breach_index {
+                        debug_assert!(bar_state.bar.is_breach(
+                            agg_record.price,
+                            bar_state.upper_threshold,
+                            bar_state.lower_threshold,
+                        ));
+
                         // Breach detected - update bar with breaching record (includes microstructure)
                         bar_state.bar.update_with_trade(agg_record);
+                        let reason =
+                            CloseReason::from_breach(agg_record.price, bar_state.upper_threshold);
+                        if self.record_close_reason {
+                            bar_state.bar.close_reason = Some(reason);
+                        }
 
                         // Validation: Ensure high/low include open/close extremes
                         debug_assert!(
@@ -276,12 +798,28 @@ impl RangeBarProcessor {
                             bar_state.bar.low <= bar_state.bar.open.min(bar_state.bar.close)
                         );
 
-                        bars.push(bar_state.bar.clone());
+                        self.reconcile_volume(emitted, &bar_state.bar)?;
+                        self.record_close(&bar_state.bar, reason);
+                        emit(&bar_state.bar);
+                        emitted += 1;
                         current_bar = None;
                         defer_open = true; // Next record will open new bar
                     } else {
                         // No breach: normal update with microstructure calculations
                         bar_state.bar.update_with_trade(agg_record);
+
+                        if at_trade_cap(self.max_trades_per_bar, &bar_state.bar) {
+                            // Trade cap reached: force-close without a breach
+                            if self.record_close_reason {
+                                bar_state.bar.close_reason = Some(CloseReason::TradeCap);
+                            }
+                            self.reconcile_volume(emitted, &bar_state.bar)?;
+                            self.record_close(&bar_state.bar, CloseReason::TradeCap);
+                            emit(&bar_state.bar);
+                            emitted += 1;
+                            current_bar = None;
+                            defer_open = true;
+                        }
                     }
                 }
             }
@@ -292,11 +830,14 @@ impl RangeBarProcessor {
 
         // Add final partial bar only if explicitly requested
         // This preserves algorithm integrity: bars should only close on threshold breach
-        if include_incomplete && let Some(bar_state) = current_bar {
-            bars.push(bar_state.bar);
+        if include_incomplete && let Some(mut bar_state) = current_bar {
+            if self.record_close_reason {
+                bar_state.bar.close_reason = Some(CloseReason::EndOfData);
+            }
+            emit(&bar_state.bar);
         }
 
-        Ok(bars)
+        Ok(())
     }
 
     // === CHECKPOINT METHODS ===
@@ -372,6 +913,10 @@ impl RangeBarProcessor {
             }),
             _ => None,
         };
+        let data_source = current_bar_state
+            .as_ref()
+            .map(|state| state.bar.data_source)
+            .unwrap_or_default();
 
         Ok(Self {
             threshold_decimal_bps: checkpoint.threshold_decimal_bps,
@@ -381,6 +926,19 @@ impl RangeBarProcessor {
             last_timestamp_us: checkpoint.last_timestamp_us,
             anomaly_summary: checkpoint.anomaly_summary,
             resumed_from_checkpoint: true, // Signal to continue from existing bar state
+            strict_volume: false,
+            annotate_thresholds: false,
+            record_close_reason: false,
+            gap_policy: GapPolicy::default(),
+            prev_last_trade_id: None,
+            gap_warnings: Vec::new(),
+            dedup: false,
+            duplicates_skipped: 0,
+            data_source,
+            max_trades_per_bar: None,
+            daily_utc_close: false,
+            #[cfg(feature = "metrics")]
+            metrics: ProcessorMetrics::default(),
         })
     }
 
@@ -443,33 +1001,205 @@ impl RangeBarProcessor {
         self.threshold_decimal_bps
     }
 
+    /// Validate every bar with [`RangeBar::validate`]
+    ///
+    /// Returns the index and violation of the first invalid bar.
+    pub fn validate_bars(bars: &[RangeBar]) -> Result<(), ProcessingError> {
+        for (bar_index, bar) in bars.iter().enumerate() {
+            bar.validate()
+                .map_err(|source| ProcessingError::InvalidBar { bar_index, source })?;
+        }
+        Ok(())
+    }
+
     /// Validate that trades are properly sorted for deterministic processing
     fn validate_trade_ordering(&self, trades: &[AggTrade]) -> Result<(), ProcessingError> {
-        for i in 1..trades.len() {
-            let prev = &trades[i - 1];
-            let curr = &trades[i];
+        validate_trade_ordering(trades)
+    }
+}
 
-            // Check ordering: (timestamp, agg_trade_id) ascending
-            if curr.timestamp < prev.timestamp
-                || (curr.timestamp == prev.timestamp && curr.agg_trade_id <= prev.agg_trade_id)
-            {
-                return Err(ProcessingError::UnsortedTrades {
-                    index: i,
-                    prev_time: prev.timestamp,
-                    prev_id: prev.agg_trade_id,
-                    curr_time: curr.timestamp,
-                    curr_id: curr.agg_trade_id,
-                });
-            }
+/// Validated, order-independent construction of a [`RangeBarProcessor`]
+///
+/// Collects every option first and checks the combination in
+/// [`build`](Self::build), instead of clamping values one `with_*` call at a
+/// time. Defaults match [`RangeBarProcessor::new`].
+///
+/// ```ignore
+/// let processor = RangeBarProcessor::builder(250)
+///     .with_close_reason(true)
+///     .with_max_trades_per_bar(Some(10_000))
+///     .build()?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct RangeBarProcessorBuilder {
+    threshold_decimal_bps: u32,
+    data_source: DataSource,
+    strict_volume: bool,
+    annotate_thresholds: bool,
+    record_close_reason: bool,
+    gap_policy: GapPolicy,
+    dedup: bool,
+    max_trades_per_bar: Option<u32>,
+    daily_utc_close: bool,
+}
+
+impl RangeBarProcessorBuilder {
+    /// Start a builder for `threshold_decimal_bps` (validated in [`build`](Self::build))
+    pub fn new(threshold_decimal_bps: u32) -> Self {
+        Self {
+            threshold_decimal_bps,
+            data_source: DataSource::default(),
+            strict_volume: false,
+            annotate_thresholds: false,
+            record_close_reason: false,
+            gap_policy: GapPolicy::default(),
+            dedup: false,
+            max_trades_per_bar: None,
+            daily_utc_close: false,
         }
+    }
 
-        Ok(())
+    /// See [`RangeBarProcessor::with_data_source`]
+    pub fn with_data_source(mut self, data_source: DataSource) -> Self {
+        self.data_source = data_source;
+        self
+    }
+
+    /// See [`RangeBarProcessor::with_strict_volume`]
+    pub fn with_strict_volume(mut self, strict_volume: bool) -> Self {
+        self.strict_volume = strict_volume;
+        self
+    }
+
+    /// See [`RangeBarProcessor::with_threshold_annotation`]
+    pub fn with_threshold_annotation(mut self, annotate_thresholds: bool) -> Self {
+        self.annotate_thresholds = annotate_thresholds;
+        self
     }
+
+    /// See [`RangeBarProcessor::with_close_reason`]
+    pub fn with_close_reason(mut self, record_close_reason: bool) -> Self {
+        self.record_close_reason = record_close_reason;
+        self
+    }
+
+    /// See [`RangeBarProcessor::with_gap_policy`]
+    pub fn with_gap_policy(mut self, gap_policy: GapPolicy) -> Self {
+        self.gap_policy = gap_policy;
+        self
+    }
+
+    /// See [`RangeBarProcessor::with_dedup`]
+    pub fn with_dedup(mut self, dedup: bool) -> Self {
+        self.dedup = dedup;
+        self
+    }
+
+    /// See [`RangeBarProcessor::with_max_trades_per_bar`]; must be at least 2
+    pub fn with_max_trades_per_bar(mut self, max: Option<u32>) -> Self {
+        self.max_trades_per_bar = max;
+        self
+    }
+
+    /// See [`RangeBarProcessor::with_daily_utc_close`]
+    pub fn with_daily_utc_close(mut self, daily_utc_close: bool) -> Self {
+        self.daily_utc_close = daily_utc_close;
+        self
+    }
+
+    /// Validate the configuration and create the processor
+    ///
+    /// # Errors
+    ///
+    /// - [`ProcessingError::InvalidThreshold`] - threshold outside 1..=100,000
+    /// - [`ProcessingError::InvalidParameter`] - `max_trades_per_bar` below 2
+    ///   (rather than silently raised as the `with_*` setter does)
+    pub fn build(self) -> Result<RangeBarProcessor, ProcessingError> {
+        if let Some(max) = self.max_trades_per_bar
+            && max < 2
+        {
+            return Err(ProcessingError::InvalidParameter {
+                name: "max_trades_per_bar",
+                value: max as f64,
+            });
+        }
+
+        Ok(RangeBarProcessor::new(self.threshold_decimal_bps)?
+            .with_data_source(self.data_source)
+            .with_strict_volume(self.strict_volume)
+            .with_threshold_annotation(self.annotate_thresholds)
+            .with_close_reason(self.record_close_reason)
+            .with_gap_policy(self.gap_policy)
+            .with_dedup(self.dedup)
+            .with_max_trades_per_bar(self.max_trades_per_bar)
+            .with_daily_utc_close(self.daily_utc_close))
+    }
+}
+
+/// Microseconds per UTC day
+const DAY_US: i64 = 86_400_000_000;
+
+/// Whether the open bar has reached `max_trades_per_bar`
+///
+/// Free function so it can be called while the bar state is mutably borrowed.
+fn at_trade_cap(max_trades_per_bar: Option<u32>, bar: &RangeBar) -> bool {
+    max_trades_per_bar.is_some_and(|max| bar.agg_record_count >= max)
+}
+
+/// Whether `trade` falls on a later UTC day than the bar's open
+fn crosses_utc_day(bar: &RangeBar, trade: &AggTrade) -> bool {
+    trade.timestamp.div_euclid(DAY_US) != bar.open_time.div_euclid(DAY_US)
+}
+
+/// Check records are sorted by (timestamp, agg_trade_id) ascending
+pub(crate) fn validate_trade_ordering(trades: &[AggTrade]) -> Result<(), ProcessingError> {
+    for i in 1..trades.len() {
+        let prev = &trades[i - 1];
+        let curr = &trades[i];
+
+        // Check ordering: (timestamp, agg_trade_id) ascending
+        if curr.timestamp < prev.timestamp
+            || (curr.timestamp == prev.timestamp && curr.agg_trade_id <= prev.agg_trade_id)
+        {
+            return Err(ProcessingError::UnsortedTrades {
+                index: i,
+                prev_time: prev.timestamp,
+                prev_id: prev.agg_trade_id,
+                curr_time: curr.timestamp,
+                curr_id: curr.agg_trade_id,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Stably sort trades by (timestamp, agg_trade_id), returning how many moved
+///
+/// Repairs near-sorted archives so they pass [`RangeBarProcessor`]'s ordering
+/// check. Already-sorted input is detected in one pass and left untouched.
+///
+/// Reordering is opt-in on purpose: out-of-order records can signal upstream
+/// defects (clock skew, merged files, replayed segments) that a silent sort
+/// would hide. Log or inspect a non-zero return value rather than ignoring it.
+pub fn sort_trades_stable(trades: &mut [AggTrade]) -> usize {
+    let key = |trade: &AggTrade| (trade.timestamp, trade.agg_trade_id);
+    if trades.is_sorted_by_key(key) {
+        return 0;
+    }
+
+    let original: Vec<(i64, i64)> = trades.iter().map(key).collect();
+    trades.sort_by_key(key);
+    trades
+        .iter()
+        .zip(&original)
+        .filter(|&(trade, before)| key(trade) != *before)
+        .count()
 }
 
 /// Internal state for a range bar being built
-#[derive(Clone)]
-struct RangeBarState {
+#[derive(Debug, Clone)]
+pub(crate) struct RangeBarState {
     /// The range bar being constructed
     pub bar: RangeBar,
 
@@ -482,7 +1212,7 @@ struct RangeBarState {
 
 impl RangeBarState {
     /// Create new range bar state from opening trade
-    fn new(trade: &AggTrade, threshold_decimal_bps: u32) -> Self {
+    pub(crate) fn new(trade: &AggTrade, threshold_decimal_bps: u32) -> Self {
         let bar = RangeBar::new(trade);
 
         // Compute FIXED thresholds from opening price
@@ -497,6 +1227,59 @@ impl RangeBarState {
     }
 }
 
+/// Records compared per step of the breach pre-scan
+const BREACH_SCAN_LANES: usize = 8;
+
+/// Find the first record at or after `from` that breaches `state`'s thresholds
+///
+/// Prices are gathered into fixed-width `i64` lanes and reduced with
+/// non-short-circuiting comparisons so the compiler can vectorize the scan;
+/// only the lane group containing a breach is re-scanned scalar to locate it.
+/// Uses the same inclusive comparison as [`RangeBar::is_breach`].
+///
+/// Returns `records.len()` when no record breaches.
+fn first_breach_index(records: &[AggTrade], from: usize, state: &RangeBarState) -> usize {
+    let upper = state.upper_threshold.0;
+    let lower = state.lower_threshold.0;
+    let breaches = |price: i64| price >= upper || price <= lower;
+
+    let tail = records.get(from..).unwrap_or_default();
+    let mut chunks = tail.chunks_exact(BREACH_SCAN_LANES);
+    let mut offset = from;
+
+    for chunk in &mut chunks {
+        let mut prices = [0i64; BREACH_SCAN_LANES];
+        for (lane, record) in prices.iter_mut().zip(chunk) {
+            *lane = record.price.0;
+        }
+
+        let any_breach = prices.iter().fold(false, |acc, &price| {
+            acc | (price >= upper) | (price <= lower)
+        });
+        if any_breach {
+            let lane = prices.iter().position(|&price| breaches(price));
+            return offset + lane.unwrap_or(BREACH_SCAN_LANES);
+        }
+        offset += BREACH_SCAN_LANES;
+    }
+
+    chunks
+        .remainder()
+        .iter()
+        .position(|record| breaches(record.price.0))
+        .map_or(records.len(), |lane| offset + lane)
+}
+
+/// Map a bar's volume-split violation to [`ProcessingError::VolumeReconciliation`]
+fn check_volume_reconciliation(bar_index: usize, bar: &RangeBar) -> Result<(), ProcessingError> {
+    bar.validate_volume()
+        .map_err(|_| ProcessingError::VolumeReconciliation {
+            bar_index,
+            volume: bar.volume,
+            buy_plus_sell: FixedPoint(bar.buy_volume.0.saturating_add(bar.sell_volume.0)),
+        })
+}
+
 /// Processing errors
 #[derive(Error, Debug)]
 pub enum ProcessingError {
@@ -518,6 +1301,39 @@ pub enum ProcessingError {
         "Invalid threshold: {threshold_decimal_bps} (decimal bps). Valid range: 1-100,000 (0.001%-100%)"
     )]
     InvalidThreshold { threshold_decimal_bps: u32 },
+
+    #[error("Invalid {name}: {value}")]
+    InvalidParameter { name: &'static str, value: f64 },
+
+    /// Strict-mode volume split mismatch; the processor must be discarded
+    /// (see [`RangeBarProcessor::with_strict_volume`])
+    #[error(
+        "Volume reconciliation failed for bar {bar_index}: volume={volume}, buy+sell={buy_plus_sell}"
+    )]
+    VolumeReconciliation {
+        bar_index: usize,
+        volume: FixedPoint,
+        buy_plus_sell: FixedPoint,
+    },
+
+    #[error("Invalid bar at index {bar_index}: {source}")]
+    InvalidBar {
+        bar_index: usize,
+        #[source]
+        source: BarValidationError,
+    },
+
+    #[error("Trade ID gap at index {index}: expected first_trade_id {expected}, got {actual}")]
+    TradeIdGap {
+        index: usize,
+        expected: i64,
+        actual: i64,
+    },
+
+    #[error(
+        "Ambiguous threshold {threshold}: values below 10 need an explicit unit ('bps' or 'tenths_bps'); thresholds are decimal bps since v3 (e.g. 25 bps = 250)"
+    )]
+    AmbiguousThresholdUnit { threshold: u32 },
 }
 
 #[cfg(feature = "python")]
@@ -543,6 +1359,34 @@ impl From<ProcessingError> for PyErr {
                 "Invalid threshold: {} (decimal bps). Valid range: 1-100,000 (0.001%-100%)",
                 threshold_decimal_bps
             )),
+            ProcessingError::InvalidParameter { name, value } => {
+                pyo3::exceptions::PyValueError::new_err(format!("Invalid {}: {}", name, value))
+            }
+            ProcessingError::VolumeReconciliation {
+                bar_index,
+                volume,
+                buy_plus_sell,
+            } => pyo3::exceptions::PyValueError::new_err(format!(
+                "Volume reconciliation failed for bar {}: volume={}, buy+sell={}",
+                bar_index, volume, buy_plus_sell
+            )),
+            ProcessingError::InvalidBar { bar_index, source } => {
+                pyo3::exceptions::PyValueError::new_err(format!(
+                    "Invalid bar at index {}: {}",
+                    bar_index, source
+                ))
+            }
+            ProcessingError::TradeIdGap {
+                index,
+                expected,
+                actual,
+            } => pyo3::exceptions::PyValueError::new_err(format!(
+                "Trade ID gap at index {}: expected first_trade_id {}, got {}",
+                index, expected, actual
+            )),
+            err @ ProcessingError::AmbiguousThresholdUnit { .. } => {
+                pyo3::exceptions::PyValueError::new_err(err.to_string())
+            }
         }
     }
 }
@@ -639,6 +1483,42 @@ mod tests {
         assert_eq!(bar.low.to_string(), "49875.00000000");
     }
 
+    #[test]
+    fn test_threshold_touch_tie_breaking() {
+        // open 50000 at 250 (25bps): thresholds are exactly 50125 / 49875
+        let (upper, lower) = FixedPoint::from_str("50000.0")
+            .unwrap()
+            .compute_range_thresholds(250);
+        assert_eq!(upper.to_string(), "50125.00000000");
+        assert_eq!(lower.to_string(), "49875.00000000");
+
+        let run = |touch: &str| {
+            let trades = vec![
+                test_utils::create_test_agg_trade(1, "50000.0", "1.0", 1000),
+                test_utils::create_test_agg_trade(2, touch, "2.0", 2000),
+            ];
+            RangeBarProcessor::new(250)
+                .unwrap()
+                .process_agg_trade_records(&trades)
+                .unwrap()
+        };
+
+        // Exact touch closes the bar with the touching trade included
+        for touch in ["50125.00000000", "49875.00000000"] {
+            let bars = run(touch);
+            assert_eq!(bars.len(), 1, "touch at {touch} must close");
+            assert_eq!(bars[0].close.to_string(), touch);
+            assert_eq!(bars[0].close_time, 2000);
+            assert_eq!(bars[0].agg_record_count, 2);
+            assert_eq!(bars[0].volume.to_string(), "3.00000000");
+        }
+
+        // One tick inside either threshold does not close
+        for inside in ["50124.99999999", "49875.00000001"] {
+            assert!(run(inside).is_empty(), "{inside} must not close");
+        }
+    }
+
     #[test]
     fn test_large_gap_single_bar() {
         let mut processor = RangeBarProcessor::new(250).unwrap(); // 250 × 0.1bps = 25bps = 0.25%
@@ -674,6 +1554,245 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_sort_trades_stable_repairs_near_sorted_input() {
+        let trade =
+            |id, timestamp| test_utils::create_test_agg_trade(id, "50000.0", "1.0", timestamp);
+        let mut trades = vec![
+            trade(1, 1000),
+            trade(3, 3000),
+            trade(2, 2000), // arrived late
+            trade(4, 4000),
+            trade(6, 5000),
+            trade(5, 5000), // same timestamp, ids swapped
+            trade(7, 6000),
+        ];
+
+        let mut processor = RangeBarProcessor::new(250).unwrap();
+        assert!(processor.process_agg_trade_records(&trades).is_err());
+
+        assert_eq!(sort_trades_stable(&mut trades), 4);
+        let ids: Vec<i64> = trades.iter().map(|t| t.agg_trade_id).collect();
+        assert_eq!(ids, vec![1, 2, 3, 4, 5, 6, 7]);
+        assert!(validate_trade_ordering(&trades).is_ok());
+
+        // Sorted input is left as-is
+        assert_eq!(sort_trades_stable(&mut trades), 0);
+    }
+
+    #[test]
+    fn test_builder_applies_combined_options() {
+        let mut processor = RangeBarProcessor::builder(250)
+            .with_max_trades_per_bar(Some(3))
+            .with_dedup(true)
+            .with_close_reason(true)
+            .with_threshold_annotation(true)
+            .with_data_source(DataSource::Kraken)
+            .build()
+            .unwrap();
+        assert_eq!(processor.threshold_decimal_bps(), 250);
+
+        let trade = |id, price| test_utils::create_test_agg_trade(id, price, "1.0", id * 1000);
+        let mut bars = Vec::new();
+        for t in [
+            trade(1, "50000.0"),
+            trade(2, "50010.0"),
+            trade(2, "50010.0"), // replay, skipped by dedup
+            trade(3, "50020.0"),
+            trade(4, "50200.0"), // opens the next bar
+            trade(5, "50400.0"), // breach
+        ] {
+            bars.extend(processor.process_single_trade(t).unwrap());
+        }
+
+        assert_eq!(processor.duplicates_skipped(), 1);
+        assert_eq!(bars.len(), 2);
+        assert_eq!(bars[0].agg_record_count, 3);
+        assert_eq!(bars[0].close_reason, Some(CloseReason::TradeCap));
+        assert_eq!(bars[1].close_reason, Some(CloseReason::UpwardBreach));
+        assert_eq!(
+            bars[1].threshold_upper,
+            Some(FixedPoint::from_str("50325.5").unwrap())
+        );
+        assert!(bars.iter().all(|b| b.data_source == DataSource::Kraken));
+    }
+
+    #[test]
+    fn test_builder_rejects_invalid_configuration() {
+        assert!(matches!(
+            RangeBarProcessor::builder(0).build(),
+            Err(ProcessingError::InvalidThreshold { .. })
+        ));
+        for max in [0, 1] {
+            assert!(matches!(
+                RangeBarProcessor::builder(250)
+                    .with_max_trades_per_bar(Some(max))
+                    .build(),
+                Err(ProcessingError::InvalidParameter {
+                    name: "max_trades_per_bar",
+                    ..
+                })
+            ));
+        }
+        assert!(RangeBarProcessor::builder(250).build().is_ok());
+    }
+
+    #[test]
+    fn test_daily_utc_close_splits_at_midnight() {
+        // 2024-01-15 00:00:00 UTC in microseconds
+        let midnight = 1_705_276_800_000_000;
+        let trades = vec![
+            test_utils::create_test_agg_trade(1, "50000.0", "1.0", midnight - 2_000_000),
+            test_utils::create_test_agg_trade(2, "50010.0", "1.0", midnight - 1_000_000),
+            test_utils::create_test_agg_trade(3, "50020.0", "1.0", midnight + 1_000_000),
+            test_utils::create_test_agg_trade(4, "50030.0", "1.0", midnight + 2_000_000),
+        ];
+
+        let mut plain = RangeBarProcessor::new(250).unwrap();
+        assert!(plain.process_agg_trade_records(&trades).unwrap().is_empty());
+
+        let mut processor = RangeBarProcessor::new(250)
+            .unwrap()
+            .with_close_reason(true)
+            .with_daily_utc_close(true);
+        let bars = processor
+            .process_agg_trade_records_with_incomplete(&trades)
+            .unwrap();
+
+        assert_eq!(bars.len(), 2);
+        assert_eq!(bars[0].close_reason, Some(CloseReason::SessionBoundary));
+        assert_eq!(bars[0].agg_record_count, 2);
+        assert!(bars[0].close_time < midnight);
+        assert_eq!(bars[1].open_time, midnight + 1_000_000);
+        assert_eq!(bars[1].open.to_string(), "50020.00000000");
+        assert_eq!(bars[1].close_reason, Some(CloseReason::EndOfData));
+
+        let mut streaming = RangeBarProcessor::new(250)
+            .unwrap()
+            .with_daily_utc_close(true);
+        let streamed: Vec<RangeBar> = trades
+            .iter()
+            .filter_map(|t| streaming.process_single_trade(t.clone()).unwrap())
+            .collect();
+        assert_eq!(streamed.len(), 1);
+        assert_eq!(streamed[0].to_csv_row(), {
+            let mut bar = bars[0].clone();
+            bar.close_reason = None;
+            bar.to_csv_row()
+        });
+        assert_eq!(
+            streaming.get_incomplete_bar().unwrap().open_time,
+            midnight + 1_000_000
+        );
+    }
+
+    #[test]
+    fn test_current_thresholds() {
+        let mut processor = RangeBarProcessor::new(250).unwrap();
+        assert_eq!(processor.current_thresholds(), None);
+
+        let open = test_utils::create_test_agg_trade(1, "50000.0", "1.0", 1000);
+        processor.process_single_trade(open).unwrap();
+        let expected = (
+            FixedPoint::from_str("50125.0").unwrap(),
+            FixedPoint::from_str("49875.0").unwrap(),
+        );
+        assert_eq!(processor.current_thresholds(), Some(expected));
+
+        // Fixed for the bar's lifetime regardless of later prices
+        let inside = test_utils::create_test_agg_trade(2, "50100.0", "1.0", 2000);
+        processor.process_single_trade(inside).unwrap();
+        assert_eq!(processor.current_thresholds(), Some(expected));
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn test_bar_close_emits_tracing_event() {
+        use std::sync::{Arc, Mutex};
+        use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+
+        /// Collects the message of every event
+        struct CaptureMessages(Arc<Mutex<Vec<String>>>);
+
+        struct MessageVisitor(String);
+
+        impl tracing::field::Visit for MessageVisitor {
+            fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+                if field.name() == "message" {
+                    self.0 = format!("{value:?}");
+                }
+            }
+        }
+
+        impl<S: tracing::Subscriber> Layer<S> for CaptureMessages {
+            fn on_event(&self, event: &tracing::Event<'_>, _ctx: Context<'_, S>) {
+                let mut visitor = MessageVisitor(String::new());
+                event.record(&mut visitor);
+                self.0.lock().unwrap().push(visitor.0);
+            }
+        }
+
+        let messages = Arc::new(Mutex::new(Vec::new()));
+        let subscriber =
+            tracing_subscriber::registry().with(CaptureMessages(Arc::clone(&messages)));
+
+        let trades = [
+            test_utils::create_test_agg_trade(1, "50000.0", "1.0", 1000),
+            test_utils::create_test_agg_trade(2, "50200.0", "1.0", 2000),
+            test_utils::create_test_agg_trade(3, "50300.0", "1.0", 3000),
+        ];
+        let bars = tracing::subscriber::with_default(subscriber, || {
+            RangeBarProcessor::new(250)
+                .unwrap()
+                .process_agg_trade_records(&trades)
+                .unwrap()
+        });
+        assert_eq!(bars.len(), 1);
+
+        let messages = messages.lock().unwrap();
+        assert_eq!(
+            messages.iter().filter(|m| *m == "range bar closed").count(),
+            1
+        );
+        assert!(messages.iter().any(|m| m == "processed trade batch"));
+    }
+
+    #[test]
+    fn test_incomplete_bar_available_after_strict_batch() {
+        let mut processor = RangeBarProcessor::new(250).unwrap();
+
+        // No breach: nothing returned, but the whole run is the open bar
+        let quiet = [
+            test_utils::create_test_agg_trade(1, "50000.0", "1.0", 1000),
+            test_utils::create_test_agg_trade(2, "50050.0", "1.0", 2000),
+            test_utils::create_test_agg_trade(3, "49950.0", "1.0", 3000),
+        ];
+        assert!(
+            processor
+                .process_agg_trade_records(&quiet)
+                .unwrap()
+                .is_empty()
+        );
+        let partial = processor.get_incomplete_bar().unwrap();
+        assert_eq!(partial.agg_record_count, 3);
+        assert_eq!(partial.close, FixedPoint::from_str("49950.0").unwrap());
+
+        // Next batch starts fresh: one breach, then a partial bar left open
+        let breaking = [
+            test_utils::create_test_agg_trade(4, "50000.0", "1.0", 4000),
+            test_utils::create_test_agg_trade(5, "50200.0", "1.0", 5000),
+            test_utils::create_test_agg_trade(6, "50300.0", "1.0", 6000),
+            test_utils::create_test_agg_trade(7, "50350.0", "1.0", 7000),
+        ];
+        let bars = processor.process_agg_trade_records(&breaking).unwrap();
+        assert_eq!(bars.len(), 1);
+        assert_eq!(bars[0].agg_record_count, 2);
+
+        let partial = processor.get_incomplete_bar().unwrap();
+        assert_eq!(partial.open, FixedPoint::from_str("50300.0").unwrap());
+        assert_eq!(partial.agg_record_count, 2);
+    }
+
     #[test]
     fn test_threshold_calculation() {
         let processor = RangeBarProcessor::new(250).unwrap(); // 250 × 0.1bps = 25bps = 0.25%
@@ -694,6 +1813,528 @@ mod tests {
         assert_eq!(bars.len(), 0);
     }
 
+    #[test]
+    fn test_validate_bars() {
+        let mut processor = RangeBarProcessor::new(250).unwrap();
+        let trades = scenarios::large_sequence(2_000);
+        let mut bars = processor
+            .process_agg_trade_records_with_incomplete(&trades)
+            .unwrap();
+        assert!(bars.len() > 1);
+        assert!(RangeBarProcessor::validate_bars(&bars).is_ok());
+
+        bars[1].high = FixedPoint(bars[1].low.0 - 1);
+        match RangeBarProcessor::validate_bars(&bars) {
+            Err(ProcessingError::InvalidBar {
+                bar_index,
+                source: BarValidationError::HighBelowLow { .. },
+            }) => assert_eq!(bar_index, 1),
+            other => panic!("Expected InvalidBar at index 1, got {:?}", other),
+        }
+    }
+
+    /// Alternating buy/sell trades with varying volumes and a breach every few trades
+    fn mixed_flow_sequence(count: i64) -> Vec<AggTrade> {
+        (0..count)
+            .map(|i| {
+                let price = 50000.0 + ((i % 7) as f64 - 3.0) * 60.0 + (i / 7) as f64 * 40.0;
+                let volume = format!("{}.{:08}", 1 + i % 3, (i * 12_345) % 100_000_000);
+                test_utils::create_test_agg_trade(
+                    i + 1,
+                    &format!("{:.8}", price),
+                    &volume,
+                    1_000 + i,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_strict_volume_matches_default_output() {
+        let trades = mixed_flow_sequence(5_000);
+
+        let mut default_processor = RangeBarProcessor::new(250).unwrap();
+        let expected = default_processor
+            .process_agg_trade_records(&trades)
+            .unwrap();
+
+        // Regression guard: any divergence between total and buy/sell accumulation fails here
+        let mut strict = RangeBarProcessor::new(250)
+            .unwrap()
+            .with_strict_volume(true);
+        let bars = strict.process_agg_trade_records(&trades).unwrap();
+        assert!(!bars.is_empty());
+        assert_eq!(bars.len(), expected.len());
+
+        let mut streaming = RangeBarProcessor::new(250)
+            .unwrap()
+            .with_strict_volume(true);
+        for trade in &trades {
+            streaming.process_single_trade(trade.clone()).unwrap();
+        }
+
+        // The export processor accumulates volumes separately; hold it to the same invariant
+        let mut export = ExportRangeBarProcessor::new(250).unwrap();
+        export.process_trades_continuously(&trades);
+        for (i, bar) in export.get_all_completed_bars().iter().enumerate() {
+            check_volume_reconciliation(i, bar).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_volume_reconciliation_error() {
+        let trade = test_utils::create_test_agg_trade(1, "50000.0", "2.0", 1000);
+        let mut bar = RangeBar::new(&trade);
+        bar.sell_volume = FixedPoint::from_str("0.5").unwrap();
+
+        match check_volume_reconciliation(3, &bar) {
+            Err(ProcessingError::VolumeReconciliation {
+                bar_index,
+                volume,
+                buy_plus_sell,
+            }) => {
+                assert_eq!(bar_index, 3);
+                assert_eq!(volume.to_string(), "2.00000000");
+                assert_eq!(buy_plus_sell.to_string(), "2.50000000");
+            }
+            other => panic!("Expected VolumeReconciliation, got {:?}", other),
+        }
+    }
+
+    /// Batch semantics with a per-record `is_breach` check instead of the pre-scan
+    ///
+    /// The record after a breach opens the next bar. Returns the completed bars
+    /// and the trailing incomplete bar.
+    #[test]
+    fn test_breach_prescan_matches_scalar_batch_loop() {
+        // Exercises full lane groups, remainders, and back-to-back breaches
+        for (count, threshold) in [(5_000, 250), (5_003, 80), (13, 250), (9, 10)] {
+            let trades = mixed_flow_sequence(count);
+
+            let mut batch = RangeBarProcessor::new(threshold).unwrap();
+            let bars = batch.process_agg_trade_records(&trades).unwrap();
+            let (expected, expected_incomplete) =
+                test_utils::generators::process_scalar_batch(&trades, threshold);
+
+            assert!(!expected.is_empty(), "count={count}, threshold={threshold}");
+            assert_eq!(
+                serde_json::to_string(&bars).unwrap(),
+                serde_json::to_string(&expected).unwrap(),
+                "count={count}, threshold={threshold}"
+            );
+            assert_eq!(
+                serde_json::to_string(&batch.get_incomplete_bar()).unwrap(),
+                serde_json::to_string(&expected_incomplete).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_process_into_matches_allocating_across_chunks() {
+        let trades = mixed_flow_sequence(3_000);
+
+        let mut allocating = RangeBarProcessor::new(250).unwrap();
+        let mut expected = Vec::new();
+        for chunk in trades.chunks(700) {
+            expected.extend(allocating.process_agg_trade_records(chunk).unwrap());
+        }
+
+        let mut reusing = RangeBarProcessor::new(250).unwrap();
+        let mut out = Vec::new();
+        for chunk in trades.chunks(700) {
+            reusing
+                .process_agg_trade_records_into(chunk, &mut out)
+                .unwrap();
+        }
+
+        assert!(!out.is_empty());
+        assert_eq!(
+            serde_json::to_string(&out).unwrap(),
+            serde_json::to_string(&expected).unwrap()
+        );
+
+        // Existing contents are preserved
+        let sentinel = out[0].clone();
+        let mut seeded = vec![sentinel.clone()];
+        RangeBarProcessor::new(250)
+            .unwrap()
+            .process_agg_trade_records_into(&trades, &mut seeded)
+            .unwrap();
+        assert_eq!(seeded[0].open_time, sentinel.open_time);
+        assert!(seeded.len() > 1);
+    }
+
+    #[test]
+    fn test_threshold_annotation() {
+        let trades = mixed_flow_sequence(2_000);
+
+        let mut annotated = RangeBarProcessor::new(250)
+            .unwrap()
+            .with_threshold_annotation(true);
+        let bars = annotated
+            .process_agg_trade_records_with_incomplete(&trades)
+            .unwrap();
+        assert!(bars.len() > 1);
+        for bar in &bars {
+            let (upper, lower) = bar.open.compute_range_thresholds(250);
+            assert_eq!(bar.threshold_upper, Some(upper));
+            assert_eq!(bar.threshold_lower, Some(lower));
+        }
+
+        let mut streaming = RangeBarProcessor::new(250)
+            .unwrap()
+            .with_threshold_annotation(true);
+        for trade in &trades {
+            if let Some(bar) = streaming.process_single_trade(trade.clone()).unwrap() {
+                assert_eq!(
+                    bar.threshold_upper,
+                    Some(bar.open.compute_range_thresholds(250).0)
+                );
+            }
+        }
+
+        // Default output omits the fields entirely
+        let mut plain = RangeBarProcessor::new(250).unwrap();
+        let plain_bars = plain.process_agg_trade_records(&trades).unwrap();
+        assert_eq!(plain_bars[0].threshold_upper, None);
+        let json = serde_json::to_string(&plain_bars[0]).unwrap();
+        assert!(!json.contains("threshold_upper"));
+    }
+
+    #[test]
+    fn test_close_reason_breach_directions() {
+        let trades = vec![
+            test_utils::create_test_agg_trade(1, "50000.0", "1.0", 1000),
+            test_utils::create_test_agg_trade(2, "50125.0", "1.0", 2000), // up breach
+            test_utils::create_test_agg_trade(3, "50100.0", "1.0", 3000), // opens bar 2
+            test_utils::create_test_agg_trade(4, "49974.75", "1.0", 4000), // down breach
+        ];
+
+        let mut processor = RangeBarProcessor::new(250).unwrap().with_close_reason(true);
+        let bars = processor.process_agg_trade_records(&trades).unwrap();
+        assert_eq!(bars.len(), 2);
+        assert_eq!(bars[0].close_reason, Some(CloseReason::UpwardBreach));
+        assert_eq!(bars[1].close_reason, Some(CloseReason::DownwardBreach));
+
+        let mut streaming = RangeBarProcessor::new(250).unwrap().with_close_reason(true);
+        let closed: Vec<RangeBar> = trades[..2]
+            .iter()
+            .filter_map(|t| streaming.process_single_trade(t.clone()).unwrap())
+            .collect();
+        assert_eq!(closed[0].close_reason, Some(CloseReason::UpwardBreach));
+    }
+
+    #[test]
+    fn test_max_trades_per_bar_splits_flat_stream() {
+        // 1,000 trades oscillating well inside a 25bps band: never breaches
+        let trades: Vec<AggTrade> = (0..1_000)
+            .map(|i| {
+                let price = if i % 2 == 0 { "50000.0" } else { "50010.0" };
+                test_utils::create_test_agg_trade(i + 1, price, "1.0", 1_000 + i)
+            })
+            .collect();
+
+        let mut uncapped = RangeBarProcessor::new(250).unwrap();
+        assert!(
+            uncapped
+                .process_agg_trade_records(&trades)
+                .unwrap()
+                .is_empty()
+        );
+
+        let mut processor = RangeBarProcessor::new(250)
+            .unwrap()
+            .with_close_reason(true)
+            .with_max_trades_per_bar(Some(100));
+        let bars = processor.process_agg_trade_records(&trades).unwrap();
+
+        // Capped bars cover 100 records each; the next opens on the following trade
+        assert_eq!(bars.len(), 10);
+        for (i, bar) in bars.iter().enumerate() {
+            assert_eq!(bar.agg_record_count, 100);
+            assert_eq!(bar.first_trade_id, (i as i64 * 100 + 1) * 10);
+            assert_eq!(bar.close_reason, Some(CloseReason::TradeCap));
+        }
+
+        let mut streaming = RangeBarProcessor::new(250)
+            .unwrap()
+            .with_max_trades_per_bar(Some(100));
+        let streamed: Vec<RangeBar> = trades
+            .iter()
+            .filter_map(|t| streaming.process_single_trade(t.clone()).unwrap())
+            .collect();
+        assert_eq!(streamed.len(), 10);
+        assert!(streamed.iter().all(|b| b.agg_record_count == 100));
+    }
+
+    #[test]
+    fn test_close_reason_end_of_data() {
+        let trades = vec![
+            test_utils::create_test_agg_trade(1, "50000.0", "1.0", 1000),
+            test_utils::create_test_agg_trade(2, "50125.0", "1.0", 2000),
+            test_utils::create_test_agg_trade(3, "50100.0", "1.0", 3000),
+            test_utils::create_test_agg_trade(4, "50110.0", "1.0", 4000),
+        ];
+
+        let mut processor = RangeBarProcessor::new(250).unwrap().with_close_reason(true);
+        let bars = processor
+            .process_agg_trade_records_with_incomplete(&trades)
+            .unwrap();
+        assert_eq!(bars.len(), 2);
+        assert_eq!(bars[0].close_reason, Some(CloseReason::UpwardBreach));
+        assert_eq!(bars[1].close_reason, Some(CloseReason::EndOfData));
+
+        // Off by default
+        let mut plain = RangeBarProcessor::new(250).unwrap();
+        let bars = plain
+            .process_agg_trade_records_with_incomplete(&trades)
+            .unwrap();
+        assert!(bars.iter().all(|bar| bar.close_reason.is_none()));
+    }
+
+    #[cfg(feature = "metrics")]
+    #[test]
+    fn test_metrics_accumulate_across_calls() {
+        let trades: Vec<AggTrade> = (0..400)
+            .map(|i| {
+                let price = 50_000.0 + ((i % 40) as f64 - 20.0).abs() * 10.0 + i as f64;
+                test_utils::create_test_agg_trade(i + 1, &format!("{price:.1}"), "1.0", 1_000 + i)
+            })
+            .collect();
+        let (first, second) = trades.split_at(150);
+
+        let mut processor = RangeBarProcessor::new(10).unwrap();
+        let mut bars = processor.process_agg_trade_records(first).unwrap();
+        let after_first = *processor.metrics();
+        bars.extend(processor.process_agg_trade_records(second).unwrap());
+
+        let metrics = processor.metrics();
+        assert!(after_first.bars_emitted > 0);
+        assert_eq!(after_first.trades_processed, 150);
+        assert_eq!(metrics.trades_processed, 400);
+        assert_eq!(metrics.bars_emitted, bars.len() as u64);
+        assert_eq!(
+            metrics.breaches_up + metrics.breaches_down,
+            metrics.bars_emitted
+        );
+        assert!(metrics.breaches_up > 0 && metrics.breaches_down > 0);
+        assert_eq!(
+            metrics.max_bar_trade_count,
+            bars.iter().map(|b| b.individual_trade_count).max().unwrap()
+        );
+    }
+
+    #[cfg(feature = "metrics")]
+    #[test]
+    fn test_metrics_count_forced_closes() {
+        const DAY_US: i64 = 86_400_000_000;
+        // Flat prices never breach: closes come only from the cap and the day change
+        let trades: Vec<AggTrade> = (0..6)
+            .map(|i| {
+                let timestamp = if i < 3 { 1_000 + i } else { DAY_US + i };
+                test_utils::create_test_agg_trade(i + 1, "50000.0", "1.0", timestamp)
+            })
+            .collect();
+
+        let mut processor = RangeBarProcessor::new(250)
+            .unwrap()
+            .with_max_trades_per_bar(Some(2))
+            .with_daily_utc_close(true);
+        let bars = processor.process_agg_trade_records(&trades).unwrap();
+
+        let metrics = processor.metrics();
+        assert_eq!(metrics.bars_emitted, bars.len() as u64);
+        assert_eq!(metrics.trade_cap_closes, 2);
+        assert_eq!(metrics.session_closes, 1);
+        assert_eq!(metrics.breaches_up + metrics.breaches_down, 0);
+    }
+
+    #[test]
+    fn test_dedup_skips_replayed_trades() {
+        let trades = [
+            test_utils::create_test_agg_trade(1, "50000.0", "1.0", 1_000),
+            test_utils::create_test_agg_trade(2, "50010.0", "2.0", 2_000),
+            test_utils::create_test_agg_trade(3, "50020.0", "3.0", 3_000),
+        ];
+        let replayed = [&trades[0], &trades[1], &trades[1], &trades[0], &trades[2]];
+
+        let mut processor = RangeBarProcessor::new(250).unwrap().with_dedup(true);
+        for trade in replayed {
+            assert!(
+                processor
+                    .process_single_trade(trade.clone())
+                    .unwrap()
+                    .is_none()
+            );
+        }
+
+        let bar = processor.get_incomplete_bar().unwrap();
+        assert_eq!(bar.volume, FixedPoint::from_str("6.0").unwrap());
+        assert_eq!(bar.agg_record_count, 3);
+        assert_eq!(processor.duplicates_skipped(), 2);
+        assert_eq!(processor.anomaly_summary().overlaps_detected, 2);
+
+        // Without the guard the replay is double-counted
+        let mut naive = RangeBarProcessor::new(250).unwrap();
+        for trade in replayed {
+            naive.process_single_trade(trade.clone()).unwrap();
+        }
+        assert_eq!(
+            naive.get_incomplete_bar().unwrap().volume,
+            FixedPoint::from_str("9.0").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_data_source_stamped_on_bars() {
+        let trades = mixed_flow_sequence(200);
+        let mut processor = RangeBarProcessor::new(25)
+            .unwrap()
+            .with_data_source(DataSource::OkxSwap);
+        let bars = processor
+            .process_agg_trade_records_with_incomplete(&trades)
+            .unwrap();
+
+        assert!(bars.len() > 1);
+        assert!(
+            bars.iter()
+                .all(|bar| bar.data_source == DataSource::OkxSwap)
+        );
+
+        let default_bars = RangeBarProcessor::new(25)
+            .unwrap()
+            .process_agg_trade_records(&trades)
+            .unwrap();
+        assert_eq!(default_bars[0].data_source, DataSource::default());
+    }
+
+    #[test]
+    fn test_gap_policy_contiguous_stream() {
+        // create_test_agg_trade uses first/last_trade_id = id * 10; rebuild contiguously
+        let trades: Vec<AggTrade> = (1..=50)
+            .map(|id| {
+                test_utils::create_test_agg_trade_with_range(
+                    id,
+                    &format!("{}.0", 50_000 + (id % 7) * 40),
+                    "1.0",
+                    1_000 * id,
+                    id * 3,
+                    id * 3 + 2,
+                    id % 2 == 0,
+                )
+            })
+            .collect();
+
+        let expected = RangeBarProcessor::new(250)
+            .unwrap()
+            .process_agg_trade_records(&trades)
+            .unwrap();
+        let mut processor = RangeBarProcessor::new(250)
+            .unwrap()
+            .with_gap_policy(GapPolicy::Error);
+        let bars = processor.process_agg_trade_records(&trades).unwrap();
+
+        assert_eq!(bars.len(), expected.len());
+        assert!(processor.gap_warnings().is_empty());
+        assert!(!processor.anomaly_summary().has_anomalies());
+    }
+
+    #[test]
+    fn test_gap_policy_detects_missing_ids() {
+        let trade = |id, first, last| {
+            test_utils::create_test_agg_trade_with_range(
+                id,
+                "50000.0",
+                "1.0",
+                1_000 * id,
+                first,
+                last,
+                false,
+            )
+        };
+        // Trade IDs 106-109 are missing between records 2 and 3
+        let trades = vec![trade(1, 100, 102), trade(2, 103, 105), trade(3, 110, 110)];
+
+        let mut ignore = RangeBarProcessor::new(250).unwrap();
+        assert!(ignore.process_agg_trade_records(&trades).is_ok());
+        assert!(ignore.gap_warnings().is_empty());
+
+        let mut warn = RangeBarProcessor::new(250)
+            .unwrap()
+            .with_gap_policy(GapPolicy::Warn);
+        warn.process_agg_trade_records(&trades).unwrap();
+        assert_eq!(
+            warn.gap_warnings(),
+            &[TradeIdGap {
+                agg_trade_id: 3,
+                expected_first_trade_id: 106,
+                actual_first_trade_id: 110,
+            }]
+        );
+        assert_eq!(warn.anomaly_summary().gaps_detected, 1);
+        assert_eq!(warn.take_gap_warnings().len(), 1);
+        assert!(warn.gap_warnings().is_empty());
+
+        let mut strict = RangeBarProcessor::new(250)
+            .unwrap()
+            .with_gap_policy(GapPolicy::Error);
+        assert!(matches!(
+            strict.process_agg_trade_records(&trades),
+            Err(ProcessingError::TradeIdGap {
+                index: 2,
+                expected: 106,
+                actual: 110
+            })
+        ));
+
+        let mut streaming = RangeBarProcessor::new(250)
+            .unwrap()
+            .with_gap_policy(GapPolicy::Error);
+        streaming.process_single_trade(trades[0].clone()).unwrap();
+        streaming.process_single_trade(trades[1].clone()).unwrap();
+        assert!(streaming.process_single_trade(trades[2].clone()).is_err());
+    }
+
+    #[test]
+    fn test_process_each_matches_collecting() {
+        let trades = mixed_flow_sequence(3_000);
+
+        let mut collecting = RangeBarProcessor::new(250).unwrap();
+        let expected = collecting.process_agg_trade_records(&trades).unwrap();
+
+        let mut callback = RangeBarProcessor::new(250).unwrap();
+        let mut invocations = 0;
+        let mut last_close_time = 0;
+        callback
+            .process_agg_trade_records_each(&trades, |bar| {
+                invocations += 1;
+                last_close_time = bar.close_time;
+            })
+            .unwrap();
+
+        assert!(invocations > 0);
+        assert_eq!(invocations, expected.len());
+        assert_eq!(last_close_time, expected.last().unwrap().close_time);
+    }
+
+    #[test]
+    fn test_first_breach_index() {
+        let trades = mixed_flow_sequence(40);
+        let state = RangeBarState::new(&trades[0], 250);
+
+        for from in 0..=trades.len() {
+            let expected = trades[from..]
+                .iter()
+                .position(|t| t.price >= state.upper_threshold || t.price <= state.lower_threshold)
+                .map_or(trades.len(), |offset| from + offset);
+            assert_eq!(
+                first_breach_index(&trades, from, &state),
+                expected,
+                "from={from}"
+            );
+        }
+    }
+
     #[test]
     fn test_debug_streaming_data() {
         let mut processor = RangeBarProcessor::new(100).unwrap(); // 100 × 0.1bps = 10bps = 0.1%
@@ -800,6 +2441,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_export_processor_exact_turnover() {
+        let large = test_utils::create_test_agg_trade(1, "99999.99999999", "123456.78901234", 1000);
+        let next = test_utils::create_test_agg_trade(2, "100000.0", "3.0", 2000);
+
+        let mut processor = ExportRangeBarProcessor::new(250).unwrap();
+        processor.process_trades_continuously(&[large.clone(), next.clone()]);
+        let bar = processor.get_incomplete_bar().unwrap();
+
+        // Exact reference: 9999999999999 * 12345678901234 + 10000000000000 * 300000000
+        let expected: i128 = 123_459_789_012_327_654_321_098_766;
+        assert_eq!(bar.turnover, expected);
+        assert_eq!(bar.turnover, large.turnover() + next.turnover());
+        assert_eq!(bar.buy_turnover + bar.sell_turnover, bar.turnover);
+
+        // The former f64 path loses precision (and scale) on the same input
+        let f64_path = (large.price.to_f64() * large.volume.to_f64()) as i128
+            + (next.price.to_f64() * next.volume.to_f64()) as i128;
+        assert_ne!(bar.turnover, f64_path);
+
+        // Same scale as the primary processor
+        let mut primary = RangeBarProcessor::new(250).unwrap();
+        let primary_bar = primary
+            .process_agg_trade_records_with_incomplete(&[large, next])
+            .unwrap()
+            .pop()
+            .unwrap();
+        assert_eq!(primary_bar.turnover, bar.turnover);
+    }
+
     // === CHECKPOINT TESTS (Issues #2 and #3) ===
 
     #[test]
@@ -868,7 +2539,7 @@ mod tests {
                 i + 1,
                 &format!("{:.8}", price),
                 "1.0",
-                base_timestamp + (i as i64 * 1000000),
+                base_timestamp + (i * 1000000),
             );
             all_trades.push(trade);
         }
@@ -1125,7 +2796,7 @@ impl ExportRangeBarProcessor {
     fn process_single_trade_fixed_point(&mut self, trade: &AggTrade) {
         if self.current_bar.is_none() {
             // Start new bar
-            let trade_turnover = (trade.price.to_f64() * trade.volume.to_f64()) as i128;
+            let trade_turnover = trade.turnover();
 
             self.current_bar = Some(InternalRangeBar {
                 open_time: trade.timestamp,
@@ -1171,7 +2842,7 @@ impl ExportRangeBarProcessor {
         // Process existing bar - work with reference
         // SAFETY: current_bar guaranteed Some - early return above if None
         let bar = self.current_bar.as_mut().unwrap();
-        let trade_turnover = (trade.price.to_f64() * trade.volume.to_f64()) as i128;
+        let trade_turnover = trade.turnover();
 
         // CRITICAL FIX: Use fixed-point integer arithmetic for precise threshold calculation
         // v3.0.0: threshold now in decimal bps, using BASIS_POINTS_SCALE = 100_000
@@ -1241,6 +2912,9 @@ impl ExportRangeBarProcessor {
                 vwap: completed_bar.vwap,
                 buy_turnover: completed_bar.buy_turnover,
                 sell_turnover: completed_bar.sell_turnover,
+                threshold_upper: None,
+                threshold_lower: None,
+                close_reason: None,
             };
 
             self.completed_bars.push(export_bar);
@@ -1297,7 +2971,7 @@ impl ExportRangeBarProcessor {
     }
 
     /// Get incomplete bar if exists (for final bar processing)
-    pub fn get_incomplete_bar(&mut self) -> Option<RangeBar> {
+    pub fn get_incomplete_bar(&self) -> Option<RangeBar> {
         self.current_bar.as_ref().map(|incomplete| RangeBar {
             open_time: incomplete.open_time,
             close_time: incomplete.close_time,
@@ -1323,6 +2997,9 @@ impl ExportRangeBarProcessor {
             vwap: incomplete.vwap,
             buy_turnover: incomplete.buy_turnover,
             sell_turnover: incomplete.sell_turnover,
+            threshold_upper: None,
+            threshold_lower: None,
+            close_reason: None,
         })
     }
 }
@@ -0,0 +1,89 @@
+//! Fixed-time candles from the same trades, for comparison with range bars
+
+use crate::types::{AggTrade, RangeBar};
+
+/// Aggregate trades into fixed-interval time candles
+///
+/// Buckets are aligned to multiples of `interval_ms` since the Unix epoch. Each
+/// candle's `open_time` is its bucket start and `close_time` the last
+/// microsecond of the bucket (the Binance kline convention), regardless of when
+/// the first and last trades occurred. Intervals without trades emit no candle.
+/// Trades must be sorted by timestamp; `interval_ms` of 0 or less returns no
+/// candles.
+pub fn resample_time_bars(trades: &[AggTrade], interval_ms: i64) -> Vec<RangeBar> {
+    if interval_ms <= 0 {
+        return Vec::new();
+    }
+    let interval_us = interval_ms.saturating_mul(1_000);
+
+    let mut bars: Vec<RangeBar> = Vec::new();
+    let mut current_bucket = None;
+    for trade in trades {
+        let bucket = trade.timestamp.div_euclid(interval_us);
+        match bars.last_mut() {
+            Some(bar) if current_bucket == Some(bucket) => bar.update_with_trade(trade),
+            _ => {
+                let mut bar = RangeBar::new(trade);
+                bar.open_time = bucket * interval_us;
+                bars.push(bar);
+                current_bucket = Some(bucket);
+            }
+        }
+    }
+
+    for bar in &mut bars {
+        bar.close_time = bar.open_time + interval_us - 1;
+    }
+    bars
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+
+    #[test]
+    fn test_three_minute_buckets_with_gap() {
+        const MINUTE_US: i64 = 60_000_000;
+        let base = 1_700_000_040_000_000; // 2023-11-14 22:14:00 UTC, minute-aligned
+        let trades = [
+            (1, "100.0", base + 1_000),
+            (2, "102.0", base + 20_000_000),
+            (3, "99.0", base + 59_999_999),
+            (4, "101.0", base + MINUTE_US),
+            (5, "103.0", base + MINUTE_US + 30_000_000),
+            // minute 2 has no trades
+            (6, "98.0", base + 3 * MINUTE_US + 5_000_000),
+        ]
+        .map(|(id, price, ts)| test_utils::create_test_agg_trade(id, price, "1.0", ts));
+
+        let bars = resample_time_bars(&trades, 60_000);
+        assert_eq!(bars.len(), 3);
+
+        let ohlc = |bar: &RangeBar| [bar.open, bar.high, bar.low, bar.close].map(|p| p.to_string());
+        assert_eq!(
+            ohlc(&bars[0]),
+            ["100.00000000", "102.00000000", "99.00000000", "99.00000000"]
+        );
+        assert_eq!(
+            ohlc(&bars[1]),
+            [
+                "101.00000000",
+                "103.00000000",
+                "101.00000000",
+                "103.00000000"
+            ]
+        );
+        assert_eq!(bars[0].agg_record_count, 3);
+        assert_eq!(bars[2].agg_record_count, 1);
+
+        let opens: Vec<i64> = bars.iter().map(|b| b.open_time).collect();
+        assert_eq!(opens, vec![base, base + MINUTE_US, base + 3 * MINUTE_US]);
+        for bar in &bars {
+            assert_eq!(bar.close_time, bar.open_time + MINUTE_US - 1);
+            assert_eq!(bar.validate(), Ok(()));
+        }
+
+        assert!(resample_time_bars(&trades, 0).is_empty());
+    }
+}
@@ -0,0 +1,91 @@
+//! JSON Schema export for [`RangeBar`] and [`AggTrade`]
+//!
+//! Lets integrators in other languages generate types from the serialized
+//! form. Note the numeric encodings, which the generated schemas describe as
+//! plain integers:
+//!
+//! - [`FixedPoint`](crate::FixedPoint) fields (prices, volumes, VWAP,
+//!   thresholds) are JSON integers holding the decimal value scaled by 10^8,
+//!   e.g. `50000.5` is `5000050000000`.
+//! - Turnover fields are `i128` integers scaled by 10^16 (price × volume).
+//! - Timestamps are integer microseconds since the Unix epoch.
+
+use crate::types::{AggTrade, RangeBar};
+use serde_json::Value;
+
+/// JSON Schema (draft-07) for a serialized [`RangeBar`]
+pub fn rangebar_json_schema() -> Value {
+    serde_json::to_value(schemars::schema_for!(RangeBar)).expect("schema is valid JSON")
+}
+
+/// JSON Schema (draft-07) for a serialized [`AggTrade`]
+pub fn aggtrade_json_schema() -> Value {
+    serde_json::to_value(schemars::schema_for!(AggTrade)).expect("schema is valid JSON")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+
+    /// Check required fields and declared primitive types, following `$ref`s
+    fn assert_conforms(value: &Value, schema: &Value, root: &Value) {
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            let name = reference.trim_start_matches("#/definitions/");
+            return assert_conforms(value, &root["definitions"][name], root);
+        }
+        if let Some(variants) = schema.get("enum").and_then(Value::as_array) {
+            assert!(variants.contains(value), "{value} not in {variants:?}");
+        }
+        match schema.get("type").and_then(Value::as_str) {
+            Some("integer") => {
+                // i128 turnovers beyond u64 parse back as whole f64s
+                let whole = value.as_f64().is_some_and(|v| v.fract() == 0.0);
+                assert!(whole, "{value}");
+            }
+            Some("number") => assert!(value.is_number(), "{value}"),
+            Some("string") => assert!(value.is_string(), "{value}"),
+            Some("boolean") => assert!(value.is_boolean(), "{value}"),
+            Some("object") => {
+                let object = value.as_object().expect("object");
+                for field in schema["required"].as_array().into_iter().flatten() {
+                    assert!(
+                        object.contains_key(field.as_str().unwrap()),
+                        "missing {field}"
+                    );
+                }
+                for (key, field_value) in object {
+                    let field_schema = &schema["properties"][key];
+                    assert!(!field_schema.is_null(), "unexpected field {key}");
+                    assert_conforms(field_value, field_schema, root);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Round-trip through text, since `serde_json::Value` cannot hold large i128 turnovers
+    fn to_json_value<T: serde::Serialize>(value: &T) -> Value {
+        serde_json::from_str(&serde_json::to_string(value).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_serialized_rangebar_conforms_to_schema() {
+        let trades = test_utils::scenarios::single_breach_sequence(250);
+        let bars = crate::RangeBarProcessor::new(250)
+            .unwrap()
+            .with_threshold_annotation(true)
+            .process_agg_trade_records(&trades)
+            .unwrap();
+        assert!(!bars.is_empty());
+
+        let schema = rangebar_json_schema();
+        let bar = to_json_value(&bars[0]);
+        assert_conforms(&bar, &schema, &schema);
+        assert_eq!(schema["definitions"]["FixedPoint"]["type"], "integer");
+
+        let schema = aggtrade_json_schema();
+        let trade = to_json_value(&trades[0]);
+        assert_conforms(&trade, &schema, &schema);
+    }
+}
@@ -75,7 +75,7 @@ pub enum LoaderError {
         path: String,
         line: usize,
         #[source]
-        source: crate::fixed_point::FixedPointError,
+        source: crate::fixed_point::FixedPointParseError,
     },
 
     /// Record count validation error
@@ -115,7 +115,7 @@ impl AggTradeRecord {
     /// Convert CSV record to AggTrade
     ///
     /// SLO: Fail-fast on parse errors (no defaults, no fallbacks)
-    fn into_agg_trade(self) -> Result<AggTrade, crate::fixed_point::FixedPointError> {
+    fn into_agg_trade(self) -> Result<AggTrade, crate::fixed_point::FixedPointParseError> {
         Ok(AggTrade {
             agg_trade_id: self.a,
             price: FixedPoint::from_str(&self.p)?,
@@ -23,7 +23,7 @@
 //! - Changes propagate automatically to all tests
 
 use crate::FixedPoint;
-use crate::processor::ExportRangeBarProcessor;
+use crate::processor::{ExportRangeBarProcessor, RangeBarState};
 use crate::types::{AggTrade, RangeBar};
 
 // =============================================================================
@@ -72,6 +72,35 @@ pub fn process_batch_style(trades: &[AggTrade], threshold_decimal_bps: u32) -> V
     bars
 }
 
+/// Process trades with a plain per-record breach check (no pre-scan)
+///
+/// Reference for
+/// [`process_agg_trade_records`](crate::RangeBarProcessor::process_agg_trade_records)
+/// with default options: the breaching trade closes its bar and the next trade
+/// opens a new one. Returns completed bars and the trailing open bar.
+pub fn process_scalar_batch(
+    trades: &[AggTrade],
+    threshold_decimal_bps: u32,
+) -> (Vec<RangeBar>, Option<RangeBar>) {
+    let mut bars = Vec::new();
+    let mut current: Option<RangeBarState> = None;
+    for trade in trades {
+        match current.as_mut() {
+            None => current = Some(RangeBarState::new(trade, threshold_decimal_bps)),
+            Some(state) => {
+                state.bar.update_with_trade(trade);
+                if state
+                    .bar
+                    .is_breach(trade.price, state.upper_threshold, state.lower_threshold)
+                {
+                    bars.extend(current.take().map(|state| state.bar));
+                }
+            }
+        }
+    }
+    (bars, current.map(|state| state.bar))
+}
+
 /// Process trades in streaming style (chunked processing)
 ///
 /// Simulates real-world streaming behavior with memory constraints
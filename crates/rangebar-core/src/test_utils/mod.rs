@@ -102,6 +102,9 @@ pub fn create_test_range_bar(
         buy_trade_count: 0,
         sell_trade_count: 0,
         vwap: FixedPoint::from_str(open).unwrap(), // Simple default
+        threshold_upper: None,
+        threshold_lower: None,
+        close_reason: None,
     }
 }
 
@@ -197,6 +200,132 @@ impl AggTradeBuilder {
     }
 }
 
+/// Reproducible random-walk trade stream for property and fuzz-style tests
+///
+/// Each step moves the price by a normally distributed return with standard
+/// deviation `vol_decimal_bps` (decimal basis points, 100 = 0.1%). Timestamps
+/// strictly increase from [`constants::BASE_TIMESTAMP`], aggTrade IDs start at 1,
+/// and individual trade ID ranges are contiguous. The same `seed` always
+/// yields the same stream.
+pub fn synthetic_stream(
+    seed: u64,
+    count: usize,
+    start_price: FixedPoint,
+    vol_decimal_bps: u32,
+) -> Vec<AggTrade> {
+    let mut rng = SplitMix64(seed);
+    let mut price = start_price.0.max(1);
+    let mut timestamp = constants::BASE_TIMESTAMP;
+    let mut next_trade_id = 1;
+
+    (0..count as i64)
+        .map(|i| {
+            if i > 0 {
+                let step = rng.next_normal() * vol_decimal_bps as f64 / 100_000.0;
+                price = ((price as f64) * (1.0 + step)).round().max(1.0) as i64;
+                timestamp += 1 + (rng.next_u64() % 1_000) as i64;
+            }
+            let individual_trades = 1 + (rng.next_u64() % 3) as i64;
+            let first_trade_id = next_trade_id;
+            next_trade_id += individual_trades;
+
+            AggTrade {
+                agg_trade_id: i + 1,
+                price: FixedPoint(price),
+                // 0.001 to 10.0 units
+                volume: FixedPoint(100_000 + (rng.next_u64() % 1_000_000_000) as i64),
+                first_trade_id,
+                last_trade_id: next_trade_id - 1,
+                timestamp,
+                is_buyer_maker: rng.next_u64().is_multiple_of(2),
+                is_best_match: None,
+            }
+        })
+        .collect()
+}
+
+/// Assert core invariants over consecutive completed bars
+///
+/// Panics with the offending bar index if any check fails:
+///
+/// - **Continuity**: every trade lands in exactly one bar, so each bar's
+///   `first_trade_id` follows the previous bar's `last_trade_id` (requires
+///   input with contiguous trade IDs, e.g. [`synthetic_stream`]) and time
+///   never runs backwards. The next bar opens on the trade *after* the breach,
+///   so `open[i + 1]` is generally not `close[i]`.
+/// - **OHLC bounds**: `low <= open, close <= high`.
+/// - **Bounded range**: the bar closed on a breach, and only the closing trade
+///   may lie beyond a threshold. The non-breaching extreme stays strictly
+///   inside its threshold, so the sole overshoot is the closing trade's.
+pub fn assert_bar_invariants(bars: &[RangeBar], threshold_decimal_bps: u32) {
+    for (i, bar) in bars.iter().enumerate() {
+        assert!(
+            bar.low <= bar.open.min(bar.close) && bar.high >= bar.open.max(bar.close),
+            "bar {i}: OHLC out of bounds: open={} high={} low={} close={}",
+            bar.open,
+            bar.high,
+            bar.low,
+            bar.close
+        );
+
+        let (upper, lower) = bar.open.compute_range_thresholds(threshold_decimal_bps);
+        if bar.close >= upper {
+            assert!(
+                bar.high == bar.close && bar.low > lower,
+                "bar {i}: upward breach with excess range: open={} high={} low={} close={}",
+                bar.open,
+                bar.high,
+                bar.low,
+                bar.close
+            );
+        } else {
+            assert!(
+                bar.close <= lower && bar.low == bar.close && bar.high < upper,
+                "bar {i}: closed without a clean breach: open={} high={} low={} close={}",
+                bar.open,
+                bar.high,
+                bar.low,
+                bar.close
+            );
+        }
+
+        if let Some(prev) = i.checked_sub(1).map(|p| &bars[p]) {
+            assert_eq!(
+                bar.first_trade_id,
+                prev.last_trade_id + 1,
+                "bar {i}: trades missing or repeated between bars"
+            );
+            assert!(
+                bar.open_time >= prev.close_time,
+                "bar {i}: opens before previous close"
+            );
+        }
+    }
+}
+
+/// Minimal SplitMix64 generator so test data needs no RNG dependency
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in [0, 1)
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Approximately standard normal (Irwin-Hall sum of 12 uniforms)
+    fn next_normal(&mut self) -> f64 {
+        (0..12).map(|_| self.next_f64()).sum::<f64>() - 6.0
+    }
+}
+
 /// Common test scenarios
 pub mod scenarios {
     use super::*;
@@ -294,3 +423,43 @@ pub mod scenarios {
         builder.build()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_synthetic_stream_is_deterministic_and_ordered() {
+        let start = FixedPoint::from_str("50000.0").unwrap();
+        let trades = synthetic_stream(42, 2_000, start, 50);
+        let again = synthetic_stream(42, 2_000, start, 50);
+        let other = synthetic_stream(43, 2_000, start, 50);
+
+        let key = |t: &AggTrade| (t.price, t.volume, t.timestamp, t.first_trade_id);
+        assert_eq!(trades.len(), 2_000);
+        assert!(trades.iter().map(key).eq(again.iter().map(key)));
+        assert!(!trades.iter().map(key).eq(other.iter().map(key)));
+        assert_eq!(trades[0].price, start);
+
+        for pair in trades.windows(2) {
+            assert!(pair[1].timestamp > pair[0].timestamp);
+            assert_eq!(pair[1].agg_trade_id, pair[0].agg_trade_id + 1);
+            assert_eq!(pair[1].first_trade_id, pair[0].last_trade_id + 1);
+        }
+        assert!(trades.iter().any(|t| t.price != start));
+    }
+
+    #[test]
+    fn test_bar_invariants_hold_over_random_seeds() {
+        let start = FixedPoint::from_str("50000.0").unwrap();
+        for seed in 0..1_000 {
+            let threshold_decimal_bps = [25, 100, 250][seed as usize % 3];
+            let trades = synthetic_stream(seed, 500, start, 40);
+            let bars = crate::RangeBarProcessor::new(threshold_decimal_bps)
+                .unwrap()
+                .process_agg_trade_records(&trades)
+                .unwrap();
+            assert_bar_invariants(&bars, threshold_decimal_bps);
+        }
+    }
+}
@@ -0,0 +1,108 @@
+//! Threshold unit resolution for externally supplied thresholds
+//!
+//! Thresholds are processed internally in decimal basis points (1 = 0.001%),
+//! but callers migrating from the v2 API still send whole basis points. A value
+//! below [`AMBIGUOUS_THRESHOLD_LIMIT`] without an explicit unit is almost
+//! certainly a whole-bps value and would silently produce bars ten times
+//! narrower than intended, so it is rejected rather than guessed.
+
+use crate::processor::ProcessingError;
+use serde::{Deserialize, Serialize};
+
+/// Unitless thresholds below this value are rejected as ambiguous
+pub const AMBIGUOUS_THRESHOLD_LIMIT: u32 = 10;
+
+/// Maximum threshold in decimal basis points (100%)
+const MAX_THRESHOLD_DECIMAL_BPS: u32 = 100_000;
+
+/// Unit of a caller-supplied threshold value
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ThresholdUnit {
+    /// Whole basis points (1 = 0.01%)
+    Bps,
+    /// Tenths of a basis point, i.e. decimal bps (1 = 0.001%)
+    TenthsBps,
+}
+
+impl ThresholdUnit {
+    /// Convert a value in this unit to decimal basis points
+    pub fn to_decimal_bps(self, value: u32) -> Option<u32> {
+        match self {
+            Self::Bps => value.checked_mul(10),
+            Self::TenthsBps => Some(value),
+        }
+    }
+}
+
+/// Resolve a caller-supplied threshold to decimal basis points
+///
+/// Without a unit the value is taken as decimal bps, unless it is below
+/// [`AMBIGUOUS_THRESHOLD_LIMIT`], in which case
+/// [`ProcessingError::AmbiguousThresholdUnit`] is returned.
+pub fn resolve_threshold_decimal_bps(
+    value: u32,
+    unit: Option<ThresholdUnit>,
+) -> Result<u32, ProcessingError> {
+    let threshold_decimal_bps = match unit {
+        None if value < AMBIGUOUS_THRESHOLD_LIMIT => {
+            return Err(ProcessingError::AmbiguousThresholdUnit { threshold: value });
+        }
+        None => Some(value),
+        Some(unit) => unit.to_decimal_bps(value),
+    };
+
+    match threshold_decimal_bps {
+        Some(t) if (1..=MAX_THRESHOLD_DECIMAL_BPS).contains(&t) => Ok(t),
+        _ => Err(ProcessingError::InvalidThreshold {
+            threshold_decimal_bps: threshold_decimal_bps.unwrap_or(u32::MAX),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_with_explicit_unit() {
+        let resolve = |value, unit| resolve_threshold_decimal_bps(value, unit).unwrap();
+        assert_eq!(resolve(25, Some(ThresholdUnit::Bps)), 250);
+        assert_eq!(resolve(5, Some(ThresholdUnit::Bps)), 50);
+        assert_eq!(resolve(5, Some(ThresholdUnit::TenthsBps)), 5);
+        assert_eq!(resolve(250, None), 250);
+    }
+
+    #[test]
+    fn test_resolve_rejects_ambiguous_and_out_of_range() {
+        assert!(matches!(
+            resolve_threshold_decimal_bps(8, None),
+            Err(ProcessingError::AmbiguousThresholdUnit { threshold: 8 })
+        ));
+        assert!(matches!(
+            resolve_threshold_decimal_bps(0, Some(ThresholdUnit::TenthsBps)),
+            Err(ProcessingError::InvalidThreshold { .. })
+        ));
+        assert!(matches!(
+            resolve_threshold_decimal_bps(20_000, Some(ThresholdUnit::Bps)),
+            Err(ProcessingError::InvalidThreshold {
+                threshold_decimal_bps: 200_000
+            })
+        ));
+        assert!(matches!(
+            resolve_threshold_decimal_bps(u32::MAX, Some(ThresholdUnit::Bps)),
+            Err(ProcessingError::InvalidThreshold { .. })
+        ));
+    }
+
+    #[test]
+    fn test_unit_serde_names() {
+        assert_eq!(
+            serde_json::to_string(&ThresholdUnit::TenthsBps).unwrap(),
+            "\"tenths_bps\""
+        );
+        let unit: ThresholdUnit = serde_json::from_str("\"bps\"").unwrap();
+        assert_eq!(unit, ThresholdUnit::Bps);
+    }
+}
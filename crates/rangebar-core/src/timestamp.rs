@@ -3,6 +3,9 @@
 //! This module provides centralized timestamp handling to ensure all aggTrade data
 //! uses consistent 16-digit microsecond precision regardless of source format.
 
+use std::fmt;
+use thiserror::Error;
+
 /// Universal timestamp normalization threshold
 /// Values below this are treated as 13-digit milliseconds and converted to microseconds
 const MICROSECOND_THRESHOLD: u64 = 10_000_000_000_000;
@@ -35,6 +38,109 @@ pub fn normalize_timestamp(raw_timestamp: u64) -> i64 {
     }
 }
 
+/// Values below this are treated as 10-digit seconds by [`detect_time_unit`]
+const MILLISECOND_THRESHOLD: u64 = 100_000_000_000;
+
+/// Values at or above this are treated as 19-digit nanoseconds by [`detect_time_unit`]
+const NANOSECOND_THRESHOLD: u64 = 10_000_000_000_000_000;
+
+/// Unit of a raw epoch timestamp
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeUnit {
+    Seconds,
+    Millis,
+    Micros,
+    Nanos,
+}
+
+impl fmt::Display for TimeUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            TimeUnit::Seconds => "s",
+            TimeUnit::Millis => "ms",
+            TimeUnit::Micros => "us",
+            TimeUnit::Nanos => "ns",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Timestamp conversion and validation errors
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum TimestampError {
+    #[error("Negative timestamp: {value}")]
+    Negative { value: i64 },
+
+    #[error("Timestamp {value}{unit} overflows microsecond range")]
+    Overflow { value: i64, unit: TimeUnit },
+
+    #[error("Timestamp {timestamp}us outside valid range [{min}, {max}]")]
+    OutOfRange { timestamp: i64, min: i64, max: i64 },
+
+    #[error("Invalid timestamp at index {index}: {source}")]
+    AtIndex {
+        index: usize,
+        #[source]
+        source: Box<TimestampError>,
+    },
+}
+
+/// Guess the unit of a raw epoch timestamp from its magnitude
+///
+/// | Digits | Range | Unit |
+/// |--------|-------|------|
+/// | ≤11 | `< 1e11` | seconds |
+/// | 12-13 | `< 1e13` | milliseconds |
+/// | 14-16 | `< 1e16` | microseconds |
+/// | ≥17 | `≥ 1e16` | nanoseconds |
+///
+/// The millisecond/microsecond boundary matches [`normalize_timestamp`].
+pub fn detect_time_unit(value: i64) -> TimeUnit {
+    let magnitude = value.unsigned_abs();
+    if magnitude < MILLISECOND_THRESHOLD {
+        TimeUnit::Seconds
+    } else if magnitude < MICROSECOND_THRESHOLD {
+        TimeUnit::Millis
+    } else if magnitude < NANOSECOND_THRESHOLD {
+        TimeUnit::Micros
+    } else {
+        TimeUnit::Nanos
+    }
+}
+
+/// Normalize a timestamp in an explicit unit to microseconds
+///
+/// The internal representation is microseconds (see [`crate::AggTrade::timestamp`]);
+/// nanosecond input is truncated toward zero.
+///
+/// # Examples
+/// ```rust
+/// use rangebar_core::{TimeUnit, normalize_timestamp_with_unit};
+///
+/// assert_eq!(normalize_timestamp_with_unit(1609459200, TimeUnit::Seconds), Ok(1609459200000000));
+/// assert_eq!(normalize_timestamp_with_unit(1609459200000000123, TimeUnit::Nanos), Ok(1609459200000000));
+/// ```
+pub fn normalize_timestamp_with_unit(value: i64, unit: TimeUnit) -> Result<i64, TimestampError> {
+    if value < 0 {
+        return Err(TimestampError::Negative { value });
+    }
+
+    let micros = match unit {
+        TimeUnit::Seconds => value.checked_mul(1_000_000),
+        TimeUnit::Millis => value.checked_mul(1_000),
+        TimeUnit::Micros => Some(value),
+        TimeUnit::Nanos => Some(value / 1_000),
+    };
+
+    micros.ok_or(TimestampError::Overflow { value, unit })
+}
+
+/// Lower bound used by [`validate_timestamp`]: 2000-01-01 00:00:00 UTC in microseconds
+const MIN_TIMESTAMP: i64 = 946_684_800_000_000;
+
+/// Upper bound used by [`validate_timestamp`]: 2035-01-01 00:00:00 UTC in microseconds
+const MAX_TIMESTAMP: i64 = 2_051_222_400_000_000;
+
 /// Validate timestamp is in expected microsecond range
 ///
 /// Checks if timestamp falls within reasonable bounds for financial data.
@@ -55,13 +161,76 @@ pub fn normalize_timestamp(raw_timestamp: u64) -> i64 {
 /// - MAX: 2035-01-01 (future-proof for upcoming data)
 /// - Rejects: Unix epoch (1970), far future (2100+), negative timestamps
 pub fn validate_timestamp(timestamp: i64) -> bool {
-    // Expanded bounds: 2000-01-01 to 2035-01-01 in microseconds (Q16)
-    const MIN_TIMESTAMP: i64 = 946_684_800_000_000; // 2000-01-01 00:00:00 UTC
-    const MAX_TIMESTAMP: i64 = 2_051_222_400_000_000; // 2035-01-01 00:00:00 UTC
-
     (MIN_TIMESTAMP..=MAX_TIMESTAMP).contains(&timestamp)
 }
 
+/// Validate timestamp against caller-supplied inclusive bounds
+///
+/// All values are microseconds, matching [`validate_timestamp`]. Use this
+/// (or [`TimestampValidator`]) for synthetic or pre-2000 datasets.
+pub fn validate_timestamp_in_range(
+    timestamp: i64,
+    min_us: i64,
+    max_us: i64,
+) -> Result<(), TimestampError> {
+    if (min_us..=max_us).contains(&timestamp) {
+        Ok(())
+    } else {
+        Err(TimestampError::OutOfRange {
+            timestamp,
+            min: min_us,
+            max: max_us,
+        })
+    }
+}
+
+/// Reusable timestamp bounds (microseconds, inclusive)
+///
+/// The default validator uses the same 2000-2035 range as [`validate_timestamp`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimestampValidator {
+    min_us: i64,
+    max_us: i64,
+}
+
+impl TimestampValidator {
+    /// Create a validator with custom inclusive bounds in microseconds
+    ///
+    /// Bounds are swapped if given in reverse order.
+    pub fn new(min_us: i64, max_us: i64) -> Self {
+        Self {
+            min_us: min_us.min(max_us),
+            max_us: min_us.max(max_us),
+        }
+    }
+
+    /// Lower bound in microseconds
+    pub fn min_us(&self) -> i64 {
+        self.min_us
+    }
+
+    /// Upper bound in microseconds
+    pub fn max_us(&self) -> i64 {
+        self.max_us
+    }
+
+    /// Validate a microsecond timestamp against the configured bounds
+    pub fn validate(&self, timestamp: i64) -> Result<(), TimestampError> {
+        validate_timestamp_in_range(timestamp, self.min_us, self.max_us)
+    }
+
+    /// Boolean form of [`TimestampValidator::validate`]
+    pub fn is_valid(&self, timestamp: i64) -> bool {
+        self.validate(timestamp).is_ok()
+    }
+}
+
+impl Default for TimestampValidator {
+    fn default() -> Self {
+        Self::new(MIN_TIMESTAMP, MAX_TIMESTAMP)
+    }
+}
+
 /// Create a normalized AggTrade with automatic timestamp conversion
 ///
 /// This is the preferred way to create AggTrade instances to ensure
@@ -89,6 +258,41 @@ pub fn create_aggtrade_with_normalized_timestamp(
     }
 }
 
+/// Normalize a batch of trades from `unit` to microseconds in place
+///
+/// All timestamps are converted first and written only if every one succeeds,
+/// so on error the slice is left untouched. The error is wrapped in
+/// [`TimestampError::AtIndex`] with the position of the first bad trade.
+///
+/// Returns the number of trades whose timestamp changed (zero for [`TimeUnit::Micros`]).
+pub fn normalize_aggtrade_batch(
+    trades: &mut [crate::types::AggTrade],
+    unit: TimeUnit,
+) -> Result<usize, TimestampError> {
+    let normalized = trades
+        .iter()
+        .enumerate()
+        .map(|(index, trade)| {
+            normalize_timestamp_with_unit(trade.timestamp, unit).map_err(|source| {
+                TimestampError::AtIndex {
+                    index,
+                    source: Box::new(source),
+                }
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut converted = 0;
+    for (trade, timestamp) in trades.iter_mut().zip(normalized) {
+        if trade.timestamp != timestamp {
+            trade.timestamp = timestamp;
+            converted += 1;
+        }
+    }
+
+    Ok(converted)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -148,4 +352,181 @@ mod tests {
         // Invalid: Far future (2050+)
         assert!(!validate_timestamp(2_524_608_000_000_000)); // 2050-01-01
     }
+
+    #[test]
+    fn test_normalize_with_each_unit() {
+        let expected = 1_609_459_200_000_000; // 2021-01-01 in microseconds
+
+        assert_eq!(
+            normalize_timestamp_with_unit(1_609_459_200, TimeUnit::Seconds),
+            Ok(expected)
+        );
+        assert_eq!(
+            normalize_timestamp_with_unit(1_609_459_200_000, TimeUnit::Millis),
+            Ok(expected)
+        );
+        assert_eq!(
+            normalize_timestamp_with_unit(1_609_459_200_000_000, TimeUnit::Micros),
+            Ok(expected)
+        );
+        assert_eq!(
+            normalize_timestamp_with_unit(1_609_459_200_000_000_999, TimeUnit::Nanos),
+            Ok(expected)
+        );
+    }
+
+    #[test]
+    fn test_normalize_with_unit_errors() {
+        assert_eq!(
+            normalize_timestamp_with_unit(-1, TimeUnit::Millis),
+            Err(TimestampError::Negative { value: -1 })
+        );
+        assert_eq!(
+            normalize_timestamp_with_unit(i64::MAX, TimeUnit::Seconds),
+            Err(TimestampError::Overflow {
+                value: i64::MAX,
+                unit: TimeUnit::Seconds
+            })
+        );
+    }
+
+    #[test]
+    fn test_detect_time_unit() {
+        assert_eq!(detect_time_unit(1_609_459_200), TimeUnit::Seconds);
+        assert_eq!(detect_time_unit(1_609_459_200_000), TimeUnit::Millis);
+        assert_eq!(detect_time_unit(1_609_459_200_000_000), TimeUnit::Micros);
+        assert_eq!(detect_time_unit(1_609_459_200_000_000_000), TimeUnit::Nanos);
+    }
+
+    #[test]
+    fn test_detect_time_unit_millis_micros_boundary() {
+        let threshold = MICROSECOND_THRESHOLD as i64;
+        assert_eq!(detect_time_unit(threshold - 1), TimeUnit::Millis);
+        assert_eq!(detect_time_unit(threshold), TimeUnit::Micros);
+
+        // Detection agrees with the legacy normalize_timestamp boundary
+        for value in [threshold - 1, threshold] {
+            let unit = detect_time_unit(value);
+            assert_eq!(
+                normalize_timestamp_with_unit(value, unit),
+                Ok(normalize_timestamp(value as u64))
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_timestamp_in_range() {
+        let min = 1_000;
+        let max = 2_000;
+
+        assert_eq!(validate_timestamp_in_range(1_500, min, max), Ok(()));
+        assert_eq!(validate_timestamp_in_range(min, min, max), Ok(()));
+        assert_eq!(validate_timestamp_in_range(max, min, max), Ok(()));
+        assert_eq!(
+            validate_timestamp_in_range(999, min, max),
+            Err(TimestampError::OutOfRange {
+                timestamp: 999,
+                min,
+                max
+            })
+        );
+        assert_eq!(
+            validate_timestamp_in_range(2_001, min, max),
+            Err(TimestampError::OutOfRange {
+                timestamp: 2_001,
+                min,
+                max
+            })
+        );
+    }
+
+    #[test]
+    fn test_timestamp_validator_default_matches_validate_timestamp() {
+        let validator = TimestampValidator::default();
+        for ts in [
+            0,
+            MIN_TIMESTAMP - 1,
+            MIN_TIMESTAMP,
+            1_609_459_200_000_000,
+            MAX_TIMESTAMP,
+            MAX_TIMESTAMP + 1,
+        ] {
+            assert_eq!(validator.is_valid(ts), validate_timestamp(ts), "ts={ts}");
+        }
+    }
+
+    #[test]
+    fn test_timestamp_validator_custom_bounds() {
+        // 1990-01-01 .. 2000-01-01, rejected by the default bounds
+        let validator = TimestampValidator::new(631_152_000_000_000, MIN_TIMESTAMP);
+        let ts_1995 = 788_918_400_000_000;
+
+        assert!(validator.is_valid(ts_1995));
+        assert!(!validate_timestamp(ts_1995));
+        assert!(validator.validate(MIN_TIMESTAMP + 1).is_err());
+        assert_eq!(
+            TimestampValidator::new(10, 5),
+            TimestampValidator::new(5, 10)
+        );
+    }
+
+    fn trade_at(agg_trade_id: i64, timestamp: i64) -> crate::types::AggTrade {
+        crate::types::AggTrade {
+            agg_trade_id,
+            price: crate::FixedPoint(5_000_000_000_000),
+            volume: crate::FixedPoint(100_000_000),
+            first_trade_id: agg_trade_id,
+            last_trade_id: agg_trade_id,
+            timestamp,
+            is_buyer_maker: false,
+            is_best_match: None,
+        }
+    }
+
+    #[test]
+    fn test_normalize_aggtrade_batch_mixed_units() {
+        // Millisecond archive followed by microsecond archive (Binance 2025 format change)
+        let mut trades = vec![
+            trade_at(1, 1_609_459_200_000),
+            trade_at(2, 1_609_459_200_001),
+            trade_at(3, 1_609_459_200_002_000),
+            trade_at(4, 1_609_459_200_003_000),
+        ];
+
+        let (millis, micros) = trades.split_at_mut(2);
+        assert_eq!(normalize_aggtrade_batch(millis, TimeUnit::Millis), Ok(2));
+        assert_eq!(normalize_aggtrade_batch(micros, TimeUnit::Micros), Ok(0));
+
+        let timestamps: Vec<i64> = trades.iter().map(|t| t.timestamp).collect();
+        assert_eq!(
+            timestamps,
+            vec![
+                1_609_459_200_000_000,
+                1_609_459_200_001_000,
+                1_609_459_200_002_000,
+                1_609_459_200_003_000,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_normalize_aggtrade_batch_reports_first_bad_index() {
+        let mut trades = vec![
+            trade_at(1, 1_609_459_200),
+            trade_at(2, -5),
+            trade_at(3, i64::MAX),
+        ];
+
+        let err = normalize_aggtrade_batch(&mut trades, TimeUnit::Seconds).unwrap_err();
+        assert_eq!(
+            err,
+            TimestampError::AtIndex {
+                index: 1,
+                source: Box::new(TimestampError::Negative { value: -5 }),
+            }
+        );
+
+        // Slice untouched on error
+        assert_eq!(trades[0].timestamp, 1_609_459_200);
+    }
 }
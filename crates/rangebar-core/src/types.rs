@@ -1,11 +1,23 @@
 //! Type definitions for range bar processing
 
-use crate::fixed_point::FixedPoint;
+use crate::fixed_point::{BASIS_POINTS_SCALE, FixedPoint, FixedPointParseError, SCALE};
+use crate::timestamp::normalize_timestamp;
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 /// Data source for market data (future-proofing for multi-exchange support)
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+///
+/// Recorded on every [`RangeBar`] so bars from different providers can be
+/// compared. Quote-based sources build bars from mid-price synthetic trades
+/// and carry no aggressor side: their buy/sell fields are zero and
+/// [`direction_known`](Self::direction_known) is `false`.
+///
+/// Each variant identifies a [`Provider`] and [`Market`]; use
+/// [`provider`](Self::provider) and [`market`](Self::market) to partition a
+/// mixed bar set by origin.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, Default)]
 #[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum DataSource {
     /// Binance Spot Market (8 fields including is_best_match)
     BinanceSpot,
@@ -14,6 +26,100 @@ pub enum DataSource {
     BinanceFuturesUM,
     /// Binance Coin-Margined Futures
     BinanceFuturesCM,
+    /// Exness Raw_Spread forex quotes (Bid/Ask only, no volume or direction)
+    Exness,
+    /// OKX spot trades
+    OkxSpot,
+    /// OKX perpetual swap trades
+    OkxSwap,
+    /// Kraken spot trades
+    Kraken,
+    /// Trades loaded from a user-supplied CSV layout
+    GenericCsv,
+}
+
+/// Venue or data vendor a [`DataSource`] comes from
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Provider {
+    Binance,
+    Exness,
+    Okx,
+    Kraken,
+    GenericCsv,
+}
+
+/// Market segment a [`DataSource`] trades in
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Market {
+    Spot,
+    /// USD(T)-margined futures
+    UsdMarginedFutures,
+    /// Coin-margined futures
+    CoinMarginedFutures,
+    /// Perpetual swaps quoted in contracts
+    PerpetualSwap,
+    /// OTC forex quotes
+    Forex,
+    /// Not known to the loader
+    Unspecified,
+}
+
+impl DataSource {
+    /// Every data source, in declaration order
+    pub const ALL: [DataSource; 8] = [
+        DataSource::BinanceSpot,
+        DataSource::BinanceFuturesUM,
+        DataSource::BinanceFuturesCM,
+        DataSource::Exness,
+        DataSource::OkxSpot,
+        DataSource::OkxSwap,
+        DataSource::Kraken,
+        DataSource::GenericCsv,
+    ];
+
+    /// Provider this source's data comes from
+    pub fn provider(&self) -> Provider {
+        match self {
+            DataSource::BinanceSpot
+            | DataSource::BinanceFuturesUM
+            | DataSource::BinanceFuturesCM => Provider::Binance,
+            DataSource::Exness => Provider::Exness,
+            DataSource::OkxSpot | DataSource::OkxSwap => Provider::Okx,
+            DataSource::Kraken => Provider::Kraken,
+            DataSource::GenericCsv => Provider::GenericCsv,
+        }
+    }
+
+    /// Market segment this source's data comes from
+    pub fn market(&self) -> Market {
+        match self {
+            DataSource::BinanceSpot | DataSource::OkxSpot | DataSource::Kraken => Market::Spot,
+            DataSource::BinanceFuturesUM => Market::UsdMarginedFutures,
+            DataSource::BinanceFuturesCM => Market::CoinMarginedFutures,
+            DataSource::OkxSwap => Market::PerpetualSwap,
+            DataSource::Exness => Market::Forex,
+            DataSource::GenericCsv => Market::Unspecified,
+        }
+    }
+
+    /// Data source for a provider/market pair, `None` if the pair is not supported
+    pub fn from_parts(provider: Provider, market: Market) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|source| source.provider() == provider && source.market() == market)
+    }
+
+    /// Whether trades from this source carry an aggressor side
+    ///
+    /// When `false`, buy/sell volumes, counts and turnovers are zero by
+    /// convention and order-flow metrics should be ignored.
+    pub fn direction_known(&self) -> bool {
+        !matches!(self, DataSource::Exness)
+    }
 }
 
 /// Aggregate trade data from Binance markets
@@ -22,6 +128,7 @@ pub enum DataSource {
 /// exchange trades that occurred at the same price within ~100ms timeframe.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct AggTrade {
     /// Aggregate trade ID (unique per AggTrade record)
     pub agg_trade_id: i64,
@@ -61,14 +168,173 @@ impl AggTrade {
     }
 
     /// Turnover (price * volume) as i128 to prevent overflow
+    ///
+    /// Exact product of the raw fixed-point values, scaled by `SCALE²` (1e16).
     pub fn turnover(&self) -> i128 {
         (self.price.0 as i128) * (self.volume.0 as i128)
     }
+
+    /// Build trades from parallel columnar arrays in one pass
+    ///
+    /// All columns must have the same length as `ids`. Each record is treated as
+    /// a single individual trade (`first_trade_id == last_trade_id == agg_trade_id`)
+    /// and `is_best_match` is left as `None`.
+    pub fn from_columns(
+        ids: &[i64],
+        prices: &[FixedPoint],
+        volumes: &[FixedPoint],
+        timestamps: &[i64],
+        is_buyer_maker: &[bool],
+    ) -> Result<Vec<AggTrade>, ConversionError> {
+        let expected = ids.len();
+        for (column, actual) in [
+            ("prices", prices.len()),
+            ("volumes", volumes.len()),
+            ("timestamps", timestamps.len()),
+            ("is_buyer_maker", is_buyer_maker.len()),
+        ] {
+            if actual != expected {
+                return Err(ConversionError::ColumnLengthMismatch {
+                    column,
+                    expected,
+                    actual,
+                });
+            }
+        }
+
+        Ok((0..expected)
+            .map(|i| AggTrade {
+                agg_trade_id: ids[i],
+                price: prices[i],
+                volume: volumes[i],
+                first_trade_id: ids[i],
+                last_trade_id: ids[i],
+                timestamp: timestamps[i],
+                is_buyer_maker: is_buyer_maker[i],
+                is_best_match: None,
+            })
+            .collect())
+    }
+}
+
+/// Errors building trades from columnar or row-oriented input
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum ConversionError {
+    #[error("Column '{column}' has {actual} rows, expected {expected}")]
+    ColumnLengthMismatch {
+        column: &'static str,
+        expected: usize,
+        actual: usize,
+    },
+
+    /// Price or volume is not a valid fixed-point decimal
+    #[error("Invalid {field}: {source}")]
+    InvalidNumber {
+        field: &'static str,
+        #[source]
+        source: FixedPointParseError,
+    },
+
+    /// Price or volume is NaN or infinite
+    #[error("{field} is not finite")]
+    NonFinite { field: &'static str },
+
+    /// Trade time is before the Unix epoch
+    #[error("Negative timestamp {timestamp}")]
+    NegativeTimestamp { timestamp: i64 },
+}
+
+impl ConversionError {
+    /// Whether the error is confined to a single malformed record
+    ///
+    /// Non-finite, empty or non-numeric decimals can be skipped; out-of-range
+    /// or over-precise decimals and column mismatches should abort the load.
+    pub fn is_skippable(&self) -> bool {
+        match self {
+            ConversionError::NonFinite { .. } | ConversionError::NegativeTimestamp { .. } => true,
+            ConversionError::InvalidNumber { source, .. } => source.is_skippable(),
+            ConversionError::ColumnLengthMismatch { .. } => false,
+        }
+    }
+}
+
+/// Raw Binance aggTrade payload using Binance's single-letter field names
+///
+/// Deserializes WebSocket `aggTrade` events and REST `/api/v3/aggTrades`
+/// entries directly; event envelope fields (`e`, `E`, `s`) are ignored. Price
+/// and quantity strings are parsed exactly into [`FixedPoint`] without passing
+/// through `f64`, so malformed numbers fail deserialization. Converting to
+/// [`AggTrade`] rejects negative trade times.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct BinanceAggTradeJson {
+    /// Aggregate trade ID (`a`)
+    #[serde(rename = "a")]
+    pub agg_trade_id: i64,
+
+    /// Price (`p`)
+    #[serde(rename = "p", deserialize_with = "fixed_point_from_str")]
+    pub price: FixedPoint,
+
+    /// Quantity (`q`)
+    #[serde(rename = "q", deserialize_with = "fixed_point_from_str")]
+    pub quantity: FixedPoint,
+
+    /// First individual trade ID (`f`)
+    #[serde(rename = "f")]
+    pub first_trade_id: i64,
+
+    /// Last individual trade ID (`l`)
+    #[serde(rename = "l")]
+    pub last_trade_id: i64,
+
+    /// Trade time in milliseconds or microseconds (`T`)
+    #[serde(rename = "T")]
+    pub trade_time: i64,
+
+    /// Whether the buyer was the maker (`m`)
+    #[serde(rename = "m")]
+    pub is_buyer_maker: bool,
+
+    /// Whether the trade was the best price match (`M`, spot only)
+    #[serde(rename = "M", default)]
+    pub is_best_match: Option<bool>,
+}
+
+impl TryFrom<BinanceAggTradeJson> for AggTrade {
+    type Error = ConversionError;
+
+    fn try_from(raw: BinanceAggTradeJson) -> Result<Self, Self::Error> {
+        let trade_time =
+            u64::try_from(raw.trade_time).map_err(|_| ConversionError::NegativeTimestamp {
+                timestamp: raw.trade_time,
+            })?;
+
+        Ok(AggTrade {
+            agg_trade_id: raw.agg_trade_id,
+            price: raw.price,
+            volume: raw.quantity,
+            first_trade_id: raw.first_trade_id,
+            last_trade_id: raw.last_trade_id,
+            timestamp: normalize_timestamp(trade_time),
+            is_buyer_maker: raw.is_buyer_maker,
+            is_best_match: raw.is_best_match,
+        })
+    }
+}
+
+/// Parse a decimal string field into [`FixedPoint`]
+fn fixed_point_from_str<'de, D>(deserializer: D) -> Result<FixedPoint, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let value = String::deserialize(deserializer)?;
+    FixedPoint::from_str(&value).map_err(serde::de::Error::custom)
 }
 
 /// Range bar with OHLCV data and market microstructure enhancements
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct RangeBar {
     /// Opening timestamp in microseconds (first trade)
     pub open_time: i64,
@@ -92,6 +358,8 @@ pub struct RangeBar {
     pub volume: FixedPoint,
 
     /// Total turnover (sum of price * volume)
+    /// Exact integer product of the raw fixed-point values (`price.0 * volume.0`),
+    /// so it carries a scale of `SCALE²` (1e16)
     pub turnover: i128,
 
     /// Total number of individual exchange trades in this range bar
@@ -131,10 +399,56 @@ pub struct RangeBar {
     pub vwap: FixedPoint,
 
     /// Turnover from buy-side trades (buy pressure)
+    /// Same `SCALE²` (1e16) scale as `turnover`; see [`RangeBar::buy_turnover_fixed`]
     pub buy_turnover: i128,
 
     /// Turnover from sell-side trades (sell pressure)
+    /// Same `SCALE²` (1e16) scale as `turnover`; see [`RangeBar::sell_turnover_fixed`]
     pub sell_turnover: i128,
+
+    // === OPTIONAL ANNOTATIONS ===
+    /// Upper breach threshold fixed at bar open
+    /// Set only when the processor was built `with_threshold_annotation(true)`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub threshold_upper: Option<FixedPoint>,
+
+    /// Lower breach threshold fixed at bar open
+    /// Set only when the processor was built `with_threshold_annotation(true)`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub threshold_lower: Option<FixedPoint>,
+    /// Why the bar closed
+    /// Set only when the processor was built `with_close_reason(true)`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub close_reason: Option<CloseReason>,
+}
+
+/// Why a range bar was closed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub enum CloseReason {
+    /// Price reached the upper threshold
+    UpwardBreach,
+    /// Price reached the lower threshold
+    DownwardBreach,
+    /// Bar reached the maximum aggTrade record count (analysis mode, not a breach)
+    TradeCap,
+    /// Next trade fell in a new session, e.g. a new UTC day (analysis mode, not a breach)
+    SessionBoundary,
+    /// Data ended before a breach (incomplete trailing bar)
+    EndOfData,
+}
+
+impl CloseReason {
+    /// Direction of a threshold breach by `price` against the bar's upper threshold
+    pub fn from_breach(price: FixedPoint, upper_threshold: FixedPoint) -> Self {
+        if price >= upper_threshold {
+            CloseReason::UpwardBreach
+        } else {
+            CloseReason::DownwardBreach
+        }
+    }
 }
 
 impl RangeBar {
@@ -187,6 +501,139 @@ impl RangeBar {
             vwap: trade.price, // Initial VWAP equals opening price
             buy_turnover,
             sell_turnover,
+            threshold_upper: None,
+            threshold_lower: None,
+            close_reason: None,
+        }
+    }
+
+    /// Column names in canonical export order, matching [`RangeBar::to_csv_row`]
+    ///
+    /// Single source of truth for CSV/Parquet column ordering. Optional
+    /// annotations (`threshold_upper`, `threshold_lower`, `close_reason`) are
+    /// not part of the stable columns.
+    pub const fn csv_header() -> &'static [&'static str] {
+        &[
+            "open_time",
+            "close_time",
+            "open",
+            "high",
+            "low",
+            "close",
+            "volume",
+            "turnover",
+            "individual_trade_count",
+            "agg_record_count",
+            "first_trade_id",
+            "last_trade_id",
+            "data_source",
+            "buy_volume",
+            "sell_volume",
+            "buy_trade_count",
+            "sell_trade_count",
+            "vwap",
+            "buy_turnover",
+            "sell_turnover",
+        ]
+    }
+
+    /// Bar values in [`RangeBar::csv_header`] order
+    ///
+    /// FixedPoint fields use the canonical 8-decimal string; turnovers are the
+    /// raw `SCALE²` integers.
+    pub fn to_csv_row(&self) -> Vec<String> {
+        vec![
+            self.open_time.to_string(),
+            self.close_time.to_string(),
+            self.open.to_string(),
+            self.high.to_string(),
+            self.low.to_string(),
+            self.close.to_string(),
+            self.volume.to_string(),
+            self.turnover.to_string(),
+            self.individual_trade_count.to_string(),
+            self.agg_record_count.to_string(),
+            self.first_trade_id.to_string(),
+            self.last_trade_id.to_string(),
+            format!("{:?}", self.data_source),
+            self.buy_volume.to_string(),
+            self.sell_volume.to_string(),
+            self.buy_trade_count.to_string(),
+            self.sell_trade_count.to_string(),
+            self.vwap.to_string(),
+            self.buy_turnover.to_string(),
+            self.sell_turnover.to_string(),
+        ]
+    }
+
+    /// Total turnover as a FixedPoint quote amount
+    ///
+    /// Rescales from `SCALE²` to `SCALE`, truncating toward zero below 1e-8 and
+    /// saturating at the FixedPoint range.
+    pub fn turnover_fixed(&self) -> FixedPoint {
+        turnover_to_fixed(self.turnover)
+    }
+
+    /// Buy-side turnover as a FixedPoint quote amount (see [`RangeBar::turnover_fixed`])
+    pub fn buy_turnover_fixed(&self) -> FixedPoint {
+        turnover_to_fixed(self.buy_turnover)
+    }
+
+    /// Sell-side turnover as a FixedPoint quote amount (see [`RangeBar::turnover_fixed`])
+    pub fn sell_turnover_fixed(&self) -> FixedPoint {
+        turnover_to_fixed(self.sell_turnover)
+    }
+
+    /// High-low range relative to open, in decimal basis points
+    ///
+    /// Returns 0.0 for a non-positive open.
+    pub fn range_decimal_bps(&self) -> f64 {
+        if self.open.0 <= 0 {
+            return 0.0;
+        }
+        (self.high.0 - self.low.0) as f64 * BASIS_POINTS_SCALE as f64 / self.open.0 as f64
+    }
+
+    /// Largest move from open to either extreme, in decimal basis points
+    ///
+    /// A correctly built bar never exceeds its threshold on the non-breaching
+    /// side, and exceeds it on the breaching side only by the last trade's
+    /// overshoot. Returns 0.0 for a non-positive open.
+    pub fn max_excursion_decimal_bps(&self) -> f64 {
+        if self.open.0 <= 0 {
+            return 0.0;
+        }
+        let excursion = (self.high.0 - self.open.0).max(self.open.0 - self.low.0);
+        excursion as f64 * BASIS_POINTS_SCALE as f64 / self.open.0 as f64
+    }
+
+    /// Signed order-flow volume delta: buy volume minus sell volume
+    pub fn volume_delta(&self) -> FixedPoint {
+        FixedPoint(self.buy_volume.0 - self.sell_volume.0)
+    }
+
+    /// This bar's contribution to cumulative volume delta (CVD)
+    ///
+    /// Identical to [`volume_delta`](Self::volume_delta); provided so running
+    /// CVD totals read naturally at the call site.
+    pub fn cvd_contribution(&self) -> FixedPoint {
+        self.volume_delta()
+    }
+
+    /// Whether buy/sell fields are meaningful for this bar's data source
+    pub fn direction_known(&self) -> bool {
+        self.data_source.direction_known()
+    }
+
+    /// Fraction of volume from aggressive buyers, in `[0, 1]`
+    ///
+    /// Returns 0.0 when the bar has no buy or sell volume.
+    pub fn buy_ratio(&self) -> f64 {
+        let total = self.buy_volume.0 as i128 + self.sell_volume.0 as i128;
+        if total <= 0 {
+            0.0
+        } else {
+            self.buy_volume.0 as f64 / total as f64
         }
     }
 
@@ -264,14 +711,108 @@ impl RangeBar {
     /// # Returns
     ///
     /// `true` if price breaches either threshold
+    ///
+    /// Comparisons are inclusive: a price exactly on a threshold is a breach,
+    /// so the touching trade closes (and is included in) the bar.
     pub fn is_breach(
         &self,
         price: FixedPoint,
         upper_threshold: FixedPoint,
         lower_threshold: FixedPoint,
     ) -> bool {
-        price >= upper_threshold || price <= lower_threshold
+        crate::fixed_point::is_breach(price, upper_threshold, lower_threshold)
     }
+
+    /// Check bar self-consistency (OHLC bounds, time order, volume split)
+    ///
+    /// Unlike the processor's `debug_assert!`s, this runs in release builds too.
+    /// Returns the first violation found.
+    pub fn validate(&self) -> Result<(), BarValidationError> {
+        if self.high < self.low {
+            return Err(BarValidationError::HighBelowLow {
+                high: self.high,
+                low: self.low,
+            });
+        }
+        if self.high < self.open.max(self.close) {
+            return Err(BarValidationError::HighBelowOpenClose {
+                high: self.high,
+                open: self.open,
+                close: self.close,
+            });
+        }
+        if self.low > self.open.min(self.close) {
+            return Err(BarValidationError::LowAboveOpenClose {
+                low: self.low,
+                open: self.open,
+                close: self.close,
+            });
+        }
+        if self.close_time < self.open_time {
+            return Err(BarValidationError::CloseBeforeOpen {
+                open_time: self.open_time,
+                close_time: self.close_time,
+            });
+        }
+        if self.volume.0 < 0 {
+            return Err(BarValidationError::NegativeVolume {
+                volume: self.volume,
+            });
+        }
+        self.validate_volume()
+    }
+
+    /// Check that buy and sell volume add up to total volume
+    pub fn validate_volume(&self) -> Result<(), BarValidationError> {
+        if self.buy_volume.0 as i128 + self.sell_volume.0 as i128 != self.volume.0 as i128 {
+            return Err(BarValidationError::VolumeMismatch {
+                volume: self.volume,
+                buy_volume: self.buy_volume,
+                sell_volume: self.sell_volume,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Rescale a `SCALE²` turnover to a FixedPoint, saturating on overflow
+fn turnover_to_fixed(turnover: i128) -> FixedPoint {
+    let scaled = turnover / SCALE as i128;
+    FixedPoint(scaled.clamp(i64::MIN as i128, i64::MAX as i128) as i64)
+}
+
+/// Range bar self-consistency violations reported by [`RangeBar::validate`]
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum BarValidationError {
+    #[error("High {high} is below low {low}")]
+    HighBelowLow { high: FixedPoint, low: FixedPoint },
+
+    #[error("High {high} is below max(open {open}, close {close})")]
+    HighBelowOpenClose {
+        high: FixedPoint,
+        open: FixedPoint,
+        close: FixedPoint,
+    },
+
+    #[error("Low {low} is above min(open {open}, close {close})")]
+    LowAboveOpenClose {
+        low: FixedPoint,
+        open: FixedPoint,
+        close: FixedPoint,
+    },
+
+    #[error("Close time {close_time} is before open time {open_time}")]
+    CloseBeforeOpen { open_time: i64, close_time: i64 },
+
+    #[error("Negative volume {volume}")]
+    NegativeVolume { volume: FixedPoint },
+
+    #[error("Buy volume {buy_volume} + sell volume {sell_volume} != volume {volume}")]
+    VolumeMismatch {
+        volume: FixedPoint,
+        buy_volume: FixedPoint,
+        sell_volume: FixedPoint,
+    },
 }
 
 #[cfg(test)]
@@ -279,6 +820,40 @@ mod tests {
     use super::*;
     use crate::test_utils;
 
+    #[test]
+    fn test_binance_aggtrade_json_into_agg_trade() {
+        let frame = r#"{"e":"aggTrade","E":1758666334424,"s":"BTCUSDT","a":3679713739,"p":"112070.01000000","q":"0.01328000","f":5252203256,"l":5252203266,"T":1758666334424,"m":false,"M":true}"#;
+        let raw: BinanceAggTradeJson = serde_json::from_str(frame).unwrap();
+        let trade = AggTrade::try_from(raw).unwrap();
+
+        assert_eq!(trade.agg_trade_id, 3679713739);
+        assert_eq!(trade.price, FixedPoint(11_207_001_000_000));
+        assert_eq!(trade.volume, FixedPoint(1_328_000));
+        assert_eq!(trade.first_trade_id, 5252203256);
+        assert_eq!(trade.last_trade_id, 5252203266);
+        assert_eq!(trade.individual_trade_count(), 11);
+        assert_eq!(trade.timestamp, 1_758_666_334_424_000);
+        assert!(!trade.is_buyer_maker);
+        assert_eq!(trade.is_best_match, Some(true));
+
+        // REST entries carry no envelope; bad numbers are rejected, not zeroed
+        let rest = r#"[{"a":1,"p":"0.1","q":"2","f":1,"l":1,"T":1498793709153,"m":true}]"#;
+        let page: Vec<BinanceAggTradeJson> = serde_json::from_str(rest).unwrap();
+        assert_eq!(page[0].price, FixedPoint(10_000_000));
+        assert_eq!(page[0].is_best_match, None);
+        assert!(
+            serde_json::from_str::<BinanceAggTradeJson>(&frame.replace("112070.01000000", "nan"))
+                .is_err()
+        );
+
+        // A negative trade time must not wrap into a far-future u64
+        let raw: BinanceAggTradeJson =
+            serde_json::from_str(&frame.replace("\"T\":1758666334424", "\"T\":-1")).unwrap();
+        let err = AggTrade::try_from(raw).unwrap_err();
+        assert_eq!(err, ConversionError::NegativeTimestamp { timestamp: -1 });
+        assert!(err.is_skippable());
+    }
+
     #[test]
     fn test_agg_trade_creation() {
         let trade = test_utils::create_test_agg_trade_with_range(
@@ -402,4 +977,238 @@ mod tests {
         );
         println!("   VWAP: {}", bar.vwap.to_string());
     }
+
+    #[test]
+    fn test_volume_delta_and_buy_ratio() {
+        // 1.0 bought, then 2.0 sold
+        let bar = valid_bar();
+        assert_eq!(bar.volume_delta(), FixedPoint::from_str("-1.0").unwrap());
+        assert_eq!(bar.cvd_contribution(), bar.volume_delta());
+        assert!((bar.buy_ratio() - 1.0 / 3.0).abs() < 1e-12);
+
+        let mut empty = bar.clone();
+        empty.buy_volume = FixedPoint(0);
+        empty.sell_volume = FixedPoint(0);
+        assert_eq!(empty.volume_delta(), FixedPoint(0));
+        assert_eq!(empty.buy_ratio(), 0.0);
+    }
+
+    fn valid_bar() -> RangeBar {
+        let mut bar = RangeBar::new(&test_utils::create_test_agg_trade_with_range(
+            1,
+            "50000.0",
+            "1.0",
+            1640995200000,
+            1,
+            1,
+            false,
+        ));
+        bar.update_with_trade(&test_utils::create_test_agg_trade_with_range(
+            2,
+            "50125.0",
+            "2.0",
+            1640995201000,
+            2,
+            2,
+            true,
+        ));
+        bar
+    }
+
+    #[test]
+    fn test_turnover_split_is_exact() {
+        let buy = test_utils::create_test_agg_trade_with_range(
+            1,
+            "50000.12345678",
+            "1.23456789",
+            1640995200000,
+            1,
+            1,
+            false,
+        );
+        let sell = test_utils::create_test_agg_trade_with_range(
+            2,
+            "50001.87654321",
+            "9.87654321",
+            1640995201000,
+            2,
+            4,
+            true,
+        );
+
+        let mut bar = RangeBar::new(&buy);
+        bar.update_with_trade(&sell);
+
+        assert_eq!(bar.buy_turnover, buy.turnover());
+        assert_eq!(bar.sell_turnover, sell.turnover());
+        assert_eq!(bar.buy_turnover + bar.sell_turnover, bar.turnover);
+
+        // 50000.12345678 * 1.23456789 = 61728.54691577|63907942 (truncated at 1e-8)
+        assert_eq!(bar.buy_turnover_fixed().to_string(), "61728.54691577");
+
+        // Per-side truncation can lose at most one unit each
+        let split_loss =
+            bar.turnover_fixed().0 - bar.buy_turnover_fixed().0 - bar.sell_turnover_fixed().0;
+        assert!((0..=1).contains(&split_loss));
+    }
+
+    #[test]
+    fn test_csv_row_matches_header() {
+        let trade = test_utils::create_test_agg_trade(1, "50000.5", "1.25", 1000);
+        let bar = RangeBar::new(&trade);
+
+        let header = RangeBar::csv_header();
+        let row = bar.to_csv_row();
+        assert_eq!(header.len(), row.len());
+        assert_eq!(
+            row,
+            vec![
+                "1000",
+                "1000",
+                "50000.50000000",
+                "50000.50000000",
+                "50000.50000000",
+                "50000.50000000",
+                "1.25000000",
+                "625006250000000000000",
+                "1",
+                "1",
+                "10",
+                "10",
+                "BinanceFuturesUM",
+                "1.25000000",
+                "0.00000000",
+                "1",
+                "0",
+                "50000.50000000",
+                "625006250000000000000",
+                "0",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_data_source_identity_round_trip() {
+        for source in DataSource::ALL {
+            assert_eq!(
+                DataSource::from_parts(source.provider(), source.market()),
+                Some(source)
+            );
+            let json = serde_json::to_string(&source).unwrap();
+            assert_eq!(serde_json::from_str::<DataSource>(&json).unwrap(), source);
+        }
+
+        assert_eq!(DataSource::default(), DataSource::BinanceFuturesUM);
+        assert_eq!(DataSource::OkxSwap.market(), Market::PerpetualSwap);
+        assert_eq!(
+            DataSource::from_parts(Provider::Kraken, Market::Forex),
+            None
+        );
+    }
+
+    #[test]
+    fn test_validate_accepts_processed_bar() {
+        assert_eq!(valid_bar().validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_rejects_invalid_bars() {
+        let price = |s: &str| FixedPoint::from_str(s).unwrap();
+
+        let mut bar = valid_bar();
+        bar.high = price("50100.0"); // below close 50125
+        assert!(matches!(
+            bar.validate(),
+            Err(BarValidationError::HighBelowOpenClose { .. })
+        ));
+
+        let mut bar = valid_bar();
+        bar.low = price("50010.0"); // above open 50000
+        assert!(matches!(
+            bar.validate(),
+            Err(BarValidationError::LowAboveOpenClose { .. })
+        ));
+
+        let mut bar = valid_bar();
+        bar.low = price("60000.0");
+        assert!(matches!(
+            bar.validate(),
+            Err(BarValidationError::HighBelowLow { .. })
+        ));
+
+        let mut bar = valid_bar();
+        bar.close_time = bar.open_time - 1;
+        assert_eq!(
+            bar.validate(),
+            Err(BarValidationError::CloseBeforeOpen {
+                open_time: bar.open_time,
+                close_time: bar.open_time - 1,
+            })
+        );
+
+        let mut bar = valid_bar();
+        bar.volume = FixedPoint(-1);
+        bar.buy_volume = FixedPoint(0);
+        bar.sell_volume = FixedPoint(-1);
+        assert!(matches!(
+            bar.validate(),
+            Err(BarValidationError::NegativeVolume { .. })
+        ));
+
+        let mut bar = valid_bar();
+        bar.sell_volume = price("1.5");
+        let err = bar.validate().unwrap_err();
+        assert!(matches!(err, BarValidationError::VolumeMismatch { .. }));
+        assert_eq!(
+            err.to_string(),
+            "Buy volume 1.00000000 + sell volume 1.50000000 != volume 3.00000000"
+        );
+    }
+
+    #[test]
+    fn test_from_columns() {
+        let prices = [
+            FixedPoint::from_str("50000.0").unwrap(),
+            FixedPoint::from_str("50001.5").unwrap(),
+        ];
+        let volumes = [
+            FixedPoint::from_str("1.0").unwrap(),
+            FixedPoint::from_str("0.25").unwrap(),
+        ];
+
+        let trades = AggTrade::from_columns(
+            &[10, 11],
+            &prices,
+            &volumes,
+            &[1_000_000, 2_000_000],
+            &[false, true],
+        )
+        .unwrap();
+
+        assert_eq!(trades.len(), 2);
+        assert_eq!(trades[1].agg_trade_id, 11);
+        assert_eq!(trades[1].price, prices[1]);
+        assert_eq!(trades[1].volume, volumes[1]);
+        assert_eq!(trades[1].timestamp, 2_000_000);
+        assert!(trades[1].is_buyer_maker);
+        assert_eq!(trades[1].individual_trade_count(), 1);
+        assert_eq!(trades[1].is_best_match, None);
+    }
+
+    #[test]
+    fn test_from_columns_length_mismatch() {
+        let price = FixedPoint::from_str("50000.0").unwrap();
+        let err =
+            AggTrade::from_columns(&[1, 2], &[price, price], &[price], &[1, 2], &[true, false])
+                .unwrap_err();
+
+        assert_eq!(
+            err,
+            ConversionError::ColumnLengthMismatch {
+                column: "volumes",
+                expected: 2,
+                actual: 1,
+            }
+        );
+    }
 }
@@ -0,0 +1,52 @@
+//! WebAssembly bindings for in-browser bar construction
+//!
+//! A thin `wasm-bindgen` wrapper over [`RangeBarProcessor`]. Trades are passed
+//! in their serialized [`AggTrade`] form, so prices and volumes are integers
+//! scaled by 10^8 and timestamps are microseconds (see [`crate::schema`] for
+//! the exact encoding when the `schema` feature is enabled).
+
+use crate::processor::RangeBarProcessor;
+use crate::types::AggTrade;
+use wasm_bindgen::prelude::*;
+
+/// Build range bars from a JS array of trades
+///
+/// Returns the completed bars as a JSON array string; a trailing bar that has
+/// not breached is not included, matching
+/// [`RangeBarProcessor::process_agg_trade_records`]. Turnover fields exceed
+/// JS's safe-integer range, so parse them with a BigInt-aware JSON reader if
+/// exact values are needed.
+///
+/// Throws if `threshold_bps` (decimal bps, e.g. 250 = 25bps) is out of range,
+/// `trades` is not an array of trades, or the trades are not sorted.
+#[wasm_bindgen]
+pub fn wasm_process(threshold_bps: u32, trades: JsValue) -> Result<JsValue, JsError> {
+    let trades: Vec<AggTrade> = serde_wasm_bindgen::from_value(trades)?;
+    let bars = RangeBarProcessor::new(threshold_bps)?.process_agg_trade_records(&trades)?;
+    Ok(JsValue::from_str(&serde_json::to_string(&bars)?))
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    #[wasm_bindgen_test]
+    fn test_breach_sequence_bar_count() {
+        // +0.4% breaches 25bps; the third trade opens a bar that never closes
+        let trades = test_utils::create_breach_test_sequence();
+        let input = serde_wasm_bindgen::to_value(&trades).unwrap();
+
+        let Ok(output) = wasm_process(250, input) else {
+            panic!("wasm_process rejected a valid trade sequence");
+        };
+        let bars: Vec<serde_json::Value> =
+            serde_json::from_str(&output.as_string().unwrap()).unwrap();
+        assert_eq!(bars.len(), 1);
+        assert_eq!(bars[0]["open"], trades[0].price.0);
+        assert_eq!(bars[0]["close"], trades[1].price.0);
+
+        assert!(wasm_process(0, serde_wasm_bindgen::to_value(&trades).unwrap()).is_err());
+    }
+}
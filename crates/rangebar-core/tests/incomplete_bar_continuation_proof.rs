@@ -121,7 +121,7 @@ fn test_incomplete_bar_continues_across_year_boundary() {
     println!("  Trade count:    {}", inc_bar.agg_record_count);
     println!("  Open time:      {}", inc_bar.open_time);
 
-    let incomplete_open = inc_bar.open.clone();
+    let incomplete_open = inc_bar.open;
     let incomplete_trade_count = inc_bar.agg_record_count;
 
     // ═══════════════════════════════════════════════════════════════════
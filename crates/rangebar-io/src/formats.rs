@@ -12,6 +12,43 @@ pub trait DataFrameConverter<T> {
     /// Convert to Polars DataFrame
     fn to_polars_dataframe(&self) -> PolarsResult<DataFrame>;
 
+    /// Read agg trades from a DataFrame with the standard aggTrade columns
+    ///
+    /// Unlike `Vec::<AggTrade>::from_polars_dataframe`, which expects raw scaled
+    /// `i64` prices, `price` and `volume` may be `Decimal` or `String` columns and
+    /// are converted to [`FixedPoint`] exactly (no f64 round trip). Raw `i64`
+    /// fixed-point columns are still accepted.
+    fn trades_from_dataframe(&self, df: &DataFrame) -> Result<Vec<AggTrade>, ConversionError> {
+        validate_aggtrade_columns(df)?;
+
+        let agg_trade_ids = extract_i64_column(df, "agg_trade_id")?;
+        let prices = extract_fixed_point_column(df, "price")?;
+        let volumes = extract_fixed_point_column(df, "volume")?;
+        let first_trade_ids = extract_i64_column(df, "first_trade_id")?;
+        let last_trade_ids = extract_i64_column(df, "last_trade_id")?;
+        let timestamps = extract_i64_column(df, "timestamp")?;
+        let is_buyer_makers = extract_bool_column(df, "is_buyer_maker")?;
+
+        let mut agg_trades = Vec::with_capacity(df.height());
+        for i in 0..df.height() {
+            let agg_trade = AggTrade {
+                agg_trade_id: agg_trade_ids[i],
+                price: prices[i],
+                volume: volumes[i],
+                first_trade_id: first_trade_ids[i],
+                last_trade_id: last_trade_ids[i],
+                timestamp: timestamps[i],
+                is_buyer_maker: is_buyer_makers[i],
+                is_best_match: None,
+            };
+
+            validate_agg_trade(&agg_trade)?;
+            agg_trades.push(agg_trade);
+        }
+
+        Ok(agg_trades)
+    }
+
     /// Convert from Polars DataFrame
     fn from_polars_dataframe(df: DataFrame) -> Result<T, ConversionError>;
 }
@@ -39,29 +76,8 @@ pub enum ConversionError {
     FixedPointError(String),
 }
 
-/// Required columns for RangeBar DataFrame
-pub const RANGEBAR_COLUMNS: &[&str] = &[
-    "open_time",
-    "close_time",
-    "open",
-    "high",
-    "low",
-    "close",
-    "volume",
-    "turnover",
-    "individual_trade_count",
-    "agg_record_count",
-    "first_trade_id",
-    "last_trade_id",
-    "data_source",
-    "buy_volume",
-    "sell_volume",
-    "buy_trade_count",
-    "sell_trade_count",
-    "vwap",
-    "buy_turnover",
-    "sell_turnover",
-];
+/// Required columns for RangeBar DataFrame (canonical order from [`RangeBar::csv_header`])
+pub const RANGEBAR_COLUMNS: &[&str] = RangeBar::csv_header();
 
 /// Required columns for AggTrade DataFrame
 pub const AGGTRADE_COLUMNS: &[&str] = &[
@@ -197,6 +213,9 @@ impl DataFrameConverter<Vec<RangeBar>> for Vec<RangeBar> {
                 vwap: FixedPoint(vwaps[i]),
                 buy_turnover: buy_turnovers[i] as i128,
                 sell_turnover: sell_turnovers[i] as i128,
+                threshold_upper: None,
+                threshold_lower: None,
+                close_reason: None,
             };
 
             // Validate range bar data integrity
@@ -271,6 +290,178 @@ impl DataFrameConverter<Vec<AggTrade>> for Vec<AggTrade> {
     }
 }
 
+/// RangeBar columns holding FixedPoint prices or volumes
+const FIXED_POINT_BAR_COLUMNS: &[&str] = &[
+    "open",
+    "high",
+    "low",
+    "close",
+    "volume",
+    "buy_volume",
+    "sell_volume",
+    "vwap",
+];
+
+/// Build range bars from a column subset, leaving absent fields zeroed
+///
+/// Price and volume columns are restored as exact FixedPoint values; integer
+/// columns are copied as-is and `data_source` falls back to its default. No
+/// integrity validation is applied since partial bars legitimately fail it.
+pub(crate) fn range_bars_from_projection(df: &DataFrame) -> Result<Vec<RangeBar>, ConversionError> {
+    let mut bars: Vec<RangeBar> = (0..df.height()).map(|_| blank_range_bar()).collect();
+
+    for name in df.get_column_names() {
+        let name = name.as_str();
+        let values: Vec<i64> = if FIXED_POINT_BAR_COLUMNS.contains(&name) {
+            extract_fixed_point_column(df, name)?
+                .into_iter()
+                .map(|value| value.0)
+                .collect()
+        } else if name == "data_source" {
+            continue;
+        } else {
+            extract_i64_column(df, name)?
+        };
+
+        for (bar, value) in bars.iter_mut().zip(values) {
+            set_projected_field(bar, name, value);
+        }
+    }
+
+    Ok(bars)
+}
+
+fn blank_range_bar() -> RangeBar {
+    RangeBar {
+        open_time: 0,
+        close_time: 0,
+        open: FixedPoint(0),
+        high: FixedPoint(0),
+        low: FixedPoint(0),
+        close: FixedPoint(0),
+        volume: FixedPoint(0),
+        turnover: 0,
+        individual_trade_count: 0,
+        agg_record_count: 0,
+        first_trade_id: 0,
+        last_trade_id: 0,
+        data_source: rangebar_core::DataSource::default(),
+        buy_volume: FixedPoint(0),
+        sell_volume: FixedPoint(0),
+        buy_trade_count: 0,
+        sell_trade_count: 0,
+        vwap: FixedPoint(0),
+        buy_turnover: 0,
+        sell_turnover: 0,
+        threshold_upper: None,
+        threshold_lower: None,
+        close_reason: None,
+    }
+}
+
+/// Assign a raw column value to the matching RangeBar field (unknown names are ignored)
+fn set_projected_field(bar: &mut RangeBar, column_name: &str, value: i64) {
+    match column_name {
+        "open_time" => bar.open_time = value,
+        "close_time" => bar.close_time = value,
+        "open" => bar.open = FixedPoint(value),
+        "high" => bar.high = FixedPoint(value),
+        "low" => bar.low = FixedPoint(value),
+        "close" => bar.close = FixedPoint(value),
+        "volume" => bar.volume = FixedPoint(value),
+        "turnover" => bar.turnover = value as i128,
+        "individual_trade_count" => bar.individual_trade_count = value as u32,
+        "agg_record_count" => bar.agg_record_count = value as u32,
+        "first_trade_id" => bar.first_trade_id = value,
+        "last_trade_id" => bar.last_trade_id = value,
+        "buy_volume" => bar.buy_volume = FixedPoint(value),
+        "sell_volume" => bar.sell_volume = FixedPoint(value),
+        "buy_trade_count" => bar.buy_trade_count = value as u32,
+        "sell_trade_count" => bar.sell_trade_count = value as u32,
+        "vwap" => bar.vwap = FixedPoint(value),
+        "buy_turnover" => bar.buy_turnover = value as i128,
+        "sell_turnover" => bar.sell_turnover = value as i128,
+        _ => {}
+    }
+}
+
+/// Extract a Decimal, String, or raw i64 column as exact FixedPoint values
+fn extract_fixed_point_column(
+    df: &DataFrame,
+    column_name: &str,
+) -> Result<Vec<FixedPoint>, ConversionError> {
+    let series = df
+        .column(column_name)
+        .map_err(|_| ConversionError::MissingColumn {
+            column: column_name.to_string(),
+        })?;
+
+    let null_error = |row: usize| {
+        ConversionError::FixedPointError(format!("column '{column_name}' row {row}: null value"))
+    };
+
+    match series.dtype() {
+        DataType::Int64 => Ok(extract_i64_column(df, column_name)?
+            .into_iter()
+            .map(FixedPoint)
+            .collect()),
+        DataType::String => series
+            .str()?
+            .iter()
+            .enumerate()
+            .map(|(row, value)| {
+                let value = value.ok_or_else(|| null_error(row))?;
+                FixedPoint::from_str(value).map_err(|e| {
+                    ConversionError::FixedPointError(format!(
+                        "column '{column_name}' row {row}: {e}"
+                    ))
+                })
+            })
+            .collect(),
+        DataType::Decimal(_, _) => {
+            let decimals = series.as_materialized_series().decimal()?;
+            let scale = decimals.scale();
+            decimals
+                .physical()
+                .iter()
+                .enumerate()
+                .map(|(row, value)| {
+                    let value = value.ok_or_else(|| null_error(row))?;
+                    decimal_to_fixed_point(value, scale).ok_or_else(|| {
+                        ConversionError::FixedPointError(format!(
+                            "column '{column_name}' row {row}: decimal {value} (scale {scale}) \
+                             is not exactly representable with 8 decimal places"
+                        ))
+                    })
+                })
+                .collect()
+        }
+        other => Err(ConversionError::InvalidDataType {
+            column: column_name.to_string(),
+            expected: "Decimal, String, or i64".to_string(),
+            actual: format!("{:?}", other),
+        }),
+    }
+}
+
+/// Rescale an unscaled decimal to FixedPoint's 8 decimal places, rejecting precision loss
+fn decimal_to_fixed_point(unscaled: i128, scale: usize) -> Option<FixedPoint> {
+    const FIXED_POINT_DECIMALS: usize = 8;
+
+    let scaled = if scale <= FIXED_POINT_DECIMALS {
+        let factor = 10i128.checked_pow((FIXED_POINT_DECIMALS - scale) as u32)?;
+        unscaled.checked_mul(factor)?
+    } else {
+        let divisor = 10i128.checked_pow((scale - FIXED_POINT_DECIMALS) as u32)?;
+        if unscaled % divisor != 0 {
+            return None;
+        }
+        unscaled / divisor
+    };
+
+    i64::try_from(scaled).ok().map(FixedPoint)
+}
+
 /// Validate RangeBar DataFrame has required columns
 fn validate_rangebar_columns(df: &DataFrame) -> Result<(), ConversionError> {
     for &column in RANGEBAR_COLUMNS {
@@ -456,6 +647,9 @@ mod tests {
             vwap: FixedPoint(105000000),
             buy_turnover: 630000000,
             sell_turnover: 420000000,
+            threshold_upper: None,
+            threshold_lower: None,
+            close_reason: None,
         }
     }
 
@@ -509,6 +703,72 @@ mod tests {
         assert!(matches!(result, Err(ConversionError::MissingColumn { .. })));
     }
 
+    fn aggtrade_dataframe(prices: Column, volumes: Column) -> DataFrame {
+        DataFrame::new(vec![
+            Column::new("agg_trade_id".into(), vec![1i64, 2]),
+            prices,
+            volumes,
+            Column::new("first_trade_id".into(), vec![10i64, 12]),
+            Column::new("last_trade_id".into(), vec![11i64, 12]),
+            Column::new("timestamp".into(), vec![1_000_000i64, 2_000_000]),
+            Column::new("is_buyer_maker".into(), vec![false, true]),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_trades_from_dataframe_string_and_decimal() {
+        let string_df = aggtrade_dataframe(
+            Column::new("price".into(), vec!["50000.12345678", "50001.5"]),
+            Column::new("volume".into(), vec!["0.00000001", "2"]),
+        );
+        let decimal_df = aggtrade_dataframe(
+            Column::new("price".into(), vec!["50000.12345678", "50001.5"])
+                .cast(&DataType::Decimal(Some(18), Some(8)))
+                .unwrap(),
+            Column::new("volume".into(), vec!["0.00000001", "2"])
+                .cast(&DataType::Decimal(Some(18), Some(10)))
+                .unwrap(),
+        );
+
+        for df in [string_df, decimal_df] {
+            let trades = Vec::<AggTrade>::new().trades_from_dataframe(&df).unwrap();
+            assert_eq!(trades.len(), 2);
+            assert_eq!(trades[0].price, FixedPoint(5_000_012_345_678));
+            assert_eq!(trades[0].volume, FixedPoint(1));
+            assert_eq!(trades[1].price, FixedPoint(5_000_150_000_000));
+            assert_eq!(trades[1].volume, FixedPoint(200_000_000));
+            assert_eq!(trades[1].individual_trade_count(), 1);
+            assert!(trades[1].is_buyer_maker);
+        }
+    }
+
+    #[test]
+    fn test_trades_from_dataframe_column_errors() {
+        let mut df = aggtrade_dataframe(
+            Column::new("price".into(), vec!["50000.0", "50001.0"]),
+            Column::new("volume".into(), vec![1.0f64, 2.0]),
+        );
+        let converter = Vec::<AggTrade>::new();
+        match converter.trades_from_dataframe(&df) {
+            Err(ConversionError::InvalidDataType { column, .. }) => assert_eq!(column, "volume"),
+            other => panic!("Expected InvalidDataType, got {:?}", other),
+        }
+
+        df.drop_in_place("timestamp").unwrap();
+        match converter.trades_from_dataframe(&df) {
+            Err(ConversionError::MissingColumn { column }) => assert_eq!(column, "timestamp"),
+            other => panic!("Expected MissingColumn, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_decimal_to_fixed_point_rejects_precision_loss() {
+        assert_eq!(decimal_to_fixed_point(15, 1), Some(FixedPoint(150_000_000)));
+        assert_eq!(decimal_to_fixed_point(1_000, 11), Some(FixedPoint(1)));
+        assert_eq!(decimal_to_fixed_point(1, 9), None);
+    }
+
     #[test]
     fn test_invalid_range_bar_validation() {
         let mut invalid_bar = create_test_range_bar();
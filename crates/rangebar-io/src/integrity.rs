@@ -0,0 +1,98 @@
+//! Content hashing for exported files
+
+use sha2::{Digest, Sha256};
+use std::io::{Read, Write};
+use std::path::Path;
+
+/// Writer that hashes every byte it passes through to the inner writer
+///
+/// Lets exporters report a content hash without re-reading the output file.
+/// Only bytes the inner writer accepted are hashed, so the digest always
+/// matches what was written.
+pub struct HashingWriter<W: Write> {
+    inner: W,
+    hasher: Sha256,
+}
+
+impl<W: Write> HashingWriter<W> {
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner,
+            hasher: Sha256::new(),
+        }
+    }
+
+    /// Underlying writer
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Lowercase hex SHA-256 of everything written so far
+    ///
+    /// Drops the inner writer; flush first if it buffers.
+    pub fn finalize(self) -> String {
+        format!("{:x}", self.hasher.finalize())
+    }
+}
+
+impl<W: Write> Write for HashingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.hasher.update(&buf[..written]);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Lowercase hex SHA-256 of a file's contents, read in chunks
+pub fn compute_sha256_hash<P: AsRef<Path>>(path: P) -> std::io::Result<String> {
+    let mut file = std::fs::File::open(path)?;
+    let mut hasher = Sha256::new();
+    let mut buffer = [0u8; 64 * 1024];
+
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_compute_sha256_hash_known_vector() {
+        let temp_dir = tempdir().unwrap();
+        let path = temp_dir.path().join("abc.txt");
+        std::fs::write(&path, b"abc").unwrap();
+
+        assert_eq!(
+            compute_sha256_hash(&path).unwrap(),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn test_hashing_writer_matches_file_hash() {
+        let temp_dir = tempdir().unwrap();
+        let path = temp_dir.path().join("tee.txt");
+
+        let mut writer = HashingWriter::new(std::fs::File::create(&path).unwrap());
+        writer.write_all(b"a").unwrap();
+        writer.write_all(b"bc").unwrap();
+        writer.flush().unwrap();
+        let hash = writer.finalize();
+
+        assert_eq!(std::fs::read(&path).unwrap(), b"abc");
+        assert_eq!(hash, compute_sha256_hash(&path).unwrap());
+    }
+}
@@ -3,18 +3,24 @@
 //! This module provides efficient I/O operations for reading and writing
 //! range bar data in various formats including CSV, Parquet, and Arrow.
 
+pub mod integrity;
+pub mod metadata;
+
 #[cfg(feature = "parquet")]
 pub mod polars_io;
 
 #[cfg(feature = "parquet")]
 pub mod formats;
 
+pub use integrity::{HashingWriter, compute_sha256_hash};
+pub use metadata::RangeBarMetadata;
+
 // Re-export commonly used types when parquet feature is enabled
 #[cfg(feature = "parquet")]
 pub use formats::{ConversionError, DataFrameConverter};
 
 #[cfg(feature = "parquet")]
 pub use polars_io::{
-    ArrowExporter, ExportError, ParquetExporter, PolarsExporter, PolarsExporterConfig,
-    StreamingCsvExporter,
+    ArrowExporter, ExportError, FeatherExporter, ParquetCodec, ParquetExporter, PolarsExporter,
+    PolarsExporterConfig, StreamingCsvExporter,
 };
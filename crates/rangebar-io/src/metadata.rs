@@ -0,0 +1,69 @@
+//! Metadata sidecars written next to exported bar files
+
+use crate::integrity::compute_sha256_hash;
+use rangebar_core::RangeBar;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Descriptive summary of an exported bar file, stored as `<output>.meta.json`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RangeBarMetadata {
+    /// Instrument symbol, if known to the exporter
+    pub symbol: Option<String>,
+
+    /// Threshold the bars were built with, in decimal basis points
+    pub threshold_decimal_bps: Option<u32>,
+
+    /// Number of bars in the data file
+    pub bar_count: usize,
+
+    /// Open time of the first bar in microseconds
+    pub first_open_time: Option<i64>,
+
+    /// Close time of the last bar in microseconds
+    pub last_close_time: Option<i64>,
+
+    /// File name of the data file this sidecar describes
+    pub data_file: String,
+
+    /// Lowercase hex SHA-256 of the data file
+    pub sha256: String,
+}
+
+impl RangeBarMetadata {
+    /// Describe `bars` as written to `data_path`, hashing the file on disk
+    pub fn for_export(
+        bars: &[RangeBar],
+        data_path: &Path,
+        symbol: Option<String>,
+        threshold_decimal_bps: Option<u32>,
+    ) -> std::io::Result<Self> {
+        Ok(Self {
+            symbol,
+            threshold_decimal_bps,
+            bar_count: bars.len(),
+            first_open_time: bars.first().map(|bar| bar.open_time),
+            last_close_time: bars.last().map(|bar| bar.close_time),
+            data_file: data_path
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default(),
+            sha256: compute_sha256_hash(data_path)?,
+        })
+    }
+
+    /// Sidecar location for a data file: the full file name plus `.meta.json`
+    pub fn sidecar_path(data_path: &Path) -> PathBuf {
+        let mut path = data_path.as_os_str().to_owned();
+        path.push(".meta.json");
+        PathBuf::from(path)
+    }
+
+    /// Write this metadata as pretty JSON next to `data_path`
+    pub fn write_sidecar(&self, data_path: &Path) -> std::io::Result<PathBuf> {
+        let path = Self::sidecar_path(data_path);
+        let json = serde_json::to_vec_pretty(self)?;
+        std::fs::write(&path, json)?;
+        Ok(path)
+    }
+}
@@ -3,16 +3,50 @@
 //! High-performance export capabilities using Polars for efficient
 //! file format conversion and streaming operations.
 
-use crate::formats::{ConversionError, DataFrameConverter};
+use crate::formats::{
+    ConversionError, DataFrameConverter, RANGEBAR_COLUMNS, range_bars_from_projection,
+};
+use crate::integrity::HashingWriter;
+use crate::metadata::RangeBarMetadata;
 use polars::prelude::*;
+use polars_arrow::record_batch::RecordBatch;
 use rangebar_core::RangeBar;
+use std::io::{BufWriter, Write};
 use std::path::Path;
 use thiserror::Error;
 
+/// Parquet compression codec
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ParquetCodec {
+    /// Fast, moderate ratio (no level)
+    #[default]
+    Snappy,
+    /// Best ratio; level 1-22
+    Zstd,
+    /// Widely compatible; level 0-9
+    Gzip,
+    /// No compression (no level)
+    Uncompressed,
+}
+
+impl ParquetCodec {
+    /// Accepted compression levels, `None` if the codec takes no level
+    pub fn level_range(self) -> Option<std::ops::RangeInclusive<u8>> {
+        match self {
+            ParquetCodec::Zstd => Some(1..=22),
+            ParquetCodec::Gzip => Some(0..=9),
+            ParquetCodec::Snappy | ParquetCodec::Uncompressed => None,
+        }
+    }
+}
+
 /// Polars exporter configuration
 #[derive(Debug, Clone)]
 pub struct PolarsExporterConfig {
-    /// Compression level for Parquet files (0-22, higher = better compression)
+    /// Compression codec for Parquet files
+    pub parquet_codec: ParquetCodec,
+
+    /// Compression level for Parquet files (ignored by codecs without levels)
     pub parquet_compression_level: Option<u8>,
 
     /// Row group size for Parquet files
@@ -28,6 +62,7 @@ pub struct PolarsExporterConfig {
 impl Default for PolarsExporterConfig {
     fn default() -> Self {
         Self {
+            parquet_codec: ParquetCodec::Snappy,
             parquet_compression_level: Some(6), // Good balance of speed/compression
             parquet_row_group_size: Some(100_000), // Optimal for range bar data
             parquet_statistics: true,
@@ -36,6 +71,45 @@ impl Default for PolarsExporterConfig {
     }
 }
 
+impl PolarsExporterConfig {
+    /// Set the Parquet codec and level, validating the level for that codec
+    ///
+    /// `None` uses the codec's default level. Snappy and Uncompressed reject
+    /// any explicit level.
+    pub fn with_compression(
+        mut self,
+        codec: ParquetCodec,
+        level: Option<u8>,
+    ) -> Result<Self, ExportError> {
+        if let Some(level) = level
+            && !codec
+                .level_range()
+                .is_some_and(|range| range.contains(&level))
+        {
+            return Err(ExportError::InvalidCompressionLevel { codec, level });
+        }
+
+        self.parquet_codec = codec;
+        self.parquet_compression_level = level;
+        Ok(self)
+    }
+
+    /// Polars compression setting for the configured codec and level
+    fn parquet_compression(&self) -> PolarsResult<ParquetCompression> {
+        let level = self.parquet_compression_level;
+        Ok(match self.parquet_codec {
+            ParquetCodec::Snappy => ParquetCompression::Snappy,
+            ParquetCodec::Uncompressed => ParquetCompression::Uncompressed,
+            ParquetCodec::Zstd => {
+                ParquetCompression::Zstd(level.map(|l| ZstdLevel::try_new(l as i32)).transpose()?)
+            }
+            ParquetCodec::Gzip => {
+                ParquetCompression::Gzip(level.map(GzipLevel::try_new).transpose()?)
+            }
+        })
+    }
+}
+
 /// Main Polars exporter for range bar data
 #[derive(Debug)]
 pub struct PolarsExporter {
@@ -72,6 +146,15 @@ impl PolarsExporter {
             }
         })?;
 
+        let compression =
+            self.config
+                .parquet_compression()
+                .map_err(|e| ExportError::WriteFailed {
+                    format: "parquet".to_string(),
+                    path: path.as_ref().to_string_lossy().to_string(),
+                    source: e.into(),
+                })?;
+
         // Write Parquet file directly
         let mut file =
             std::fs::File::create(path.as_ref()).map_err(|e| ExportError::WriteFailed {
@@ -81,7 +164,7 @@ impl PolarsExporter {
             })?;
 
         ParquetWriter::new(&mut file)
-            .with_compression(ParquetCompression::Snappy)
+            .with_compression(compression)
             .with_statistics(if self.config.parquet_statistics {
                 StatisticsOptions::default()
             } else {
@@ -97,6 +180,7 @@ impl PolarsExporter {
         Ok(ParquetExportResult {
             records_written: range_bars.len(),
             file_path: path.as_ref().to_string_lossy().to_string(),
+            metadata_path: None,
         })
     }
 
@@ -154,15 +238,15 @@ impl PolarsExporter {
             }
         })?;
 
-        // Write CSV file directly
-        let mut file =
-            std::fs::File::create(path.as_ref()).map_err(|e| ExportError::WriteFailed {
-                format: "csv".to_string(),
-                path: path.as_ref().to_string_lossy().to_string(),
-                source: e.into(),
-            })?;
+        // Write CSV file directly, hashing bytes as they are written
+        let file = std::fs::File::create(path.as_ref()).map_err(|e| ExportError::WriteFailed {
+            format: "csv".to_string(),
+            path: path.as_ref().to_string_lossy().to_string(),
+            source: e.into(),
+        })?;
+        let mut writer = HashingWriter::new(file);
 
-        CsvWriter::new(&mut file)
+        CsvWriter::new(&mut writer)
             .include_header(true)
             .with_separator(b',')
             .finish(&mut df.clone())
@@ -175,6 +259,7 @@ impl PolarsExporter {
         Ok(CsvExportResult {
             records_written: range_bars.len(),
             file_path: path.as_ref().to_string_lossy().to_string(),
+            sha256: writer.finalize(),
         })
     }
 }
@@ -189,21 +274,119 @@ impl Default for PolarsExporter {
 #[derive(Debug)]
 pub struct ParquetExporter {
     exporter: PolarsExporter,
+    write_metadata: bool,
+    symbol: Option<String>,
+    threshold_decimal_bps: Option<u32>,
 }
 
 impl ParquetExporter {
     pub fn new() -> Self {
+        Self::with_config(PolarsExporterConfig::default())
+    }
+
+    /// Create a Parquet exporter with custom configuration (e.g. compression)
+    pub fn with_config(config: PolarsExporterConfig) -> Self {
         Self {
-            exporter: PolarsExporter::new(),
+            exporter: PolarsExporter::with_config(config),
+            write_metadata: false,
+            symbol: None,
+            threshold_decimal_bps: None,
         }
     }
 
+    /// Write a [`RangeBarMetadata`] sidecar (`<output>.meta.json`) after each export
+    pub fn with_metadata(mut self, write_metadata: bool) -> Self {
+        self.write_metadata = write_metadata;
+        self
+    }
+
+    /// Symbol recorded in the metadata sidecar
+    pub fn with_symbol(mut self, symbol: impl Into<String>) -> Self {
+        self.symbol = Some(symbol.into());
+        self
+    }
+
+    /// Threshold recorded in the metadata sidecar, in decimal basis points
+    pub fn with_threshold_decimal_bps(mut self, threshold_decimal_bps: u32) -> Self {
+        self.threshold_decimal_bps = Some(threshold_decimal_bps);
+        self
+    }
+
     pub fn export<P: AsRef<Path>>(
         &self,
         range_bars: &[RangeBar],
         path: P,
     ) -> Result<ParquetExportResult, ExportError> {
-        self.exporter.export_parquet(range_bars, path)
+        let mut result = self.exporter.export_parquet(range_bars, path.as_ref())?;
+
+        if self.write_metadata {
+            let sidecar = RangeBarMetadata::for_export(
+                range_bars,
+                path.as_ref(),
+                self.symbol.clone(),
+                self.threshold_decimal_bps,
+            )
+            .and_then(|metadata| metadata.write_sidecar(path.as_ref()))
+            .map_err(|e| ExportError::WriteFailed {
+                format: "metadata".to_string(),
+                path: RangeBarMetadata::sidecar_path(path.as_ref())
+                    .to_string_lossy()
+                    .to_string(),
+                source: e.into(),
+            })?;
+            result.metadata_path = Some(sidecar.to_string_lossy().to_string());
+        }
+
+        Ok(result)
+    }
+
+    /// Read a subset of bars from a Parquet file written by [`export`](Self::export)
+    ///
+    /// Uses a lazy scan so only the requested `columns` are decoded and row
+    /// groups outside `time_range` (inclusive bounds on `close_time`, in
+    /// microseconds) are skipped via column statistics. Selected price and
+    /// volume columns are restored as exact FixedPoint values; unselected
+    /// fields are left zeroed. `None` for `columns` reads every bar column.
+    pub fn read_filtered(
+        path: &Path,
+        columns: Option<&[&str]>,
+        time_range: Option<(i64, i64)>,
+    ) -> Result<Vec<RangeBar>, ExportError> {
+        let columns = columns.unwrap_or(RANGEBAR_COLUMNS);
+        if let Some(unknown) = columns.iter().find(|c| !RANGEBAR_COLUMNS.contains(c)) {
+            return Err(ExportError::ConversionFailed {
+                source: ConversionError::MissingColumn {
+                    column: unknown.to_string(),
+                },
+            });
+        }
+
+        let read_failed = |e: PolarsError| ExportError::ReadFailed {
+            format: "parquet".to_string(),
+            path: path.to_string_lossy().to_string(),
+            source: e.into(),
+        };
+
+        let mut lazy = LazyFrame::scan_parquet(
+            PlPath::new(&path.to_string_lossy()),
+            ScanArgsParquet::default(),
+        )
+        .map_err(read_failed)?;
+
+        if let Some((start, end)) = time_range {
+            lazy = lazy.filter(
+                col("close_time")
+                    .gt_eq(lit(start))
+                    .and(col("close_time").lt_eq(lit(end))),
+            );
+        }
+
+        let df = lazy
+            .select(columns.iter().map(|c| col(*c)).collect::<Vec<_>>())
+            .collect()
+            .map_err(read_failed)?;
+
+        range_bars_from_projection(&df).map_err(|source| ExportError::ConversionFailed { source })
     }
 }
 
@@ -233,6 +416,40 @@ impl ArrowExporter {
     ) -> Result<ArrowExportResult, ExportError> {
         self.exporter.export_arrow_ipc(range_bars, path)
     }
+
+    /// Stream bars out as fixed-size Arrow record batches without materializing the source
+    ///
+    /// Each batch carries the full RangeBar schema and exactly `batch_rows`
+    /// rows, except a shorter final batch. A `batch_rows` of 0 is treated as 1.
+    pub fn stream_batches(
+        &self,
+        bars: impl Iterator<Item = RangeBar>,
+        batch_rows: usize,
+    ) -> impl Iterator<Item = Result<RecordBatch, ExportError>> {
+        let batch_rows = batch_rows.max(1);
+        let mut bars = bars;
+
+        std::iter::from_fn(move || {
+            let batch: Vec<RangeBar> = bars.by_ref().take(batch_rows).collect();
+            if batch.is_empty() {
+                return None;
+            }
+
+            Some(record_batch(batch))
+        })
+    }
+}
+
+/// Convert one batch of bars into a single Arrow record batch
+fn record_batch(bars: Vec<RangeBar>) -> Result<RecordBatch, ExportError> {
+    let conversion_failed = |e| ExportError::ConversionFailed {
+        source: ConversionError::PolarsError(e),
+    };
+    let mut df = bars.to_polars_dataframe().map_err(conversion_failed)?;
+    df.as_single_chunk();
+    df.iter_chunks(CompatLevel::newest(), false)
+        .next()
+        .ok_or_else(|| conversion_failed(PolarsError::NoData("Empty RangeBar batch".into())))
 }
 
 impl Default for ArrowExporter {
@@ -241,25 +458,133 @@ impl Default for ArrowExporter {
     }
 }
 
+/// Feather (Arrow IPC file) exporter and reader for short-lived interchange
+///
+/// Writes the same columns as [`ArrowExporter`] and [`ParquetExporter`]; prices
+/// and volumes stay raw fixed-point integers, so values round-trip exactly.
+/// Files open directly with `pandas.read_feather` and `arrow::read_feather`.
+#[derive(Debug)]
+pub struct FeatherExporter {
+    exporter: PolarsExporter,
+}
+
+impl FeatherExporter {
+    pub fn new() -> Self {
+        Self {
+            exporter: PolarsExporter::new(),
+        }
+    }
+
+    pub fn export<P: AsRef<Path>>(
+        &self,
+        range_bars: &[RangeBar],
+        path: P,
+    ) -> Result<ArrowExportResult, ExportError> {
+        self.exporter.export_arrow_ipc(range_bars, path)
+    }
+
+    /// Read bars back from a Feather file, validating each bar
+    pub fn read<P: AsRef<Path>>(path: P) -> Result<Vec<RangeBar>, ExportError> {
+        let read_failed = |e: Box<dyn std::error::Error + Send + Sync>| ExportError::ReadFailed {
+            format: "feather".to_string(),
+            path: path.as_ref().to_string_lossy().to_string(),
+            source: e,
+        };
+
+        let file = std::fs::File::open(path.as_ref()).map_err(|e| read_failed(e.into()))?;
+        let df = polars::io::ipc::IpcReader::new(file)
+            .finish()
+            .map_err(|e| read_failed(e.into()))?;
+
+        Vec::<RangeBar>::from_polars_dataframe(df)
+            .map_err(|source| ExportError::ConversionFailed { source })
+    }
+}
+
+impl Default for FeatherExporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Specialized streaming CSV exporter
+///
+/// Writes rows incrementally using [`RangeBar::csv_header`] and
+/// [`RangeBar::to_csv_row`], so prices and volumes appear as canonical
+/// 8-decimal strings rather than raw fixed-point integers.
 #[derive(Debug)]
 pub struct StreamingCsvExporter {
-    exporter: PolarsExporter,
+    flush_each_bar: bool,
 }
 
 impl StreamingCsvExporter {
     pub fn new() -> Self {
         Self {
-            exporter: PolarsExporter::new(),
+            flush_each_bar: false,
         }
     }
 
+    /// Flush the writer after every completed row so the file can be tailed
+    ///
+    /// Each row is written in full before the flush, so readers never observe
+    /// a partial line. Flushing per bar turns every row into a write syscall
+    /// and can cut throughput by an order of magnitude on large exports;
+    /// leave it off for batch jobs.
+    pub fn with_flush_each_bar(mut self, flush_each_bar: bool) -> Self {
+        self.flush_each_bar = flush_each_bar;
+        self
+    }
+
+    /// Write the header and one row per bar to an arbitrary writer
+    ///
+    /// Returns the number of rows written. Without flush-each-bar the writer
+    /// is flushed once at the end.
+    pub fn export_to_writer<W: Write>(
+        &self,
+        range_bars: &[RangeBar],
+        mut writer: W,
+    ) -> std::io::Result<usize> {
+        writeln!(writer, "{}", RangeBar::csv_header().join(","))?;
+        for bar in range_bars {
+            let row = format!("{}\n", bar.to_csv_row().join(","));
+            writer.write_all(row.as_bytes())?;
+            if self.flush_each_bar {
+                writer.flush()?;
+            }
+        }
+        if !self.flush_each_bar {
+            writer.flush()?;
+        }
+        Ok(range_bars.len())
+    }
+
     pub fn export<P: AsRef<Path>>(
         &self,
         range_bars: &[RangeBar],
         path: P,
     ) -> Result<CsvExportResult, ExportError> {
-        self.exporter.export_streaming_csv(range_bars, path)
+        if range_bars.is_empty() {
+            return Err(ExportError::EmptyData);
+        }
+
+        let file_path = path.as_ref().to_string_lossy().to_string();
+        let write_failed = |e: std::io::Error| ExportError::WriteFailed {
+            format: "csv".to_string(),
+            path: file_path.clone(),
+            source: e.into(),
+        };
+
+        let file = std::fs::File::create(path.as_ref()).map_err(write_failed)?;
+        let mut writer = HashingWriter::new(BufWriter::new(file));
+        let records_written = self
+            .export_to_writer(range_bars, &mut writer)
+            .map_err(write_failed)?;
+
+        Ok(CsvExportResult {
+            records_written,
+            file_path,
+            sha256: writer.finalize(),
+        })
     }
 }
 
@@ -274,6 +599,8 @@ impl Default for StreamingCsvExporter {
 pub struct ParquetExportResult {
     pub records_written: usize,
     pub file_path: String,
+    /// Sidecar written alongside the data file, if metadata was enabled
+    pub metadata_path: Option<String>,
 }
 
 /// Arrow export result
@@ -288,6 +615,8 @@ pub struct ArrowExportResult {
 pub struct CsvExportResult {
     pub records_written: usize,
     pub file_path: String,
+    /// Lowercase hex SHA-256 of the written file, computed while writing
+    pub sha256: String,
 }
 
 /// Export operation errors
@@ -310,6 +639,17 @@ pub enum ExportError {
         source: Box<dyn std::error::Error + Send + Sync>,
     },
 
+    #[error("Compression level {level} is not valid for {codec:?}")]
+    InvalidCompressionLevel { codec: ParquetCodec, level: u8 },
+
+    #[error("Failed to read {format} file '{path}'")]
+    ReadFailed {
+        format: String,
+        path: String,
+        #[source]
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+
     #[error("I/O error: {0}")]
     IoError(#[from] std::io::Error),
 }
@@ -343,6 +683,9 @@ mod tests {
                 vwap: FixedPoint(105000000),
                 buy_turnover: 630000000,
                 sell_turnover: 420000000,
+                threshold_upper: None,
+                threshold_lower: None,
+                close_reason: None,
             },
             RangeBar {
                 open_time: 1000002,
@@ -365,6 +708,9 @@ mod tests {
                 vwap: FixedPoint(110000000),
                 buy_turnover: 1320000000,
                 sell_turnover: 880000000,
+                threshold_upper: None,
+                threshold_lower: None,
+                close_reason: None,
             },
         ]
     }
@@ -444,4 +790,292 @@ mod tests {
         let csv_result = csv_exporter.export(&range_bars, &csv_path).unwrap();
         assert_eq!(csv_result.records_written, 2);
     }
+
+    #[test]
+    fn test_streaming_csv_uses_canonical_rows() {
+        let range_bars = create_test_range_bars();
+        let temp_dir = tempdir().unwrap();
+        let file_path = temp_dir.path().join("canonical.csv");
+
+        StreamingCsvExporter::new()
+            .export(&range_bars, &file_path)
+            .unwrap();
+
+        let contents = std::fs::read_to_string(&file_path).unwrap();
+        let lines: Vec<&str> = contents.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], RangeBar::csv_header().join(","));
+        assert_eq!(lines[1], range_bars[0].to_csv_row().join(","));
+        assert!(lines[1].starts_with("1000000,1000001,1.00000000,1.10000000,"));
+    }
+
+    /// Writer that records every flush and the buffer length at that point
+    #[derive(Default)]
+    struct FlushRecorder {
+        data: Vec<u8>,
+        flushed_at: Vec<usize>,
+    }
+
+    impl Write for FlushRecorder {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.data.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            self.flushed_at.push(self.data.len());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_streaming_csv_flush_each_bar() {
+        let range_bars = create_many_range_bars(5);
+
+        let mut recorder = FlushRecorder::default();
+        StreamingCsvExporter::new()
+            .with_flush_each_bar(true)
+            .export_to_writer(&range_bars, &mut recorder)
+            .unwrap();
+
+        assert_eq!(recorder.flushed_at.len(), range_bars.len());
+        // Every flush lands on a line boundary
+        for &offset in &recorder.flushed_at {
+            assert_eq!(recorder.data[offset - 1], b'\n');
+        }
+
+        let mut buffered = FlushRecorder::default();
+        StreamingCsvExporter::new()
+            .export_to_writer(&range_bars, &mut buffered)
+            .unwrap();
+        assert_eq!(buffered.flushed_at.len(), 1);
+        assert_eq!(buffered.data, recorder.data);
+    }
+
+    /// `count` bars cycling the test template with shifted times and prices
+    fn create_many_range_bars(count: usize) -> Vec<RangeBar> {
+        let template = create_test_range_bars();
+        (0..count)
+            .map(|i| {
+                let mut bar = template[i % template.len()].clone();
+                bar.open_time += i as i64 * 10;
+                bar.close_time += i as i64 * 10;
+                bar.open = FixedPoint(bar.open.0 + i as i64);
+                bar.close = FixedPoint(bar.close.0 - i as i64);
+                bar
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_parquet_compression_codecs() {
+        let bars = create_many_range_bars(10_000);
+        let temp_dir = tempdir().unwrap();
+
+        let write = |codec, level, name: &str| {
+            let config = PolarsExporterConfig::default()
+                .with_compression(codec, level)
+                .unwrap();
+            let path = temp_dir.path().join(name);
+            ParquetExporter::with_config(config)
+                .export(&bars, &path)
+                .unwrap();
+            path
+        };
+        let zstd_path = write(ParquetCodec::Zstd, Some(19), "zstd.parquet");
+        let snappy_path = write(ParquetCodec::Snappy, None, "snappy.parquet");
+
+        let rows = |path: &Path| -> Vec<Vec<String>> {
+            ParquetExporter::read_filtered(path, None, None)
+                .unwrap()
+                .iter()
+                .map(RangeBar::to_csv_row)
+                .collect()
+        };
+        assert_eq!(rows(&zstd_path), rows(&snappy_path));
+        assert_eq!(rows(&zstd_path).len(), bars.len());
+
+        let size = |path: &Path| std::fs::metadata(path).unwrap().len();
+        assert_ne!(size(&zstd_path), size(&snappy_path));
+
+        // Levels are validated per codec
+        let config = PolarsExporterConfig::default();
+        assert!(matches!(
+            config
+                .clone()
+                .with_compression(ParquetCodec::Zstd, Some(23)),
+            Err(ExportError::InvalidCompressionLevel { level: 23, .. })
+        ));
+        assert!(
+            config
+                .clone()
+                .with_compression(ParquetCodec::Gzip, Some(10))
+                .is_err()
+        );
+        assert!(
+            config
+                .clone()
+                .with_compression(ParquetCodec::Snappy, Some(1))
+                .is_err()
+        );
+        assert!(
+            config
+                .with_compression(ParquetCodec::Uncompressed, None)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_parquet_metadata_sidecar() {
+        let bars = create_many_range_bars(100);
+        let temp_dir = tempdir().unwrap();
+        let file_path = temp_dir.path().join("bars.parquet");
+
+        let result = ParquetExporter::new()
+            .with_metadata(true)
+            .with_symbol("BTCUSDT")
+            .with_threshold_decimal_bps(250)
+            .export(&bars, &file_path)
+            .unwrap();
+
+        let sidecar_path = temp_dir.path().join("bars.parquet.meta.json");
+        assert_eq!(
+            result.metadata_path.as_deref(),
+            Some(sidecar_path.to_string_lossy().as_ref())
+        );
+
+        let metadata: RangeBarMetadata =
+            serde_json::from_slice(&std::fs::read(&sidecar_path).unwrap()).unwrap();
+        assert_eq!(metadata.symbol.as_deref(), Some("BTCUSDT"));
+        assert_eq!(metadata.threshold_decimal_bps, Some(250));
+        assert_eq!(metadata.bar_count, 100);
+        assert_eq!(metadata.first_open_time, Some(bars[0].open_time));
+        assert_eq!(metadata.last_close_time, Some(bars[99].close_time));
+        assert_eq!(
+            metadata.sha256,
+            crate::integrity::compute_sha256_hash(&file_path).unwrap()
+        );
+
+        // Disabled by default
+        let plain_path = temp_dir.path().join("plain.parquet");
+        let plain = ParquetExporter::new().export(&bars, &plain_path).unwrap();
+        assert!(plain.metadata_path.is_none());
+        assert!(!RangeBarMetadata::sidecar_path(&plain_path).exists());
+    }
+
+    #[test]
+    fn test_feather_round_trip_matches_parquet() {
+        let bars = create_many_range_bars(1_000);
+        let temp_dir = tempdir().unwrap();
+        let feather_path = temp_dir.path().join("bars.feather");
+        let parquet_path = temp_dir.path().join("bars.parquet");
+
+        let result = FeatherExporter::new().export(&bars, &feather_path).unwrap();
+        assert_eq!(result.records_written, bars.len());
+        ParquetExporter::new().export(&bars, &parquet_path).unwrap();
+
+        let from_feather = FeatherExporter::read(&feather_path).unwrap();
+        let from_parquet = ParquetExporter::read_filtered(&parquet_path, None, None).unwrap();
+
+        assert_eq!(from_feather.len(), bars.len());
+        for ((feather, parquet), original) in from_feather.iter().zip(&from_parquet).zip(&bars) {
+            assert_eq!(feather.to_csv_row(), parquet.to_csv_row());
+            assert_eq!(feather.vwap, original.vwap);
+            assert_eq!(feather.turnover, original.turnover);
+        }
+    }
+
+    #[test]
+    fn test_parquet_read_filtered_time_slice() {
+        let bars = create_many_range_bars(10_000);
+
+        let temp_dir = tempdir().unwrap();
+        let file_path = temp_dir.path().join("filtered.parquet");
+        ParquetExporter::new().export(&bars, &file_path).unwrap();
+
+        let start = bars[3_000].close_time;
+        let end = bars[3_999].close_time;
+        let read = ParquetExporter::read_filtered(
+            &file_path,
+            Some(&["open", "high", "low", "close"]),
+            Some((start, end)),
+        )
+        .unwrap();
+
+        assert_eq!(read.len(), 1_000);
+        for (got, expected) in read.iter().zip(&bars[3_000..4_000]) {
+            assert_eq!(got.open, expected.open);
+            assert_eq!(got.high, expected.high);
+            assert_eq!(got.low, expected.low);
+            assert_eq!(got.close, expected.close);
+            // Unselected columns are left at their defaults
+            assert_eq!(got.close_time, 0);
+            assert_eq!(got.volume, FixedPoint(0));
+        }
+
+        let unknown = ParquetExporter::read_filtered(&file_path, Some(&["bogus"]), None);
+        assert!(matches!(unknown, Err(ExportError::ConversionFailed { .. })));
+    }
+
+    #[test]
+    fn test_csv_export_hash_is_deterministic() {
+        let bars = create_many_range_bars(50);
+        let temp_dir = tempdir().unwrap();
+        let export = |bars: &[RangeBar], name: &str| {
+            let path = temp_dir.path().join(name);
+            let result = StreamingCsvExporter::new().export(bars, &path).unwrap();
+            assert_eq!(
+                result.sha256,
+                crate::integrity::compute_sha256_hash(&path).unwrap()
+            );
+            result.sha256
+        };
+
+        let first = export(&bars, "first.csv");
+        assert_eq!(first, export(&bars, "second.csv"));
+        assert_ne!(first, export(&bars[1..], "shorter.csv"));
+
+        let polars_path = temp_dir.path().join("polars.csv");
+        let polars = PolarsExporter::new()
+            .export_streaming_csv(&bars, &polars_path)
+            .unwrap();
+        assert_eq!(
+            polars.sha256,
+            crate::integrity::compute_sha256_hash(&polars_path).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_arrow_stream_batches() {
+        let template = create_test_range_bars();
+        let source = (0..2500).map(|i| {
+            let mut bar = template[i % template.len()].clone();
+            bar.open_time += i as i64 * 10;
+            bar.close_time += i as i64 * 10;
+            bar
+        });
+
+        let batches: Vec<RecordBatch> = ArrowExporter::new()
+            .stream_batches(source, 1000)
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        let heights: Vec<usize> = batches.iter().map(|batch| batch.height()).collect();
+        assert_eq!(heights, vec![1000, 1000, 500]);
+        for batch in &batches {
+            assert_eq!(batch.schema(), batches[0].schema());
+            assert_eq!(batch.arrays().len(), RANGEBAR_COLUMNS.len());
+        }
+
+        // Row order is preserved across batch boundaries
+        let column = RANGEBAR_COLUMNS
+            .iter()
+            .position(|&name| name == "open_time")
+            .unwrap();
+        let open_time = batches[2].arrays()[column]
+            .as_any()
+            .downcast_ref::<polars_arrow::array::PrimitiveArray<i64>>()
+            .unwrap();
+        assert_eq!(open_time.value(0), 1000000 + 2000 * 10);
+    }
 }
@@ -0,0 +1,37 @@
+//! Build check for the `no_std` subset of rangebar-core
+//!
+//! Compiles against `rangebar-core` with default features off, so any `std`
+//! dependency leaking into the `fixed_point` subset fails the build. Run
+//! `make no-std` to build it for a bare-metal target.
+
+#![cfg_attr(not(test), no_std)]
+
+use rangebar_core::{FixedPoint, is_breach};
+
+/// Index of the first price breaching the thresholds of `prices[0]`
+///
+/// Mirrors the processor's per-bar check: thresholds are fixed from the open
+/// and breaches are inclusive.
+pub fn first_breach(prices: &[FixedPoint], threshold_decimal_bps: u32) -> Option<usize> {
+    let (open, rest) = prices.split_first()?;
+    let (upper, lower) = open.compute_range_thresholds(threshold_decimal_bps);
+    rest.iter()
+        .position(|&price| is_breach(price, upper, lower))
+        .map(|i| i + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_breach() {
+        let prices = ["50000.0", "50100.0", "49900.0", "50125.0", "50200.0"]
+            .map(|p| FixedPoint::from_str(p).unwrap());
+
+        // 25bps of 50000 = 125, so the touch at 50125 closes the bar
+        assert_eq!(first_breach(&prices, 250), Some(3));
+        assert_eq!(first_breach(&prices, 1_000), None);
+        assert_eq!(first_breach(&[], 250), None);
+    }
+}
@@ -8,11 +8,19 @@ use chrono::NaiveDate;
 use csv::ReaderBuilder;
 use reqwest::Client;
 use serde::Deserialize;
+use std::fmt;
 use std::io::{Cursor, Read};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Duration;
+use thiserror::Error;
 use zip::ZipArchive;
 
-use rangebar_core::{AggTrade, FixedPoint, normalize_timestamp};
+use crate::rate_limit::RateLimiter;
+use rangebar_core::{
+    AggTrade, ConversionError, DataSource, FixedPoint, ProcessingError, RangeBarProcessor,
+    normalize_timestamp, sort_trades_stable,
+};
 
 #[derive(Debug, Deserialize)]
 pub struct CsvAggTrade(
@@ -54,45 +62,238 @@ pub fn detect_csv_headers(buffer: &str) -> bool {
     }
 }
 
-impl From<CsvAggTrade> for AggTrade {
-    fn from(csv_trade: CsvAggTrade) -> Self {
-        AggTrade {
+impl TryFrom<CsvAggTrade> for AggTrade {
+    type Error = ConversionError;
+
+    fn try_from(csv_trade: CsvAggTrade) -> Result<Self, Self::Error> {
+        Ok(AggTrade {
             agg_trade_id: csv_trade.0 as i64,
-            price: FixedPoint::from_str(&csv_trade.1.to_string()).unwrap_or(FixedPoint(0)),
-            volume: FixedPoint::from_str(&csv_trade.2.to_string()).unwrap_or(FixedPoint(0)),
+            price: csv_decimal("price", csv_trade.1)?,
+            volume: csv_decimal("quantity", csv_trade.2)?,
             first_trade_id: csv_trade.3 as i64,
             last_trade_id: csv_trade.4 as i64,
             timestamp: csv_trade.5 as i64,
             is_buyer_maker: csv_trade.6,
             is_best_match: None, // Not available in historical CSV data
-        }
+        })
     }
 }
 
 impl CsvAggTrade {
     /// Convert to AggTrade with market-aware timestamp conversion
-    pub fn to_agg_trade(&self, _market_type: &str) -> AggTrade {
+    ///
+    /// Fails with [`ConversionError::NonFinite`] for NaN or infinite columns and
+    /// [`ConversionError::InvalidNumber`] when the price or quantity cannot be
+    /// represented as a [`FixedPoint`]; use [`ConversionError::is_skippable`]
+    /// to decide whether to drop the row.
+    pub fn to_agg_trade(&self, _market_type: &str) -> Result<AggTrade, ConversionError> {
         // Universal timestamp normalization (market_type no longer needed)
         let normalized_timestamp = normalize_timestamp(self.5);
 
-        AggTrade {
+        Ok(AggTrade {
             agg_trade_id: self.0 as i64,
-            price: FixedPoint::from_str(&self.1.to_string()).unwrap_or(FixedPoint(0)),
-            volume: FixedPoint::from_str(&self.2.to_string()).unwrap_or(FixedPoint(0)),
+            price: csv_decimal("price", self.1)?,
+            volume: csv_decimal("quantity", self.2)?,
             first_trade_id: self.3 as i64,
             last_trade_id: self.4 as i64,
             timestamp: normalized_timestamp,
             is_buyer_maker: self.6,
             is_best_match: None, // Not available in historical CSV data
+        })
+    }
+}
+
+/// Parse an f64 CSV column into a [`FixedPoint`] via its shortest decimal form
+///
+/// f64 columns accept "nan"/"inf", which are rejected before parsing.
+fn csv_decimal(field: &'static str, value: f64) -> Result<FixedPoint, ConversionError> {
+    if !value.is_finite() {
+        return Err(ConversionError::NonFinite { field });
+    }
+    FixedPoint::from_str(&value.to_string())
+        .map_err(|source| ConversionError::InvalidNumber { field, source })
+}
+
+/// Handling of CSV rows that fail [`CsvAggTrade::to_agg_trade`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InvalidRowPolicy {
+    /// Fail the load with [`DataError::Conversion`] (default)
+    #[default]
+    Abort,
+
+    /// Drop rows with [skippable](ConversionError::is_skippable) errors and
+    /// count them (see [`HistoricalDataLoader::skipped_records`])
+    Skip,
+}
+
+/// Historical data loading errors
+#[derive(Error, Debug)]
+pub enum DataError {
+    #[error("Unknown market type: {0} (expected spot, um or cm)")]
+    UnknownMarket(String),
+
+    #[error("Symbol {symbol} is not valid for {market} market")]
+    InvalidSymbol { symbol: String, market: MarketType },
+
+    #[error("No data published at {url}")]
+    DataNotFound { url: String },
+
+    #[error("Checksum mismatch: expected {expected}, got {actual}")]
+    ChecksumMismatch { expected: String, actual: String },
+
+    #[error("Malformed checksum file at {url}")]
+    InvalidChecksum { url: String },
+
+    #[error("HTTP {status} for {url}")]
+    HttpStatus { status: u16, url: String },
+
+    #[error("Request timed out after {0:?}")]
+    Timeout(Duration),
+
+    #[error("Network error: {0}")]
+    Network(#[from] reqwest::Error),
+
+    #[error("aggTrade {agg_trade_id}: {source}")]
+    Conversion {
+        agg_trade_id: u64,
+        #[source]
+        source: ConversionError,
+    },
+}
+
+impl DataError {
+    /// Whether the failure is transient and worth retrying (network errors, timeouts, 5xx)
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            DataError::Network(_) | DataError::Timeout(_) => true,
+            DataError::HttpStatus { status, .. } => *status >= 500,
+            _ => false,
         }
     }
 }
 
+/// Binance market whose aggTrades archives are loaded
+///
+/// Each market lives under its own `data.binance.vision` path and uses its
+/// own symbol naming:
+/// - `Spot`: `BTCUSDT`
+/// - `UmFutures` (USDT/USDC-margined): `BTCUSDT`, or `BTCUSDT_250328` for delivery contracts
+/// - `CmFutures` (coin-margined): `BTCUSD_PERP`, or `BTCUSD_250328` for delivery contracts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MarketType {
+    #[default]
+    Spot,
+    UmFutures,
+    CmFutures,
+}
+
+impl MarketType {
+    /// Short market identifier (`spot`, `um`, `cm`)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MarketType::Spot => "spot",
+            MarketType::UmFutures => "um",
+            MarketType::CmFutures => "cm",
+        }
+    }
+
+    /// Data source to record on bars built from this market's trades
+    pub fn data_source(&self) -> DataSource {
+        match self {
+            MarketType::Spot => DataSource::BinanceSpot,
+            MarketType::UmFutures => DataSource::BinanceFuturesUM,
+            MarketType::CmFutures => DataSource::BinanceFuturesCM,
+        }
+    }
+
+    /// Path segment used by data.binance.vision for this market
+    pub fn archive_path(&self) -> &'static str {
+        match self {
+            MarketType::Spot => "spot",
+            MarketType::UmFutures => "futures/um",
+            MarketType::CmFutures => "futures/cm",
+        }
+    }
+
+    /// Coin-margined perpetual symbol for a base asset (e.g. `BTC` → `BTCUSD_PERP`)
+    pub fn cm_perpetual_symbol(base: &str) -> String {
+        format!("{}USD_PERP", base.to_uppercase())
+    }
+
+    /// Check that a symbol follows this market's naming convention
+    ///
+    /// This is a plausibility check only; it does not query Binance for listing status.
+    pub fn is_plausible_symbol(&self, symbol: &str) -> bool {
+        let symbol = symbol.to_uppercase();
+        if symbol.is_empty()
+            || !symbol
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_')
+        {
+            return false;
+        }
+
+        let (pair, contract) = match symbol.split_once('_') {
+            Some((pair, contract)) => (pair, Some(contract)),
+            None => (symbol.as_str(), None),
+        };
+        if pair.is_empty() {
+            return false;
+        }
+        let is_delivery = |c: &str| c.len() == 6 && c.chars().all(|ch| ch.is_ascii_digit());
+
+        match self {
+            MarketType::Spot => contract.is_none(),
+            MarketType::UmFutures => contract.is_none_or(is_delivery),
+            MarketType::CmFutures => {
+                pair.len() > 3
+                    && pair.ends_with("USD")
+                    && contract.is_some_and(|c| c == "PERP" || is_delivery(c))
+            }
+        }
+    }
+}
+
+impl fmt::Display for MarketType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for MarketType {
+    type Err = DataError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "spot" => Ok(MarketType::Spot),
+            "um" | "futures/um" => Ok(MarketType::UmFutures),
+            "cm" | "futures/cm" => Ok(MarketType::CmFutures),
+            _ => Err(DataError::UnknownMarket(s.to_string())),
+        }
+    }
+}
+
+const DEFAULT_BASE_URL: &str = "https://data.binance.vision/data";
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+/// Upper bound on the backoff exponent (base_delay * 2^10)
+const MAX_BACKOFF_SHIFT: u32 = 10;
+
 /// Historical data loader for Binance aggTrades
 pub struct HistoricalDataLoader {
     client: Client,
     symbol: String,
-    market_type: String,
+    market_type: MarketType,
+    base_url: String,
+    max_attempts: u32,
+    base_delay_ms: u64,
+    #[cfg_attr(not(feature = "data-integrity"), allow(dead_code))]
+    verify_checksums: bool,
+    tick_size: Option<FixedPoint>,
+    auto_sort: bool,
+    reordered_records: AtomicUsize,
+    invalid_rows: InvalidRowPolicy,
+    skipped_records: AtomicUsize,
+    rate_limiter: Option<RateLimiter>,
 }
 
 impl HistoricalDataLoader {
@@ -100,46 +301,282 @@ impl HistoricalDataLoader {
         Self::new_with_market(symbol, "spot")
     }
 
+    /// Create a loader from a market string (`spot`, `um`, `cm`)
+    ///
+    /// Unknown market strings fall back to spot. Use [`Self::with_market_type`]
+    /// for a validated symbol/market pair.
     pub fn new_with_market(symbol: &str, market_type: &str) -> Self {
+        Self::build(
+            symbol.to_uppercase(),
+            market_type.parse().unwrap_or_default(),
+        )
+    }
+
+    /// Create a loader for a typed market, rejecting symbols that don't fit its naming
+    pub fn with_market_type(symbol: &str, market_type: MarketType) -> Result<Self, DataError> {
+        let symbol = symbol.to_uppercase();
+        if !market_type.is_plausible_symbol(&symbol) {
+            return Err(DataError::InvalidSymbol {
+                symbol,
+                market: market_type,
+            });
+        }
+
+        Ok(Self::build(symbol, market_type))
+    }
+
+    fn build(symbol: String, market_type: MarketType) -> Self {
         Self {
             client: Client::new(),
-            symbol: symbol.to_uppercase(),
-            market_type: market_type.to_string(),
+            symbol,
+            market_type,
+            base_url: DEFAULT_BASE_URL.to_string(),
+            max_attempts: 1,
+            base_delay_ms: 0,
+            verify_checksums: true,
+            tick_size: None,
+            auto_sort: false,
+            reordered_records: AtomicUsize::new(0),
+            invalid_rows: InvalidRowPolicy::default(),
+            skipped_records: AtomicUsize::new(0),
+            rate_limiter: None,
+        }
+    }
+
+    /// Retry transient download failures with exponential backoff and jitter
+    ///
+    /// Network errors, timeouts and HTTP 5xx responses are retried up to
+    /// `max_attempts` total attempts, sleeping `base_delay_ms * 2^n` plus up to
+    /// `base_delay_ms` of jitter between attempts. HTTP 404 is never retried and
+    /// surfaces as [`DataError::DataNotFound`].
+    pub fn with_retry(mut self, max_attempts: u32, base_delay_ms: u64) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self.base_delay_ms = base_delay_ms;
+        self
+    }
+
+    /// Enable or disable SHA-256 verification against the sibling `.CHECKSUM` file
+    ///
+    /// On by default. Verification requires the `data-integrity` feature (enabled
+    /// by default); without it this flag has no effect.
+    pub fn with_checksum_verification(mut self, verify: bool) -> Self {
+        self.verify_checksums = verify;
+        self
+    }
+
+    /// Snap loaded trade prices to a price tick size
+    ///
+    /// Off by default. The per-symbol table in [`tick_size`](super::symbols::tick_size)
+    /// holds USDT-margined perpetual ticks, which are coarser than some spot and
+    /// coin-margined markets; only pass it for [`MarketType::UmFutures`] loaders.
+    /// `None` disables snapping.
+    pub fn with_tick_size(mut self, tick_size: Option<FixedPoint>) -> Self {
+        self.tick_size = tick_size;
+        self
+    }
+
+    /// Sort loaded trades by (timestamp, agg_trade_id) before returning them
+    ///
+    /// Off by default: trades are sorted by timestamp only, keeping archive order
+    /// within a timestamp, and id inversions there surface as
+    /// [`ProcessingError::UnsortedTrades`](rangebar_core::ProcessingError) during
+    /// processing. Silent reordering can hide upstream data issues, so check
+    /// [`Self::reordered_records`] after loading when this is enabled.
+    pub fn with_auto_sort(mut self, auto_sort: bool) -> Self {
+        self.auto_sort = auto_sort;
+        self
+    }
+
+    /// Records moved by the auto-sort across all loads so far
+    pub fn reordered_records(&self) -> usize {
+        self.reordered_records.load(Ordering::Relaxed)
+    }
+
+    /// Sort by timestamp, or apply [`sort_trades_stable`] when auto-sort is enabled
+    fn sort_trades(&self, trades: &mut [AggTrade]) {
+        if self.auto_sort {
+            let moved = sort_trades_stable(trades);
+            self.reordered_records.fetch_add(moved, Ordering::Relaxed);
+        } else {
+            trades.sort_by_key(|trade| trade.timestamp);
+        }
+    }
+
+    /// Choose whether rows that fail conversion abort the load or are dropped
+    ///
+    /// Only [skippable](ConversionError::is_skippable) errors are dropped under
+    /// [`InvalidRowPolicy::Skip`]; scale errors always abort. Check
+    /// [`Self::skipped_records`] after loading.
+    pub fn with_invalid_row_policy(mut self, policy: InvalidRowPolicy) -> Self {
+        self.invalid_rows = policy;
+        self
+    }
+
+    /// Rows dropped under [`InvalidRowPolicy::Skip`] across all loads so far
+    pub fn skipped_records(&self) -> usize {
+        self.skipped_records.load(Ordering::Relaxed)
+    }
+
+    /// Wait for a permit from `rate_limiter` before every download attempt
+    ///
+    /// Pass a clone of one limiter to several loaders to share its budget.
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Range bar processor that records this loader's market on every bar
+    ///
+    /// Stamps [`MarketType::data_source`], so bars from several markets can be
+    /// partitioned by origin downstream.
+    pub fn processor(
+        &self,
+        threshold_decimal_bps: u32,
+    ) -> Result<RangeBarProcessor, ProcessingError> {
+        Ok(RangeBarProcessor::new(threshold_decimal_bps)?
+            .with_data_source(self.market_type.data_source()))
+    }
+
+    /// Override the archive host (mirrors, local caches)
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Symbol this loader fetches
+    pub fn symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    /// Market this loader fetches from
+    pub fn market_type(&self) -> MarketType {
+        self.market_type
+    }
+
+    /// Daily aggTrades archive URL for a date
+    pub fn daily_archive_url(&self, date: NaiveDate) -> String {
+        format!(
+            "{}/{}/daily/aggTrades/{}/{}-aggTrades-{}.zip",
+            self.base_url,
+            self.market_type.archive_path(),
+            self.symbol,
+            self.symbol,
+            date.format("%Y-%m-%d")
+        )
+    }
+
+    /// Download a URL once, mapping the response status to [`DataError`]
+    async fn fetch_once(&self, url: &str) -> Result<Vec<u8>, DataError> {
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.acquire().await;
+        }
+        #[cfg(feature = "tracing")]
+        let started = std::time::Instant::now();
+
+        let response = tokio::time::timeout(REQUEST_TIMEOUT, self.client.get(url).send())
+            .await
+            .map_err(|_| DataError::Timeout(REQUEST_TIMEOUT))??;
+
+        let status = response.status();
+        if status == reqwest::StatusCode::NOT_FOUND {
+            return Err(DataError::DataNotFound {
+                url: url.to_string(),
+            });
         }
+        if !status.is_success() {
+            return Err(DataError::HttpStatus {
+                status: status.as_u16(),
+                url: url.to_string(),
+            });
+        }
+
+        let bytes = response.bytes().await?.to_vec();
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            url,
+            bytes = bytes.len(),
+            elapsed_ms = started.elapsed().as_millis() as u64,
+            "downloaded archive"
+        );
+        Ok(bytes)
     }
 
-    /// Get market path for URL construction
-    fn get_market_path(&self) -> &str {
-        match self.market_type.as_str() {
-            "um" => "futures/um",
-            "cm" => "futures/cm",
-            "spot" => "spot",
-            _ => "spot", // Default to spot
+    /// Download a URL, retrying transient failures per [`Self::with_retry`]
+    async fn fetch_with_retry(&self, url: &str) -> Result<Vec<u8>, DataError> {
+        let mut attempt = 1;
+        loop {
+            match self.fetch_once(url).await {
+                Ok(bytes) => return Ok(bytes),
+                Err(e) if e.is_retryable() && attempt < self.max_attempts => {
+                    tokio::time::sleep(self.backoff_delay(attempt)).await;
+                    attempt += 1;
+                }
+                Err(e) => return Err(e),
+            }
         }
     }
 
+    /// Exponential backoff with jitter for the given (1-based) failed attempt
+    fn backoff_delay(&self, attempt: u32) -> Duration {
+        let shift = (attempt - 1).min(MAX_BACKOFF_SHIFT);
+        let backoff = self.base_delay_ms.saturating_mul(1 << shift);
+        let jitter = if self.base_delay_ms > 0 {
+            let nanos = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.subsec_nanos() as u64)
+                .unwrap_or(0);
+            nanos % self.base_delay_ms
+        } else {
+            0
+        };
+        Duration::from_millis(backoff.saturating_add(jitter))
+    }
+
+    /// Verify archive bytes against the SHA-256 published at `{url}.CHECKSUM`
+    #[cfg(feature = "data-integrity")]
+    async fn verify_archive_checksum(&self, url: &str, zip_bytes: &[u8]) -> Result<(), DataError> {
+        use sha2::{Digest, Sha256};
+
+        let checksum_url = format!("{}.CHECKSUM", url);
+        let body = self.fetch_with_retry(&checksum_url).await?;
+
+        // Format: "<sha256 hex>  <file name>"
+        let expected = String::from_utf8_lossy(&body)
+            .split_whitespace()
+            .next()
+            .filter(|hash| hash.len() == 64 && hash.chars().all(|c| c.is_ascii_hexdigit()))
+            .ok_or(DataError::InvalidChecksum { url: checksum_url })?
+            .to_lowercase();
+        let actual = format!("{:x}", Sha256::digest(zip_bytes));
+
+        if expected != actual {
+            return Err(DataError::ChecksumMismatch { expected, actual });
+        }
+        Ok(())
+    }
+
     /// Load single day trades
+    ///
+    /// Download failures are returned as [`DataError`]; a missing date can be
+    /// detected with `err.downcast_ref::<DataError>()` matching
+    /// [`DataError::DataNotFound`] and skipped.
     pub async fn load_single_day_trades(
         &self,
         date: NaiveDate,
     ) -> Result<Vec<AggTrade>, Box<dyn std::error::Error>> {
         let date_str = date.format("%Y-%m-%d");
-        let url = format!(
-            "https://data.binance.vision/data/{}/daily/aggTrades/{}/{}-aggTrades-{}.zip",
-            self.get_market_path(),
-            self.symbol,
-            self.symbol,
-            date_str
-        );
+        let url = self.daily_archive_url(date);
 
-        let response =
-            tokio::time::timeout(Duration::from_secs(30), self.client.get(&url).send()).await??;
+        let zip_bytes = self.fetch_with_retry(&url).await?;
 
-        if !response.status().is_success() {
-            return Err(format!("HTTP {} for {}", response.status(), date_str).into());
+        #[cfg(feature = "data-integrity")]
+        if self.verify_checksums {
+            self.verify_archive_checksum(&url, &zip_bytes).await?;
         }
 
-        let zip_bytes = response.bytes().await?;
+        #[cfg(feature = "tracing")]
+        let parse_started = std::time::Instant::now();
+
         let cursor = Cursor::new(zip_bytes);
         let mut archive = ZipArchive::new(cursor)?;
 
@@ -156,11 +593,35 @@ impl HistoricalDataLoader {
         let mut day_trades = Vec::with_capacity(2_000_000);
         for result in reader.deserialize() {
             let csv_trade: CsvAggTrade = result?;
-            let agg_trade: AggTrade = csv_trade.to_agg_trade(&self.market_type);
+            let mut agg_trade = match csv_trade.to_agg_trade(self.market_type.as_str()) {
+                Ok(agg_trade) => agg_trade,
+                Err(err) if self.invalid_rows == InvalidRowPolicy::Skip && err.is_skippable() => {
+                    self.skipped_records.fetch_add(1, Ordering::Relaxed);
+                    continue;
+                }
+                Err(source) => {
+                    return Err(DataError::Conversion {
+                        agg_trade_id: csv_trade.0,
+                        source,
+                    }
+                    .into());
+                }
+            };
+            if let Some(tick) = self.tick_size {
+                agg_trade.price = agg_trade.price.snap_to_tick(tick);
+            }
             day_trades.push(agg_trade);
         }
 
-        day_trades.sort_by_key(|trade| trade.timestamp);
+        self.sort_trades(&mut day_trades);
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            symbol = %self.symbol,
+            %date,
+            records = day_trades.len(),
+            elapsed_ms = parse_started.elapsed().as_millis() as u64,
+            "parsed daily aggTrades"
+        );
         Ok(day_trades)
     }
 
@@ -190,7 +651,7 @@ impl HistoricalDataLoader {
             current_date += chrono::Duration::days(1);
         }
 
-        all_trades.sort_by_key(|trade| trade.timestamp);
+        self.sort_trades(&mut all_trades);
         Ok(all_trades)
     }
 
@@ -210,3 +671,406 @@ impl HistoricalDataLoader {
         Err("No recent data available in the last 7 days".into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 1, 15).unwrap()
+    }
+
+    #[test]
+    fn test_spot_archive_url() {
+        let loader = HistoricalDataLoader::with_market_type("btcusdt", MarketType::Spot).unwrap();
+        assert_eq!(
+            loader.daily_archive_url(date()),
+            "https://data.binance.vision/data/spot/daily/aggTrades/BTCUSDT/BTCUSDT-aggTrades-2024-01-15.zip"
+        );
+    }
+
+    #[test]
+    fn test_um_futures_archive_url() {
+        let loader =
+            HistoricalDataLoader::with_market_type("BTCUSDT", MarketType::UmFutures).unwrap();
+        assert_eq!(
+            loader.daily_archive_url(date()),
+            "https://data.binance.vision/data/futures/um/daily/aggTrades/BTCUSDT/BTCUSDT-aggTrades-2024-01-15.zip"
+        );
+    }
+
+    #[test]
+    fn test_cm_futures_archive_url() {
+        let symbol = MarketType::cm_perpetual_symbol("btc");
+        assert_eq!(symbol, "BTCUSD_PERP");
+
+        let loader =
+            HistoricalDataLoader::with_market_type(&symbol, MarketType::CmFutures).unwrap();
+        assert_eq!(
+            loader.daily_archive_url(date()),
+            "https://data.binance.vision/data/futures/cm/daily/aggTrades/BTCUSD_PERP/BTCUSD_PERP-aggTrades-2024-01-15.zip"
+        );
+    }
+
+    #[test]
+    fn test_new_with_market_string_compat() {
+        let loader = HistoricalDataLoader::new_with_market("BTCUSD_PERP", "cm");
+        assert_eq!(loader.market_type(), MarketType::CmFutures);
+        assert!(loader.daily_archive_url(date()).contains("/futures/cm/"));
+
+        let loader = HistoricalDataLoader::new_with_market("BTCUSDT", "bogus");
+        assert_eq!(loader.market_type(), MarketType::Spot);
+    }
+
+    #[test]
+    fn test_rejects_wrong_symbol_for_market() {
+        let err = HistoricalDataLoader::with_market_type("BTCUSDT", MarketType::CmFutures);
+        assert!(matches!(
+            err,
+            Err(DataError::InvalidSymbol {
+                market: MarketType::CmFutures,
+                ..
+            })
+        ));
+
+        assert!(HistoricalDataLoader::with_market_type("BTCUSD_PERP", MarketType::Spot).is_err());
+        assert!(
+            HistoricalDataLoader::with_market_type("BTCUSD_PERP", MarketType::UmFutures).is_err()
+        );
+        assert!(HistoricalDataLoader::with_market_type("BTC-USDT", MarketType::Spot).is_err());
+    }
+
+    #[test]
+    fn test_market_type_parsing() {
+        assert_eq!("spot".parse::<MarketType>().unwrap(), MarketType::Spot);
+        assert_eq!("UM".parse::<MarketType>().unwrap(), MarketType::UmFutures);
+        assert_eq!("cm".parse::<MarketType>().unwrap(), MarketType::CmFutures);
+        assert!("options".parse::<MarketType>().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_processor_stamps_market_data_source() {
+        let csv = "1,50000.0,1.0,1,1,1705276800000,true\n\
+                   2,50200.0,1.0,2,2,1705276800100,false\n";
+        let archive = zip_fixture("BTCUSDT", "2024-01-15", csv);
+        let (base_url, _) = spawn_mock_server(vec![(200, archive)]).await;
+
+        let loader = HistoricalDataLoader::with_market_type("BTCUSDT", MarketType::UmFutures)
+            .unwrap()
+            .with_base_url(&base_url)
+            .with_checksum_verification(false);
+        let trades = loader.load_single_day_trades(date()).await.unwrap();
+        let bars = loader
+            .processor(250)
+            .unwrap()
+            .process_agg_trade_records(&trades)
+            .unwrap();
+
+        assert_eq!(bars.len(), 1);
+        assert_eq!(bars[0].data_source, DataSource::BinanceFuturesUM);
+    }
+
+    #[test]
+    fn test_market_type_data_source() {
+        assert_eq!(MarketType::Spot.data_source(), DataSource::BinanceSpot);
+        assert_eq!(
+            MarketType::UmFutures.data_source(),
+            DataSource::BinanceFuturesUM
+        );
+        assert_eq!(
+            MarketType::CmFutures.data_source(),
+            DataSource::BinanceFuturesCM
+        );
+    }
+
+    /// Serve canned HTTP responses in order, one per connection, counting requests
+    async fn spawn_mock_server(
+        responses: Vec<(u16, Vec<u8>)>,
+    ) -> (String, std::sync::Arc<std::sync::atomic::AtomicUsize>) {
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let hits = Arc::new(AtomicUsize::new(0));
+        let counter = hits.clone();
+
+        tokio::spawn(async move {
+            for (status, body) in responses {
+                let Ok((mut socket, _)) = listener.accept().await else {
+                    return;
+                };
+                counter.fetch_add(1, Ordering::SeqCst);
+                let mut request = [0u8; 4096];
+                let _ = socket.read(&mut request).await;
+                let head = format!(
+                    "HTTP/1.1 {} Mock\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                    status,
+                    body.len()
+                );
+                let _ = socket.write_all(head.as_bytes()).await;
+                let _ = socket.write_all(&body).await;
+                let _ = socket.shutdown().await;
+            }
+        });
+
+        (format!("http://{}", addr), hits)
+    }
+
+    fn zip_fixture(symbol: &str, date_str: &str, csv: &str) -> Vec<u8> {
+        use std::io::Write;
+        use zip::write::SimpleFileOptions;
+
+        let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        writer
+            .start_file(
+                format!("{}-aggTrades-{}.csv", symbol, date_str),
+                SimpleFileOptions::default(),
+            )
+            .unwrap();
+        writer.write_all(csv.as_bytes()).unwrap();
+        writer.finish().unwrap().into_inner()
+    }
+
+    #[tokio::test]
+    async fn test_retry_recovers_after_transient_failures() {
+        let csv =
+            "1,50000.0,1.5,10,12,1705276800000,true\n2,50001.0,0.5,13,13,1705276800100,false\n";
+        let archive = zip_fixture("BTCUSDT", "2024-01-15", csv);
+        let (base_url, hits) =
+            spawn_mock_server(vec![(503, Vec::new()), (502, Vec::new()), (200, archive)]).await;
+
+        let loader = HistoricalDataLoader::new("BTCUSDT")
+            .with_base_url(&base_url)
+            .with_retry(3, 1)
+            .with_checksum_verification(false);
+        let trades = loader.load_single_day_trades(date()).await.unwrap();
+
+        assert_eq!(hits.load(std::sync::atomic::Ordering::SeqCst), 3);
+        assert_eq!(trades.len(), 2);
+        assert_eq!(trades[0].agg_trade_id, 1);
+        assert_eq!(trades[0].timestamp, 1705276800000000);
+    }
+
+    #[tokio::test]
+    async fn test_auto_sort_reports_reordered_records() {
+        let csv = "1,50000.0,1.0,1,1,1705276800000,true\n\
+                   3,50002.0,1.0,3,3,1705276800100,false\n\
+                   2,50001.0,1.0,2,2,1705276800100,true\n\
+                   5,50004.0,1.0,5,5,1705276800300,false\n\
+                   4,50003.0,1.0,4,4,1705276800200,false\n";
+        let archive = zip_fixture("BTCUSDT", "2024-01-15", csv);
+        let (base_url, _) = spawn_mock_server(vec![(200, archive.clone()), (200, archive)]).await;
+
+        let ids = |trades: &[AggTrade]| trades.iter().map(|t| t.agg_trade_id).collect::<Vec<_>>();
+
+        let loader = HistoricalDataLoader::new("BTCUSDT")
+            .with_base_url(&base_url)
+            .with_checksum_verification(false);
+        let trades = loader.load_single_day_trades(date()).await.unwrap();
+        // Timestamp order is always restored; the id tie at 100ms is left as-is
+        assert_eq!(ids(&trades), vec![1, 3, 2, 4, 5]);
+        assert_eq!(loader.reordered_records(), 0);
+
+        let loader = loader.with_auto_sort(true);
+        let trades = loader.load_single_day_trades(date()).await.unwrap();
+        assert_eq!(ids(&trades), vec![1, 2, 3, 4, 5]);
+        assert_eq!(loader.reordered_records(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_tick_snapping_is_opt_in() {
+        let csv = "1,50000.05,1.0,1,1,1705276800000,true\n";
+        let archive = zip_fixture("BTCUSDT", "2024-01-15", csv);
+        let (base_url, _) = spawn_mock_server(vec![(200, archive.clone()), (200, archive)]).await;
+
+        let loader = HistoricalDataLoader::new("BTCUSDT")
+            .with_base_url(&base_url)
+            .with_checksum_verification(false);
+        let trades = loader.load_single_day_trades(date()).await.unwrap();
+        assert_eq!(trades[0].price.to_string(), "50000.05000000");
+
+        let loader = loader.with_tick_size(crate::binance::symbols::tick_size("BTCUSDT"));
+        let trades = loader.load_single_day_trades(date()).await.unwrap();
+        assert_eq!(trades[0].price.to_string(), "50000.10000000");
+    }
+
+    #[tokio::test]
+    async fn test_retry_surfaces_final_error_when_exhausted() {
+        let (base_url, hits) = spawn_mock_server(vec![(500, Vec::new()), (500, Vec::new())]).await;
+
+        let loader = HistoricalDataLoader::new("BTCUSDT")
+            .with_base_url(&base_url)
+            .with_retry(2, 1)
+            .with_checksum_verification(false);
+        let err = loader.load_single_day_trades(date()).await.unwrap_err();
+
+        assert_eq!(hits.load(std::sync::atomic::Ordering::SeqCst), 2);
+        assert!(matches!(
+            err.downcast_ref::<DataError>(),
+            Some(DataError::HttpStatus { status: 500, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_not_found_is_not_retried() {
+        let (base_url, hits) = spawn_mock_server(vec![(404, Vec::new())]).await;
+
+        let loader = HistoricalDataLoader::new("BTCUSDT")
+            .with_base_url(&base_url)
+            .with_retry(5, 1)
+            .with_checksum_verification(false);
+        let err = loader.load_single_day_trades(date()).await.unwrap_err();
+
+        assert_eq!(hits.load(std::sync::atomic::Ordering::SeqCst), 1);
+        assert!(matches!(
+            err.downcast_ref::<DataError>(),
+            Some(DataError::DataNotFound { .. })
+        ));
+    }
+
+    #[cfg(feature = "data-integrity")]
+    fn checksum_fixture(archive: &[u8], file_name: &str) -> Vec<u8> {
+        use sha2::{Digest, Sha256};
+        format!("{:x}  {}\n", Sha256::digest(archive), file_name).into_bytes()
+    }
+
+    #[cfg(feature = "data-integrity")]
+    #[tokio::test]
+    async fn test_checksum_verification_accepts_intact_archive() {
+        let archive = zip_fixture(
+            "BTCUSDT",
+            "2024-01-15",
+            "1,50000.0,1.5,10,12,1705276800000,true\n",
+        );
+        let checksum = checksum_fixture(&archive, "BTCUSDT-aggTrades-2024-01-15.zip");
+        let (base_url, _) = spawn_mock_server(vec![(200, archive), (200, checksum)]).await;
+
+        let loader = HistoricalDataLoader::new("BTCUSDT").with_base_url(&base_url);
+        let trades = loader.load_single_day_trades(date()).await.unwrap();
+        assert_eq!(trades.len(), 1);
+    }
+
+    #[cfg(feature = "data-integrity")]
+    #[tokio::test]
+    async fn test_checksum_verification_rejects_tampered_archive() {
+        let archive = zip_fixture(
+            "BTCUSDT",
+            "2024-01-15",
+            "1,50000.0,1.5,10,12,1705276800000,true\n",
+        );
+        let checksum = checksum_fixture(&archive, "BTCUSDT-aggTrades-2024-01-15.zip");
+
+        // Flip a byte inside the stored CSV payload
+        let mut tampered = archive.clone();
+        let offset = tampered.len() / 2;
+        tampered[offset] ^= 0xFF;
+
+        let (base_url, _) = spawn_mock_server(vec![(200, tampered), (200, checksum)]).await;
+
+        let loader = HistoricalDataLoader::new("BTCUSDT").with_base_url(&base_url);
+        let err = loader.load_single_day_trades(date()).await.unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<DataError>(),
+            Some(DataError::ChecksumMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_csv_conversion_classifies_invalid_numbers() {
+        let row = |price: f64, qty: f64| CsvAggTrade(1, price, qty, 1, 1, 1705276800000, true);
+
+        let trade = AggTrade::try_from(row(50000.5, 1.25)).unwrap();
+        assert_eq!(trade.price.to_string(), "50000.50000000");
+        assert_eq!(trade.volume.to_string(), "1.25000000");
+
+        // Sub-satoshi precision means the whole file is on the wrong scale
+        let err = row(50000.0, 1e-9).to_agg_trade("spot").unwrap_err();
+        assert!(matches!(
+            err,
+            ConversionError::InvalidNumber {
+                field: "quantity",
+                ..
+            }
+        ));
+        assert!(!err.is_skippable());
+
+        let err = AggTrade::try_from(row(1e12, 1.0)).unwrap_err();
+        assert!(matches!(
+            err,
+            ConversionError::InvalidNumber { field: "price", .. }
+        ));
+        assert!(!err.is_skippable());
+    }
+
+    #[test]
+    fn test_csv_conversion_rejects_non_finite() {
+        let row = |price: f64, qty: f64| CsvAggTrade(1, price, qty, 1, 1, 1705276800000, true);
+
+        let err = AggTrade::try_from(row(f64::NAN, 1.0)).unwrap_err();
+        assert_eq!(err, ConversionError::NonFinite { field: "price" });
+        assert!(err.is_skippable());
+
+        let err = row(50000.0, f64::INFINITY)
+            .to_agg_trade("spot")
+            .unwrap_err();
+        assert_eq!(err, ConversionError::NonFinite { field: "quantity" });
+        assert!(err.is_skippable());
+    }
+
+    #[tokio::test]
+    async fn test_invalid_row_policy_skips_or_aborts() {
+        let csv = "1,50000.0,1.0,1,1,1705276800000,true\n\
+                   2,NaN,1.0,2,2,1705276800100,false\n\
+                   3,50001.0,inf,3,3,1705276800200,true\n\
+                   4,50002.0,1.0,4,4,1705276800300,false\n";
+        let archive = zip_fixture("BTCUSDT", "2024-01-15", csv);
+        let (base_url, _) = spawn_mock_server(vec![(200, archive.clone()), (200, archive)]).await;
+
+        let loader = HistoricalDataLoader::new("BTCUSDT")
+            .with_base_url(&base_url)
+            .with_checksum_verification(false);
+        let err = loader.load_single_day_trades(date()).await.unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<DataError>(),
+            Some(DataError::Conversion {
+                agg_trade_id: 2,
+                source: ConversionError::NonFinite { field: "price" },
+            })
+        ));
+
+        let loader = loader.with_invalid_row_policy(InvalidRowPolicy::Skip);
+        let trades = loader.load_single_day_trades(date()).await.unwrap();
+        let ids: Vec<i64> = trades.iter().map(|t| t.agg_trade_id).collect();
+        assert_eq!(ids, vec![1, 4]);
+        assert_eq!(loader.skipped_records(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_loader_propagates_conversion_errors() {
+        let csv = "1,50000.0,1.0,1,1,1705276800000,true\n\
+                   2,50000.0,0.000000001,2,2,1705276800100,false\n";
+        let archive = zip_fixture("BTCUSDT", "2024-01-15", csv);
+        let (base_url, _) = spawn_mock_server(vec![(200, archive)]).await;
+
+        let loader = HistoricalDataLoader::new("BTCUSDT")
+            .with_base_url(&base_url)
+            .with_checksum_verification(false);
+        let err = loader.load_single_day_trades(date()).await.unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<DataError>(),
+            Some(DataError::Conversion {
+                agg_trade_id: 2,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_delivery_contract_symbols() {
+        assert!(MarketType::CmFutures.is_plausible_symbol("BTCUSD_250328"));
+        assert!(MarketType::UmFutures.is_plausible_symbol("BTCUSDT_250328"));
+        assert!(!MarketType::UmFutures.is_plausible_symbol("BTCUSDT_MAR"));
+    }
+}
@@ -27,6 +27,16 @@ pub mod symbols;
 pub mod websocket;
 
 // Re-export commonly used types
-pub use historical::{CsvAggTrade, HistoricalDataLoader, detect_csv_headers, python_bool};
-pub use symbols::{TIER1_SYMBOLS, get_tier1_symbols, get_tier1_usdt_pairs, is_tier1_symbol};
-pub use websocket::{BinanceWebSocketStream, WebSocketError};
+pub use historical::{
+    CsvAggTrade, DataError, HistoricalDataLoader, InvalidRowPolicy, MarketType, detect_csv_headers,
+    python_bool,
+};
+pub use symbols::{
+    CM_EXCHANGE_INFO_URL, SymbolError, TIER1_SYMBOLS, UM_EXCHANGE_INFO_URL, get_tier1_symbols,
+    get_tier1_usdt_pairs, is_tier1_base, is_tier1_symbol, refresh_tier1_symbols,
+    refresh_tier1_symbols_or_fallback, tick_size,
+};
+pub use websocket::{
+    BackfillFn, BinanceCombinedStream, BinanceWebSocketStream, ReconnectPolicy, StreamMetrics,
+    WebSocketError,
+};
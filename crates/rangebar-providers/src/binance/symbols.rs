@@ -89,14 +89,42 @@
 //! - **Real-time Analysis**: Up-to-date symbol availability checking
 //! - **Efficient Processing**: Pure Rust implementation with minimal dependencies
 
+use rangebar_core::FixedPoint;
+use reqwest::Client;
+use serde::Deserialize;
+use std::collections::BTreeSet;
+use thiserror::Error;
+
 /// Tier-1 symbol list (as of the latest analysis)
 pub const TIER1_SYMBOLS: &[&str] = &[
     "AAVE", "ADA", "AVAX", "BCH", "BNB", "BTC", "DOGE", "ETH", "FIL", "LINK", "LTC", "NEAR", "SOL",
     "SUI", "UNI", "WIF", "WLD", "XRP",
 ];
 
+/// Quote suffixes recognised when matching trading pairs, longest first
+const QUOTE_SUFFIXES: &[&str] = &["USD_PERP", "USDT", "USDC", "USD"];
+
+/// Check if a base asset is Tier-1, regardless of the quote it trades against
+///
+/// # Examples
+///
+/// ```
+/// use rangebar_providers::binance::symbols::is_tier1_base;
+///
+/// assert!(is_tier1_base("BTC"));
+/// assert!(is_tier1_base("eth"));
+/// assert!(!is_tier1_base("BTCUSDT"));
+/// ```
+pub fn is_tier1_base(base: &str) -> bool {
+    TIER1_SYMBOLS.contains(&base.to_uppercase().as_str())
+}
+
 /// Check if a symbol is a Tier-1 instrument
 ///
+/// Accepts a base asset (`BTC`) or a pair quoted in USDT, USDC or USD, including
+/// coin-margined perpetuals (`BTCUSDT`, `btcusdc`, `BTCUSD`, `BTCUSD_PERP`).
+/// Matching is case-insensitive.
+///
 /// # Examples
 ///
 /// ```
@@ -104,10 +132,78 @@ pub const TIER1_SYMBOLS: &[&str] = &[
 ///
 /// assert!(is_tier1_symbol("BTC"));
 /// assert!(is_tier1_symbol("ETH"));
+/// assert!(is_tier1_symbol("btcusdt"));
+/// assert!(is_tier1_symbol("BTCUSD_PERP"));
 /// assert!(!is_tier1_symbol("SHIB"));
 /// ```
 pub fn is_tier1_symbol(symbol: &str) -> bool {
-    TIER1_SYMBOLS.contains(&symbol.to_uppercase().as_str())
+    let symbol = symbol.to_uppercase();
+    if is_tier1_base(&symbol) {
+        return true;
+    }
+
+    QUOTE_SUFFIXES
+        .iter()
+        .filter_map(|quote| symbol.strip_suffix(quote))
+        .any(is_tier1_base)
+}
+
+/// Price tick size per base asset, in scaled [`FixedPoint`] units
+///
+/// Snapshot of the USDT-margined perpetual `PRICE_FILTER.tickSize`. Exchanges
+/// revise tick sizes occasionally; pass an explicit tick to
+/// `HistoricalDataLoader::with_tick_size` when this table is stale.
+const TICK_SIZES: &[(&str, i64)] = &[
+    ("AAVE", 1_000_000), // 0.01
+    ("ADA", 10),         // 0.0000001
+    ("AVAX", 100_000),   // 0.001
+    ("BCH", 1_000_000),  // 0.01
+    ("BNB", 1_000_000),  // 0.01
+    ("BTC", 10_000_000), // 0.1
+    ("DOGE", 100),       // 0.000001
+    ("ETH", 1_000_000),  // 0.01
+    ("FIL", 100_000),    // 0.001
+    ("LINK", 100_000),   // 0.001
+    ("LTC", 1_000_000),  // 0.01
+    ("NEAR", 100_000),   // 0.001
+    ("SOL", 1_000_000),  // 0.01
+    ("SUI", 100),        // 0.000001
+    ("UNI", 100_000),    // 0.001
+    ("WIF", 100),        // 0.000001
+    ("WLD", 100),        // 0.000001
+    ("XRP", 10_000),     // 0.0001
+];
+
+/// Look up the price tick size for a symbol
+///
+/// Accepts the same base or quoted forms as [`is_tier1_symbol`]. Returns `None`
+/// for symbols outside the table, in which case prices should be left as-is.
+/// Ticks are for USDT-margined perpetuals; spot and coin-margined markets may
+/// use finer ticks.
+///
+/// # Examples
+///
+/// ```
+/// use rangebar_providers::binance::symbols::tick_size;
+///
+/// assert_eq!(tick_size("ETHUSDT").unwrap().to_string(), "0.01000000");
+/// assert!(tick_size("SHIBUSDT").is_none());
+/// ```
+pub fn tick_size(symbol: &str) -> Option<FixedPoint> {
+    let symbol = symbol.to_uppercase();
+    let lookup = |base: &str| {
+        TICK_SIZES
+            .iter()
+            .find(|(asset, _)| *asset == base)
+            .map(|&(_, tick)| FixedPoint(tick))
+    };
+
+    lookup(&symbol).or_else(|| {
+        QUOTE_SUFFIXES
+            .iter()
+            .filter_map(|quote| symbol.strip_suffix(quote))
+            .find_map(lookup)
+    })
 }
 
 /// Get all Tier-1 symbols as a vector
@@ -127,6 +223,9 @@ pub fn get_tier1_symbols() -> Vec<String> {
 
 /// Get Tier-1 USDT perpetual pairs
 ///
+/// Quote-specific: only `{BASE}USDT` pairs are returned. Use [`is_tier1_base`] or
+/// [`is_tier1_symbol`] to check pairs in other quotes.
+///
 /// # Examples
 ///
 /// ```
@@ -140,6 +239,116 @@ pub fn get_tier1_usdt_pairs() -> Vec<String> {
     TIER1_SYMBOLS.iter().map(|s| format!("{}USDT", s)).collect()
 }
 
+/// UM futures (USDT- and USDC-margined) exchangeInfo endpoint
+pub const UM_EXCHANGE_INFO_URL: &str = "https://fapi.binance.com/fapi/v1/exchangeInfo";
+
+/// CM futures (coin-margined) exchangeInfo endpoint
+pub const CM_EXCHANGE_INFO_URL: &str = "https://dapi.binance.com/dapi/v1/exchangeInfo";
+
+/// Symbol discovery errors
+#[derive(Error, Debug)]
+pub enum SymbolError {
+    #[error("Request failed: {0}")]
+    Request(#[from] reqwest::Error),
+
+    #[error("HTTP {status} from {url}")]
+    HttpStatus { status: u16, url: String },
+
+    #[error("Failed to parse {market} exchangeInfo: {source}")]
+    Parse {
+        market: &'static str,
+        #[source]
+        source: serde_json::Error,
+    },
+}
+
+/// Subset of a futures `exchangeInfo` response used for Tier-1 discovery
+#[derive(Debug, Deserialize)]
+struct ExchangeInfo {
+    symbols: Vec<ContractInfo>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ContractInfo {
+    contract_type: Option<String>,
+    /// UM futures trading status
+    status: Option<String>,
+    /// CM futures trading status
+    contract_status: Option<String>,
+    base_asset: String,
+    quote_asset: String,
+}
+
+impl ContractInfo {
+    fn is_trading_perpetual(&self) -> bool {
+        let status = self.status.as_deref().or(self.contract_status.as_deref());
+        self.contract_type.as_deref() == Some("PERPETUAL") && status == Some("TRADING")
+    }
+}
+
+/// Base assets with an actively-trading perpetual for the given quote asset
+fn trading_perpetual_bases(info: &ExchangeInfo, quote: &str) -> BTreeSet<String> {
+    info.symbols
+        .iter()
+        .filter(|c| c.is_trading_perpetual() && c.quote_asset == quote)
+        .map(|c| c.base_asset.to_uppercase())
+        .collect()
+}
+
+/// Intersect perpetuals across USDT-margined, USDC-margined and coin-margined markets
+fn tier1_intersection(um: &ExchangeInfo, cm: &ExchangeInfo) -> Vec<String> {
+    let usdt = trading_perpetual_bases(um, "USDT");
+    let usdc = trading_perpetual_bases(um, "USDC");
+    let coin = trading_perpetual_bases(cm, "USD");
+
+    usdt.into_iter()
+        .filter(|base| usdc.contains(base) && coin.contains(base))
+        .collect()
+}
+
+async fn fetch_exchange_info(
+    client: &Client,
+    url: &str,
+    market: &'static str,
+) -> Result<ExchangeInfo, SymbolError> {
+    let response = client.get(url).send().await?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(SymbolError::HttpStatus {
+            status: status.as_u16(),
+            url: url.to_string(),
+        });
+    }
+
+    let body = response.text().await?;
+    serde_json::from_str(&body).map_err(|source| SymbolError::Parse { market, source })
+}
+
+/// Discover the current Tier-1 base assets from Binance `exchangeInfo`
+///
+/// Queries the UM futures endpoint (USDT and USDC perpetuals) and the CM futures
+/// endpoint (coin-margined perpetuals), and returns the sorted base assets with an
+/// actively-trading perpetual in all three markets.
+///
+/// [`TIER1_SYMBOLS`] remains available as an offline fallback; see
+/// [`refresh_tier1_symbols_or_fallback`].
+pub async fn refresh_tier1_symbols(client: &Client) -> Result<Vec<String>, SymbolError> {
+    let um = fetch_exchange_info(client, UM_EXCHANGE_INFO_URL, "UM futures").await?;
+    let cm = fetch_exchange_info(client, CM_EXCHANGE_INFO_URL, "CM futures").await?;
+    Ok(tier1_intersection(&um, &cm))
+}
+
+/// Refresh Tier-1 symbols, falling back to the static [`TIER1_SYMBOLS`] list
+///
+/// The fallback is used when the API is unreachable or returns no Tier-1 symbols.
+pub async fn refresh_tier1_symbols_or_fallback(client: &Client) -> Vec<String> {
+    match refresh_tier1_symbols(client).await {
+        Ok(symbols) if !symbols.is_empty() => symbols,
+        _ => get_tier1_symbols(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -149,6 +358,16 @@ mod tests {
         assert_eq!(TIER1_SYMBOLS.len(), 18);
     }
 
+    #[test]
+    fn test_tick_size_covers_tier1() {
+        for base in TIER1_SYMBOLS {
+            assert!(tick_size(base).is_some(), "missing tick size for {base}");
+        }
+        assert_eq!(tick_size("btcusdt"), tick_size("BTCUSD_PERP"));
+        assert_eq!(tick_size("BTCUSDT"), Some(FixedPoint(10_000_000)));
+        assert_eq!(tick_size("SHIB"), None);
+    }
+
     #[test]
     fn test_is_tier1_symbol() {
         assert!(is_tier1_symbol("BTC"));
@@ -158,6 +377,27 @@ mod tests {
         assert!(!is_tier1_symbol("PEPE"));
     }
 
+    #[test]
+    fn test_is_tier1_symbol_pairs_mixed_case() {
+        assert!(is_tier1_symbol("BTCUSDT"));
+        assert!(is_tier1_symbol("btcusdt"));
+        assert!(is_tier1_symbol("EthUsdc"));
+        assert!(is_tier1_symbol("BTCUSD"));
+        assert!(is_tier1_symbol("solusd_perp"));
+        assert!(!is_tier1_symbol("SHIBUSDT"));
+        assert!(!is_tier1_symbol("BTCEUR"));
+        assert!(!is_tier1_symbol(""));
+    }
+
+    #[test]
+    fn test_is_tier1_base() {
+        assert!(is_tier1_base("BTC"));
+        assert!(is_tier1_base("btc"));
+        assert!(is_tier1_base("Doge"));
+        assert!(!is_tier1_base("BTCUSDT"));
+        assert!(!is_tier1_base("PEPE"));
+    }
+
     #[test]
     fn test_get_tier1_symbols() {
         let symbols = get_tier1_symbols();
@@ -173,4 +413,33 @@ mod tests {
         assert!(pairs.contains(&"BTCUSDT".to_string()));
         assert!(pairs.contains(&"ETHUSDT".to_string()));
     }
+
+    #[test]
+    fn test_tier1_intersection_from_exchange_info_fixtures() {
+        let um: ExchangeInfo = serde_json::from_str(include_str!(
+            "../../tests/fixtures/binance_um_exchange_info.json"
+        ))
+        .unwrap();
+        let cm: ExchangeInfo = serde_json::from_str(include_str!(
+            "../../tests/fixtures/binance_cm_exchange_info.json"
+        ))
+        .unwrap();
+
+        // SOL lacks a USDC perpetual, DOGE's CM perpetual is not trading yet,
+        // XRP's USDT perpetual is settling, and quarterlies never count
+        assert_eq!(tier1_intersection(&um, &cm), vec!["BTC", "ETH"]);
+    }
+
+    #[test]
+    fn test_tier1_intersection_empty_market() {
+        let um: ExchangeInfo = serde_json::from_str(include_str!(
+            "../../tests/fixtures/binance_um_exchange_info.json"
+        ))
+        .unwrap();
+        let cm = ExchangeInfo {
+            symbols: Vec::new(),
+        };
+
+        assert!(tier1_intersection(&um, &cm).is_empty());
+    }
 }
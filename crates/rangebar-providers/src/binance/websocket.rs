@@ -3,16 +3,24 @@
 //! This module provides asynchronous WebSocket connections to Binance streams
 //! for real-time aggTrade data feeding into range bar construction.
 
-use futures_util::StreamExt;
-use rangebar_core::{AggTrade, FixedPoint, normalize_timestamp};
-use serde::{Deserialize, Serialize};
+use crate::rate_limit::RateLimiter;
+use futures_util::{SinkExt, StreamExt};
+use rangebar_core::{AggTrade, BinanceAggTradeJson, ConversionError};
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::fmt;
+use std::future::Future;
 use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
 use std::task::{Context, Poll};
+use std::time::Duration;
 use thiserror::Error;
 use tokio::net::TcpStream;
 use tokio::sync::mpsc;
 use tokio_stream::Stream;
-use tokio_tungstenite::{MaybeTlsStream, WebSocketStream, connect_async, tungstenite::Message};
+use tokio_tungstenite::tungstenite::{self, Message};
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream, connect_async};
 
 /// WebSocket specific errors
 #[derive(Error, Debug)]
@@ -29,8 +37,23 @@ pub enum WebSocketError {
     #[error("Invalid symbol: {0}")]
     InvalidSymbol(String),
 
+    #[error("Invalid aggTrade: {0}")]
+    Conversion(#[from] ConversionError),
+
     #[error("Connection closed unexpectedly")]
     ConnectionClosed,
+
+    #[error("REST request failed: {0}")]
+    Rest(#[from] reqwest::Error),
+
+    #[error("Subscription rejected ({code}): {msg}")]
+    SubscriptionRejected { code: i64, msg: String },
+
+    #[error("No subscription reply within {0:?}")]
+    SubscriptionTimeout(Duration),
+
+    #[error("Bootstrap did not reach the live feed within {max_pages} REST pages")]
+    BootstrapPageLimit { max_pages: usize },
 }
 
 impl From<tokio_tungstenite::tungstenite::Error> for WebSocketError {
@@ -39,75 +62,120 @@ impl From<tokio_tungstenite::tungstenite::Error> for WebSocketError {
     }
 }
 
-/// Binance WebSocket aggTrade message format
-#[derive(Debug, Deserialize, Serialize)]
-struct BinanceAggTrade {
-    #[serde(rename = "e")]
-    event_type: String,
-
-    #[serde(rename = "E")]
-    event_time: i64,
+/// Combined-stream envelope: `{"stream":"btcusdt@aggTrade","data":{...}}`
+#[derive(Debug, Deserialize)]
+struct CombinedFrame {
+    data: CombinedTrade,
+}
 
+/// aggTrade event with the symbol kept for routing
+#[derive(Debug, Deserialize)]
+struct CombinedTrade {
     #[serde(rename = "s")]
     symbol: String,
+    #[serde(flatten)]
+    trade: BinanceAggTradeJson,
+}
 
-    #[serde(rename = "a")]
-    agg_trade_id: i64,
+/// Reply to a `SUBSCRIBE` request: `{"result":null,"id":1}` or an `error` object
+#[derive(Debug, Deserialize)]
+struct SubscribeReply {
+    id: u64,
+    #[serde(default)]
+    error: Option<ApiError>,
+}
 
-    #[serde(rename = "p")]
-    price: String,
+/// Error object in a request reply
+#[derive(Debug, Deserialize)]
+struct ApiError {
+    code: i64,
+    msg: String,
+}
 
-    #[serde(rename = "q")]
-    quantity: String,
+/// Callback that fetches the aggTrades missed while disconnected, typically via REST
+///
+/// Called as `backfill(last_agg_id, first_new_agg_id)`; it should return the trades
+/// with IDs strictly between the two. It runs on a blocking thread, so a blocking
+/// HTTP client is fine here.
+pub type BackfillFn = dyn Fn(u64, u64) -> Vec<AggTrade> + Send + Sync;
 
-    #[serde(rename = "f")]
-    first_trade_id: i64,
+/// Reconnection behaviour after the socket drops
+#[derive(Debug, Clone, Copy)]
+pub struct ReconnectPolicy {
+    /// Consecutive reconnect attempts before giving up (0 disables reconnection)
+    pub max_attempts: u32,
+    /// Delay before each reconnect attempt
+    pub delay: Duration,
+}
 
-    #[serde(rename = "l")]
-    last_trade_id: i64,
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            delay: Duration::from_secs(1),
+        }
+    }
+}
 
-    #[serde(rename = "T")]
-    trade_time: i64,
+/// No trade processed yet
+const NO_AGG_TRADE_ID: i64 = -1;
 
-    #[serde(rename = "m")]
-    is_buyer_maker: bool,
+/// REST API host used for bootstrapping
+const DEFAULT_REST_BASE_URL: &str = "https://api.binance.com";
 
-    #[serde(rename = "M")]
-    _ignore: bool,
-}
+/// Maximum aggTrades per REST page
+const REST_PAGE_LIMIT: usize = 1000;
 
-impl BinanceAggTrade {
-    /// Convert Binance WebSocket format to internal AggTrade format
-    fn to_agg_trade(&self) -> Result<AggTrade, WebSocketError> {
-        let price = FixedPoint::from_str(&self.price).map_err(|_| {
-            WebSocketError::JsonParsingFailed(serde_json::from_str::<()>("{}").unwrap_err())
-        })?;
-
-        let volume = FixedPoint::from_str(&self.quantity).map_err(|_| {
-            WebSocketError::JsonParsingFailed(serde_json::from_str::<()>("{}").unwrap_err())
-        })?;
-
-        Ok(AggTrade {
-            agg_trade_id: self.agg_trade_id,
-            price,
-            volume,
-            first_trade_id: self.first_trade_id,
-            last_trade_id: self.last_trade_id,
-            timestamp: normalize_timestamp(self.trade_time as u64),
-            is_buyer_maker: self.is_buyer_maker,
-            is_best_match: None, // Not provided in WebSocket stream
-        })
-    }
+/// Default cap on bootstrap REST pages (100k aggTrades)
+const DEFAULT_BOOTSTRAP_MAX_PAGES: usize = 100;
+
+/// Default bootstrap request rate, well inside Binance's REST weight budget
+const DEFAULT_BOOTSTRAP_REQUESTS_PER_SECOND: f64 = 5.0;
+
+/// Combined-stream endpoint accepting JSON `SUBSCRIBE` requests
+const COMBINED_STREAM_URL: &str = "wss://stream.binance.com:9443/stream";
+
+/// Request ID of the initial `SUBSCRIBE` call
+const SUBSCRIBE_ID: u64 = 1;
+
+/// How long [`BinanceCombinedStream::connect`] waits for the `SUBSCRIBE` reply
+const SUBSCRIBE_ACK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Snapshot of stream health counters
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StreamMetrics {
+    /// Text frames skipped because they were not a valid aggTrade message
+    pub parse_errors: u64,
 }
 
 /// WebSocket stream for Binance aggTrade data
-#[derive(Debug)]
 pub struct BinanceWebSocketStream {
     symbol: String,
     ws_stream: Option<WebSocketStream<MaybeTlsStream<TcpStream>>>,
     receiver: mpsc::Receiver<AggTrade>,
     _sender: mpsc::Sender<AggTrade>, // Keep sender alive
     connected: bool,
+    reconnect_policy: ReconnectPolicy,
+    backfill: Option<Arc<BackfillFn>>,
+    last_agg_trade_id: Arc<AtomicI64>,
+    parse_errors: Arc<AtomicU64>,
+    bootstrap_since_ms: Option<i64>,
+    bootstrap_max_pages: usize,
+    bootstrap_rate_limiter: Option<RateLimiter>,
+    rest_base_url: String,
+}
+
+impl fmt::Debug for BinanceWebSocketStream {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BinanceWebSocketStream")
+            .field("symbol", &self.symbol)
+            .field("connected", &self.connected)
+            .field("reconnect_policy", &self.reconnect_policy)
+            .field("has_backfill", &self.backfill.is_some())
+            .field("bootstrap_since_ms", &self.bootstrap_since_ms)
+            .field("last_agg_trade_id", &self.last_agg_trade_id())
+            .finish_non_exhaustive()
+    }
 }
 
 impl BinanceWebSocketStream {
@@ -128,15 +196,80 @@ impl BinanceWebSocketStream {
             receiver,
             _sender: sender,
             connected: false,
+            reconnect_policy: ReconnectPolicy::default(),
+            backfill: None,
+            last_agg_trade_id: Arc::new(AtomicI64::new(NO_AGG_TRADE_ID)),
+            parse_errors: Arc::new(AtomicU64::new(0)),
+            bootstrap_since_ms: None,
+            bootstrap_max_pages: DEFAULT_BOOTSTRAP_MAX_PAGES,
+            bootstrap_rate_limiter: None,
+            rest_base_url: DEFAULT_REST_BASE_URL.to_string(),
         })
     }
 
-    /// Connect to the Binance WebSocket stream
-    pub async fn connect(&mut self) -> Result<(), WebSocketError> {
-        let url = format!(
+    /// Set how the stream reconnects after the socket drops
+    pub fn with_reconnect_policy(mut self, policy: ReconnectPolicy) -> Self {
+        self.reconnect_policy = policy;
+        self
+    }
+
+    /// Fill aggTrade ID gaps (e.g. after a reconnect) with trades fetched by `backfill`
+    ///
+    /// Without a backfill hook, gaps are logged and the stream resumes from the
+    /// first new trade.
+    pub fn with_backfill<F>(mut self, backfill: F) -> Self
+    where
+        F: Fn(u64, u64) -> Vec<AggTrade> + Send + Sync + 'static,
+    {
+        self.backfill = Some(Arc::new(backfill));
+        self
+    }
+
+    /// Start the feed at `since_ms` (Unix ms) instead of the first live frame
+    ///
+    /// Before forwarding the first WebSocket trade, aggTrades from `since_ms` up
+    /// to (but excluding) its `agg_trade_id` are fetched via REST and forwarded
+    /// first, so the sequence is gap-free from the chosen start. A failed
+    /// bootstrap is logged and the stream starts at the first live trade.
+    pub fn with_bootstrap(mut self, since_ms: i64) -> Self {
+        self.bootstrap_since_ms = Some(since_ms);
+        self
+    }
+
+    /// Give up bootstrapping after `max_pages` REST pages (default 100)
+    ///
+    /// Bounds the catch-up when `since_ms` is far in the past; hitting the cap
+    /// fails the bootstrap with [`WebSocketError::BootstrapPageLimit`].
+    pub fn with_bootstrap_max_pages(mut self, max_pages: usize) -> Self {
+        self.bootstrap_max_pages = max_pages.max(1);
+        self
+    }
+
+    /// Pace bootstrap REST requests with `rate_limiter` (default 5 requests/s)
+    ///
+    /// Pass a clone of a limiter shared with other REST clients to keep them
+    /// under one budget.
+    pub fn with_bootstrap_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.bootstrap_rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Override the REST host used for bootstrapping (mirrors, test servers)
+    pub fn with_rest_base_url(mut self, base_url: &str) -> Self {
+        self.rest_base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    fn stream_url(&self) -> String {
+        format!(
             "wss://stream.binance.com:9443/ws/{}@aggTrade",
             self.symbol.to_lowercase()
-        );
+        )
+    }
+
+    /// Connect to the Binance WebSocket stream
+    pub async fn connect(&mut self) -> Result<(), WebSocketError> {
+        let url = self.stream_url();
 
         println!("🔌 Connecting to WebSocket: {}", url);
 
@@ -150,55 +283,71 @@ impl BinanceWebSocketStream {
     }
 
     /// Start the message processing loop in a background task
+    ///
+    /// When the socket drops, the loop reconnects per the [`ReconnectPolicy`] and
+    /// backfills any skipped aggTrade IDs before resuming.
     pub async fn start_processing(&mut self) -> Result<(), WebSocketError> {
-        if let Some(mut ws_stream) = self.ws_stream.take() {
-            let sender = self._sender.clone();
-            let symbol = self.symbol.clone();
+        if let Some(ws_stream) = self.ws_stream.take() {
+            let ctx = SessionContext {
+                symbol: self.symbol.clone(),
+                sender: self._sender.clone(),
+                last_agg_trade_id: Arc::clone(&self.last_agg_trade_id),
+                backfill: self.backfill.clone(),
+                parse_errors: Arc::clone(&self.parse_errors),
+                bootstrap: self.bootstrap_since_ms.map(|since_ms| Bootstrap {
+                    client: reqwest::Client::new(),
+                    base_url: self.rest_base_url.clone(),
+                    since_ms,
+                    max_pages: self.bootstrap_max_pages,
+                    rate_limiter: self.bootstrap_rate_limiter.clone().unwrap_or_else(|| {
+                        RateLimiter::new(DEFAULT_BOOTSTRAP_REQUESTS_PER_SECOND)
+                            .expect("default bootstrap rate is valid")
+                    }),
+                }),
+            };
+            let policy = self.reconnect_policy;
+            let url = self.stream_url();
 
             tokio::spawn(async move {
-                println!("🚀 Starting WebSocket message processing for {}", symbol);
-
-                while let Some(msg) = ws_stream.next().await {
-                    match msg {
-                        Ok(Message::Text(text)) => {
-                            if let Ok(binance_trade) =
-                                serde_json::from_str::<BinanceAggTrade>(&text)
-                            {
-                                if let Ok(agg_trade) = binance_trade.to_agg_trade() {
-                                    if sender.send(agg_trade).await.is_err() {
-                                        println!(
-                                            "❌ Channel closed, stopping WebSocket processing"
-                                        );
-                                        break;
-                                    }
-                                } else {
-                                    println!("⚠️ Failed to convert trade data");
-                                }
-                            } else {
-                                println!("⚠️ Failed to parse JSON: {}", text);
-                            }
-                        }
-                        Ok(Message::Close(_)) => {
-                            println!("🔌 WebSocket connection closed by server");
-                            break;
-                        }
-                        Ok(_) => {
-                            // Ignore other message types (ping, pong, binary)
-                        }
-                        Err(e) => {
-                            println!("❌ WebSocket error: {}", e);
-                            break;
-                        }
+                println!(
+                    "🚀 Starting WebSocket message processing for {}",
+                    ctx.symbol
+                );
+
+                let reconnect = || {
+                    let url = url.clone();
+                    async move {
+                        let (ws_stream, _) = connect_async(&url).await?;
+                        Ok::<_, WebSocketError>(ws_stream)
                     }
-                }
+                };
+                run_with_reconnect(ws_stream, reconnect, policy, &ctx).await;
 
-                println!("🔚 WebSocket processing ended for {}", symbol);
+                println!("🔚 WebSocket processing ended for {}", ctx.symbol);
             });
         }
 
         Ok(())
     }
 
+    /// ID of the last aggTrade forwarded to the receiver
+    pub fn last_agg_trade_id(&self) -> Option<i64> {
+        match self.last_agg_trade_id.load(Ordering::SeqCst) {
+            NO_AGG_TRADE_ID => None,
+            id => Some(id),
+        }
+    }
+
+    /// Health counters accumulated across reconnects
+    ///
+    /// Malformed frames are skipped rather than ending the stream; only
+    /// connection-level failures interrupt it.
+    pub fn stream_metrics(&self) -> StreamMetrics {
+        StreamMetrics {
+            parse_errors: self.parse_errors.load(Ordering::Relaxed),
+        }
+    }
+
     /// Get the next trade from the stream
     pub async fn next_trade(&mut self) -> Option<AggTrade> {
         self.receiver.recv().await
@@ -223,9 +372,455 @@ impl Stream for BinanceWebSocketStream {
     }
 }
 
+impl BinanceWebSocketStream {
+    /// Subscribe to aggTrades for several symbols over one combined-stream socket
+    ///
+    /// Yields `(symbol, trade)` pairs, which feed directly into a per-symbol
+    /// router such as `rangebar_streaming::StreamingRouter::process`.
+    pub async fn subscribe_many(symbols: &[&str]) -> Result<BinanceCombinedStream, WebSocketError> {
+        BinanceCombinedStream::connect(COMBINED_STREAM_URL, symbols).await
+    }
+}
+
+/// One socket carrying aggTrades for several symbols
+///
+/// Unlike [`BinanceWebSocketStream`], the combined stream does not reconnect or
+/// backfill; it ends when the socket drops.
+pub struct BinanceCombinedStream {
+    symbols: Vec<String>,
+    receiver: mpsc::Receiver<(String, AggTrade)>,
+    parse_errors: Arc<AtomicU64>,
+}
+
+impl fmt::Debug for BinanceCombinedStream {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BinanceCombinedStream")
+            .field("symbols", &self.symbols)
+            .field("stream_metrics", &self.stream_metrics())
+            .finish_non_exhaustive()
+    }
+}
+
+impl BinanceCombinedStream {
+    /// Connect to a combined-stream endpoint (mirrors, test servers) and subscribe
+    ///
+    /// Symbols are validated locally before connecting; an empty list or a
+    /// non-alphanumeric symbol yields [`WebSocketError::InvalidSymbol`]. Returns
+    /// once the server acknowledges the subscription, with
+    /// [`WebSocketError::SubscriptionRejected`] if it refuses, or with
+    /// [`WebSocketError::SubscriptionTimeout`] if no reply arrives within 10s.
+    pub async fn connect(url: &str, symbols: &[&str]) -> Result<Self, WebSocketError> {
+        Self::connect_with_ack_timeout(url, symbols, SUBSCRIBE_ACK_TIMEOUT).await
+    }
+
+    /// [`connect`](Self::connect) with a custom wait for the subscription reply
+    pub async fn connect_with_ack_timeout(
+        url: &str,
+        symbols: &[&str],
+        ack_timeout: Duration,
+    ) -> Result<Self, WebSocketError> {
+        let symbols: Vec<String> = symbols.iter().map(|s| s.to_uppercase()).collect();
+        if symbols.is_empty() {
+            return Err(WebSocketError::InvalidSymbol(String::new()));
+        }
+        if let Some(invalid) = symbols
+            .iter()
+            .find(|s| s.is_empty() || !s.chars().all(|c| c.is_ascii_alphanumeric()))
+        {
+            return Err(WebSocketError::InvalidSymbol(invalid.clone()));
+        }
+
+        let (mut ws_stream, _) = connect_async(url).await?;
+        let request = serde_json::json!({
+            "method": "SUBSCRIBE",
+            "params": symbols
+                .iter()
+                .map(|s| format!("{}@aggTrade", s.to_lowercase()))
+                .collect::<Vec<_>>(),
+            "id": SUBSCRIBE_ID,
+        });
+        ws_stream.send(Message::Text(request.to_string())).await?;
+
+        // Trades may race ahead of the ack; keep them for the pump
+        let mut early_frames = Vec::new();
+        let await_ack = async {
+            loop {
+                match ws_stream.next().await {
+                    Some(Ok(Message::Text(text))) => {
+                        match serde_json::from_str::<SubscribeReply>(&text) {
+                            Ok(reply) if reply.id == SUBSCRIBE_ID => {
+                                return match reply.error {
+                                    Some(ApiError { code, msg }) => {
+                                        Err(WebSocketError::SubscriptionRejected { code, msg })
+                                    }
+                                    None => Ok(()),
+                                };
+                            }
+                            _ => early_frames.push(text),
+                        }
+                    }
+                    Some(Ok(Message::Close(_))) | None => {
+                        return Err(WebSocketError::ConnectionClosed);
+                    }
+                    Some(Ok(_)) => {}
+                    Some(Err(e)) => return Err(e.into()),
+                }
+            }
+        };
+        tokio::time::timeout(ack_timeout, await_ack)
+            .await
+            .map_err(|_| WebSocketError::SubscriptionTimeout(ack_timeout))??;
+
+        let (sender, receiver) = mpsc::channel(1000);
+        let parse_errors = Arc::new(AtomicU64::new(0));
+        let ctx = CombinedContext {
+            symbols: symbols.iter().cloned().collect(),
+            sender,
+            parse_errors: Arc::clone(&parse_errors),
+        };
+        tokio::spawn(async move {
+            for text in early_frames {
+                if !ctx.handle_text(&text).await {
+                    return;
+                }
+            }
+            pump_combined(&mut ws_stream, &ctx).await;
+        });
+
+        Ok(Self {
+            symbols,
+            receiver,
+            parse_errors,
+        })
+    }
+
+    /// Subscribed symbols (uppercase)
+    pub fn symbols(&self) -> &[String] {
+        &self.symbols
+    }
+
+    /// Get the next `(symbol, trade)` pair
+    pub async fn next_trade(&mut self) -> Option<(String, AggTrade)> {
+        self.receiver.recv().await
+    }
+
+    /// Health counters for this socket
+    pub fn stream_metrics(&self) -> StreamMetrics {
+        StreamMetrics {
+            parse_errors: self.parse_errors.load(Ordering::Relaxed),
+        }
+    }
+}
+
+impl Stream for BinanceCombinedStream {
+    type Item = (String, AggTrade);
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
+/// State for the combined-stream processing task
+struct CombinedContext {
+    symbols: HashSet<String>,
+    sender: mpsc::Sender<(String, AggTrade)>,
+    parse_errors: Arc<AtomicU64>,
+}
+
+impl CombinedContext {
+    /// Route one text frame; returns `false` once the receiver has been dropped
+    async fn handle_text(&self, text: &str) -> bool {
+        match serde_json::from_str::<CombinedFrame>(text) {
+            Ok(frame) => {
+                let symbol = frame.data.symbol.to_uppercase();
+                if !self.symbols.contains(&symbol) {
+                    println!("⚠️ Ignoring trade for unsubscribed symbol {}", symbol);
+                    return true;
+                }
+                match AggTrade::try_from(frame.data.trade) {
+                    Ok(trade) => self.sender.send((symbol, trade)).await.is_ok(),
+                    Err(e) => {
+                        self.parse_errors.fetch_add(1, Ordering::Relaxed);
+                        println!("⚠️ Skipping unparseable frame: {}", e);
+                        true
+                    }
+                }
+            }
+            Err(e) => {
+                match serde_json::from_str::<SubscribeReply>(text) {
+                    Ok(SubscribeReply {
+                        error: Some(error), ..
+                    }) => println!("⚠️ Request rejected ({}): {}", error.code, error.msg),
+                    Ok(_) => {}
+                    Err(_) => {
+                        self.parse_errors.fetch_add(1, Ordering::Relaxed);
+                        println!("⚠️ Skipping unparseable frame: {}", e);
+                    }
+                }
+                true
+            }
+        }
+    }
+}
+
+/// Forward combined-stream trades until the socket ends or the receiver drops
+async fn pump_combined<S>(ws_stream: &mut S, ctx: &CombinedContext)
+where
+    S: Stream<Item = Result<Message, tungstenite::Error>> + Unpin,
+{
+    while let Some(msg) = ws_stream.next().await {
+        match msg {
+            Ok(Message::Text(text)) => {
+                if !ctx.handle_text(&text).await {
+                    return;
+                }
+            }
+            Ok(Message::Close(_)) => return,
+            Ok(_) => {}
+            Err(e) => {
+                println!("❌ WebSocket error: {}", e);
+                return;
+            }
+        }
+    }
+}
+
+/// State shared by the processing loop across reconnects
+struct SessionContext {
+    symbol: String,
+    sender: mpsc::Sender<AggTrade>,
+    last_agg_trade_id: Arc<AtomicI64>,
+    backfill: Option<Arc<BackfillFn>>,
+    parse_errors: Arc<AtomicU64>,
+    bootstrap: Option<Bootstrap>,
+}
+
+/// REST snapshot taken before the first live trade
+struct Bootstrap {
+    client: reqwest::Client,
+    base_url: String,
+    since_ms: i64,
+    max_pages: usize,
+    rate_limiter: RateLimiter,
+}
+
+impl Bootstrap {
+    /// Fetch aggTrades from `since_ms` with IDs below `first_live_id`, in ID order
+    async fn fetch_before(
+        &self,
+        symbol: &str,
+        first_live_id: i64,
+    ) -> Result<Vec<AggTrade>, WebSocketError> {
+        let mut trades: Vec<AggTrade> = Vec::new();
+        let mut cursor = format!("startTime={}", self.since_ms);
+
+        for _ in 0..self.max_pages {
+            self.rate_limiter.acquire().await;
+            let url = format!(
+                "{}/api/v3/aggTrades?symbol={}&{}&limit={}",
+                self.base_url, symbol, cursor, REST_PAGE_LIMIT
+            );
+            let body = self
+                .client
+                .get(&url)
+                .send()
+                .await?
+                .error_for_status()?
+                .text()
+                .await?;
+            let page: Vec<BinanceAggTradeJson> = serde_json::from_str(&body)?;
+            let Some(last) = page.last().map(|t| t.agg_trade_id) else {
+                return Ok(trades);
+            };
+
+            for raw in page.into_iter().filter(|t| t.agg_trade_id < first_live_id) {
+                if trades
+                    .last()
+                    .is_none_or(|t| raw.agg_trade_id > t.agg_trade_id)
+                {
+                    trades.push(AggTrade::try_from(raw)?);
+                }
+            }
+            if last >= first_live_id - 1 {
+                return Ok(trades);
+            }
+            cursor = format!("fromId={}", last + 1);
+        }
+
+        Err(WebSocketError::BootstrapPageLimit {
+            max_pages: self.max_pages,
+        })
+    }
+}
+
+/// Why a socket session stopped
+enum SessionEnd {
+    /// Socket closed or errored; reconnecting may help
+    Disconnected,
+    /// Receiver dropped; nobody is listening anymore
+    ChannelClosed,
+}
+
+/// Pump sessions, reconnecting after each drop until the policy is exhausted
+async fn run_with_reconnect<S, C, Fut>(
+    mut session: S,
+    mut reconnect: C,
+    policy: ReconnectPolicy,
+    ctx: &SessionContext,
+) where
+    S: Stream<Item = Result<Message, tungstenite::Error>> + Unpin,
+    C: FnMut() -> Fut,
+    Fut: Future<Output = Result<S, WebSocketError>>,
+{
+    loop {
+        if let SessionEnd::ChannelClosed = pump_session(&mut session, ctx).await {
+            return;
+        }
+
+        let mut attempt = 0;
+        session = loop {
+            if attempt >= policy.max_attempts {
+                println!(
+                    "❌ Giving up on {} after {} reconnect attempts",
+                    ctx.symbol, attempt
+                );
+                return;
+            }
+            attempt += 1;
+            tokio::time::sleep(policy.delay).await;
+
+            println!(
+                "🔄 Reconnecting {} ({}/{})",
+                ctx.symbol, attempt, policy.max_attempts
+            );
+            match reconnect().await {
+                Ok(session) => break session,
+                Err(e) => println!("⚠️ Reconnect failed: {}", e),
+            }
+        };
+    }
+}
+
+/// Forward trades from one socket session until it ends
+async fn pump_session<S>(ws_stream: &mut S, ctx: &SessionContext) -> SessionEnd
+where
+    S: Stream<Item = Result<Message, tungstenite::Error>> + Unpin,
+{
+    while let Some(msg) = ws_stream.next().await {
+        match msg {
+            Ok(Message::Text(text)) => match parse_frame(&text) {
+                Ok(agg_trade) => {
+                    if !forward_trade(agg_trade, ctx).await {
+                        println!("❌ Channel closed, stopping WebSocket processing");
+                        return SessionEnd::ChannelClosed;
+                    }
+                }
+                Err(e) => {
+                    // A bad frame is not worth dropping a live feed over
+                    ctx.parse_errors.fetch_add(1, Ordering::Relaxed);
+                    println!("⚠️ Skipping unparseable frame: {}", e);
+                }
+            },
+            Ok(Message::Close(_)) => {
+                println!("🔌 WebSocket connection closed by server");
+                return SessionEnd::Disconnected;
+            }
+            Ok(_) => {
+                // Ignore other message types (ping, pong, binary)
+            }
+            Err(e) => {
+                println!("❌ WebSocket error: {}", e);
+                return SessionEnd::Disconnected;
+            }
+        }
+    }
+
+    SessionEnd::Disconnected
+}
+
+/// Decode one text frame into a trade
+fn parse_frame(text: &str) -> Result<AggTrade, WebSocketError> {
+    let raw: BinanceAggTradeJson = serde_json::from_str(text)?;
+    Ok(AggTrade::try_from(raw)?)
+}
+
+/// Forward a trade, skipping replays and backfilling any aggTrade ID gap first
+///
+/// Returns `false` once the receiver has been dropped.
+async fn forward_trade(trade: AggTrade, ctx: &SessionContext) -> bool {
+    if ctx.last_agg_trade_id.load(Ordering::SeqCst) == NO_AGG_TRADE_ID
+        && let Some(bootstrap) = &ctx.bootstrap
+    {
+        match bootstrap
+            .fetch_before(&ctx.symbol, trade.agg_trade_id)
+            .await
+        {
+            Ok(snapshot) => {
+                for snapshot_trade in snapshot {
+                    let id = snapshot_trade.agg_trade_id;
+                    if ctx.sender.send(snapshot_trade).await.is_err() {
+                        return false;
+                    }
+                    ctx.last_agg_trade_id.store(id, Ordering::SeqCst);
+                }
+            }
+            Err(e) => println!("⚠️ Bootstrap failed for {}: {}", ctx.symbol, e),
+        }
+    }
+
+    let last_id = ctx.last_agg_trade_id.load(Ordering::SeqCst);
+
+    if last_id != NO_AGG_TRADE_ID {
+        if trade.agg_trade_id <= last_id {
+            // Already delivered (replayed after reconnect or by backfill)
+            return true;
+        }
+
+        if trade.agg_trade_id > last_id + 1 {
+            match &ctx.backfill {
+                Some(backfill) => {
+                    let backfill = Arc::clone(backfill);
+                    let (from, to) = (last_id as u64, trade.agg_trade_id as u64);
+                    let mut missed = tokio::task::spawn_blocking(move || backfill(from, to))
+                        .await
+                        .unwrap_or_default();
+                    missed.retain(|t| {
+                        t.agg_trade_id > last_id && t.agg_trade_id < trade.agg_trade_id
+                    });
+                    missed.sort_by_key(|t| t.agg_trade_id);
+                    missed.dedup_by_key(|t| t.agg_trade_id);
+
+                    for missed_trade in missed {
+                        let id = missed_trade.agg_trade_id;
+                        if ctx.sender.send(missed_trade).await.is_err() {
+                            return false;
+                        }
+                        ctx.last_agg_trade_id.store(id, Ordering::SeqCst);
+                    }
+                }
+                None => println!(
+                    "⚠️ aggTrade gap for {}: {} -> {} ({} missing)",
+                    ctx.symbol,
+                    last_id,
+                    trade.agg_trade_id,
+                    trade.agg_trade_id - last_id - 1
+                ),
+            }
+        }
+    }
+
+    let id = trade.agg_trade_id;
+    if ctx.sender.send(trade).await.is_err() {
+        return false;
+    }
+    ctx.last_agg_trade_id.store(id, Ordering::SeqCst);
+    true
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rangebar_core::FixedPoint;
 
     #[test]
     fn test_binance_aggtrade_conversion() {
@@ -243,14 +838,21 @@ mod tests {
             "M": true
         }"#;
 
-        let binance_trade: BinanceAggTrade = serde_json::from_str(json).unwrap();
-        let agg_trade = binance_trade.to_agg_trade().unwrap();
+        let agg_trade = parse_frame(json).unwrap();
 
         assert_eq!(agg_trade.agg_trade_id, 3679713739);
         assert_eq!(agg_trade.price.to_f64(), 112070.01);
         assert_eq!(agg_trade.volume.to_f64(), 0.01328);
         assert_eq!(agg_trade.timestamp, 1758666334424000);
         assert!(!agg_trade.is_buyer_maker);
+
+        let negative = json.replace("\"T\": 1758666334424", "\"T\": -1");
+        assert!(matches!(
+            parse_frame(&negative),
+            Err(WebSocketError::Conversion(
+                ConversionError::NegativeTimestamp { timestamp: -1 }
+            ))
+        ));
     }
 
     #[tokio::test]
@@ -272,4 +874,342 @@ mod tests {
             WebSocketError::InvalidSymbol(_)
         ));
     }
+
+    fn agg_trade_json(id: i64) -> Message {
+        Message::Text(format!(
+            r#"{{"e":"aggTrade","E":1758666334424,"s":"BTCUSDT","a":{id},"p":"112070.01","q":"0.5","f":{id},"l":{id},"T":1758666334424,"m":false,"M":true}}"#
+        ))
+    }
+
+    fn backfilled_trade(id: i64) -> AggTrade {
+        AggTrade {
+            agg_trade_id: id,
+            price: FixedPoint::from_str("112070.01").unwrap(),
+            volume: FixedPoint::from_str("0.5").unwrap(),
+            first_trade_id: id,
+            last_trade_id: id,
+            timestamp: 1758666334424000,
+            is_buyer_maker: false,
+            is_best_match: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_backfills_missed_agg_trade_ids() {
+        use futures_util::stream;
+        use std::collections::VecDeque;
+        use std::sync::Mutex;
+
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&calls);
+        let backfill: Arc<BackfillFn> = Arc::new(move |from, to| {
+            recorded.lock().unwrap().push((from, to));
+            (from as i64 + 1..to as i64).map(backfilled_trade).collect()
+        });
+
+        // First session drops after trade 2; the resumed session replays 2 and jumps to 5
+        let first = stream::iter(vec![
+            Ok(agg_trade_json(1)),
+            Ok(agg_trade_json(2)),
+            Err(tungstenite::Error::ConnectionClosed),
+        ]);
+        let mut sessions = VecDeque::from(vec![stream::iter(vec![
+            Ok(agg_trade_json(2)),
+            Ok(agg_trade_json(5)),
+            Ok(agg_trade_json(6)),
+        ])]);
+
+        let (sender, mut receiver) = mpsc::channel(100);
+        let ctx = SessionContext {
+            symbol: "BTCUSDT".to_string(),
+            sender,
+            last_agg_trade_id: Arc::new(AtomicI64::new(NO_AGG_TRADE_ID)),
+            backfill: Some(backfill),
+            parse_errors: Arc::new(AtomicU64::new(0)),
+            bootstrap: None,
+        };
+        let policy = ReconnectPolicy {
+            max_attempts: 1,
+            delay: Duration::ZERO,
+        };
+
+        let reconnect = || {
+            let next = sessions.pop_front();
+            async move { next.ok_or(WebSocketError::ConnectionClosed) }
+        };
+        run_with_reconnect(first, reconnect, policy, &ctx).await;
+        drop(ctx);
+
+        let mut ids = Vec::new();
+        while let Some(trade) = receiver.recv().await {
+            ids.push(trade.agg_trade_id);
+        }
+
+        assert_eq!(ids, vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(*calls.lock().unwrap(), vec![(2, 5)]);
+    }
+
+    #[tokio::test]
+    async fn test_malformed_frames_are_skipped_and_counted() {
+        use futures_util::stream;
+
+        let bad_number = Message::Text(
+            r#"{"e":"aggTrade","E":1,"s":"BTCUSDT","a":3,"p":"abc","q":"0.5","f":3,"l":3,"T":1,"m":false,"M":true}"#
+                .to_string(),
+        );
+        let mut session = stream::iter(vec![
+            Ok(agg_trade_json(1)),
+            Ok(Message::Text(r#"{"e":"aggTrade","a":"#.to_string())),
+            Ok(agg_trade_json(2)),
+            Ok(bad_number),
+            Ok(agg_trade_json(3)),
+        ]);
+
+        let (sender, mut receiver) = mpsc::channel(100);
+        let parse_errors = Arc::new(AtomicU64::new(0));
+        let ctx = SessionContext {
+            symbol: "BTCUSDT".to_string(),
+            sender,
+            last_agg_trade_id: Arc::new(AtomicI64::new(NO_AGG_TRADE_ID)),
+            backfill: None,
+            parse_errors: Arc::clone(&parse_errors),
+            bootstrap: None,
+        };
+
+        assert!(matches!(
+            pump_session(&mut session, &ctx).await,
+            SessionEnd::Disconnected
+        ));
+        drop(ctx);
+
+        let mut ids = Vec::new();
+        while let Some(trade) = receiver.recv().await {
+            ids.push(trade.agg_trade_id);
+        }
+        assert_eq!(ids, vec![1, 2, 3]);
+        assert_eq!(parse_errors.load(Ordering::Relaxed), 2);
+
+        let stream = BinanceWebSocketStream::new("BTCUSDT").await.unwrap();
+        assert_eq!(stream.stream_metrics(), StreamMetrics::default());
+    }
+
+    /// Serve one socket: expect a SUBSCRIBE, reply with `reply`, then send `frames`
+    async fn mock_combined_server(reply: &'static str, frames: Vec<String>) -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}/stream", listener.local_addr().unwrap());
+
+        tokio::spawn(async move {
+            let (tcp, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(tcp).await.unwrap();
+            let request = ws.next().await.unwrap().unwrap();
+            let request: serde_json::Value =
+                serde_json::from_str(request.to_text().unwrap()).unwrap();
+            assert_eq!(request["method"], "SUBSCRIBE");
+            assert_eq!(
+                request["params"],
+                serde_json::json!(["btcusdt@aggTrade", "ethusdt@aggTrade"])
+            );
+
+            ws.send(Message::Text(reply.to_string())).await.unwrap();
+            for frame in frames {
+                ws.send(Message::Text(frame)).await.unwrap();
+            }
+            ws.close(None).await.ok();
+        });
+
+        url
+    }
+
+    fn combined_frame(symbol: &str, id: i64, price: &str) -> String {
+        format!(
+            r#"{{"stream":"{lower}@aggTrade","data":{{"e":"aggTrade","E":1,"s":"{symbol}","a":{id},"p":"{price}","q":"0.5","f":{id},"l":{id},"T":1758666334424,"m":false,"M":true}}}}"#,
+            lower = symbol.to_lowercase()
+        )
+    }
+
+    #[tokio::test]
+    async fn test_combined_stream_yields_interleaved_symbols() {
+        let url = mock_combined_server(
+            r#"{"result":null,"id":1}"#,
+            vec![
+                combined_frame("BTCUSDT", 10, "112070.01"),
+                combined_frame("ETHUSDT", 20, "4100.5"),
+                "not json".to_string(),
+                combined_frame("SOLUSDT", 30, "200.0"),
+                combined_frame("BTCUSDT", 11, "112071.00"),
+                combined_frame("ETHUSDT", 21, "4101.0"),
+            ],
+        )
+        .await;
+
+        let mut stream = BinanceCombinedStream::connect(&url, &["btcusdt", "ETHUSDT"])
+            .await
+            .unwrap();
+        assert_eq!(stream.symbols(), ["BTCUSDT", "ETHUSDT"]);
+
+        let mut received = Vec::new();
+        while let Some((symbol, trade)) = stream.next_trade().await {
+            received.push((symbol, trade.agg_trade_id));
+        }
+        let expected = [
+            ("BTCUSDT", 10),
+            ("ETHUSDT", 20),
+            ("BTCUSDT", 11),
+            ("ETHUSDT", 21),
+        ];
+        assert_eq!(
+            received,
+            expected.map(|(symbol, id)| (symbol.to_string(), id))
+        );
+        assert_eq!(stream.stream_metrics().parse_errors, 1);
+    }
+
+    #[tokio::test]
+    async fn test_combined_stream_surfaces_subscription_errors() {
+        let url = mock_combined_server(
+            r#"{"error":{"code":2,"msg":"Invalid request: unknown stream"},"id":1}"#,
+            Vec::new(),
+        )
+        .await;
+        let err = BinanceCombinedStream::connect(&url, &["BTCUSDT", "ETHUSDT"])
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            WebSocketError::SubscriptionRejected { code: 2, .. }
+        ));
+
+        let err = BinanceWebSocketStream::subscribe_many(&["BTCUSDT", "BTC-USD"])
+            .await
+            .unwrap_err();
+        assert!(matches!(err, WebSocketError::InvalidSymbol(s) if s == "BTC-USD"));
+    }
+
+    #[tokio::test]
+    async fn test_combined_stream_times_out_without_ack() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}/stream", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (tcp, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(tcp).await.unwrap();
+            ws.next().await;
+            // Hold the socket open without ever replying
+            tokio::time::sleep(Duration::from_secs(5)).await;
+        });
+
+        let err = BinanceCombinedStream::connect_with_ack_timeout(
+            &url,
+            &["BTCUSDT"],
+            Duration::from_millis(100),
+        )
+        .await
+        .unwrap_err();
+        assert!(
+            matches!(err, WebSocketError::SubscriptionTimeout(t) if t == Duration::from_millis(100))
+        );
+    }
+
+    /// Serve canned JSON bodies in order, one per connection, recording request lines
+    async fn mock_rest_server(bodies: Vec<String>) -> (String, Arc<std::sync::Mutex<Vec<String>>>) {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&requests);
+
+        tokio::spawn(async move {
+            for body in bodies {
+                let Ok((mut socket, _)) = listener.accept().await else {
+                    return;
+                };
+                let mut request = [0u8; 4096];
+                let n = socket.read(&mut request).await.unwrap_or(0);
+                let head = String::from_utf8_lossy(&request[..n]);
+                recorded
+                    .lock()
+                    .unwrap()
+                    .push(head.lines().next().unwrap_or_default().to_string());
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _ = socket.write_all(response.as_bytes()).await;
+                let _ = socket.shutdown().await;
+            }
+        });
+
+        (url, requests)
+    }
+
+    fn rest_page(ids: std::ops::RangeInclusive<i64>) -> String {
+        let trades: Vec<String> = ids
+            .map(|id| {
+                format!(
+                    r#"{{"a":{id},"p":"112070.01","q":"0.5","f":{id},"l":{id},"T":1758666334000,"m":true,"M":true}}"#
+                )
+            })
+            .collect();
+        format!("[{}]", trades.join(","))
+    }
+
+    #[tokio::test]
+    async fn test_bootstrap_fills_seam_before_first_live_trade() {
+        use futures_util::stream;
+
+        // Second page overlaps the live feed, which starts at 105
+        let (url, requests) =
+            mock_rest_server(vec![rest_page(100..=102), rest_page(103..=106)]).await;
+        let mut session = stream::iter(vec![Ok(agg_trade_json(105)), Ok(agg_trade_json(106))]);
+
+        let (sender, mut receiver) = mpsc::channel(100);
+        let ctx = SessionContext {
+            symbol: "BTCUSDT".to_string(),
+            sender,
+            last_agg_trade_id: Arc::new(AtomicI64::new(NO_AGG_TRADE_ID)),
+            backfill: None,
+            parse_errors: Arc::new(AtomicU64::new(0)),
+            bootstrap: Some(Bootstrap {
+                client: reqwest::Client::new(),
+                base_url: url,
+                since_ms: 1_758_666_000_000,
+                max_pages: DEFAULT_BOOTSTRAP_MAX_PAGES,
+                rate_limiter: RateLimiter::new(1000.0).unwrap(),
+            }),
+        };
+        pump_session(&mut session, &ctx).await;
+        drop(ctx);
+
+        let mut ids = Vec::new();
+        while let Some(trade) = receiver.recv().await {
+            ids.push(trade.agg_trade_id);
+        }
+        assert_eq!(ids, (100..=106).collect::<Vec<_>>());
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].contains("symbol=BTCUSDT&startTime=1758666000000"));
+        assert!(requests[1].contains("fromId=103"));
+    }
+
+    #[tokio::test]
+    async fn test_bootstrap_stops_at_page_cap() {
+        let (url, requests) =
+            mock_rest_server(vec![rest_page(100..=102), rest_page(103..=106)]).await;
+        let bootstrap = Bootstrap {
+            client: reqwest::Client::new(),
+            base_url: url,
+            since_ms: 1_758_666_000_000,
+            max_pages: 1,
+            rate_limiter: RateLimiter::new(1000.0).unwrap(),
+        };
+
+        let err = bootstrap.fetch_before("BTCUSDT", 105).await.unwrap_err();
+        assert!(matches!(
+            err,
+            WebSocketError::BootstrapPageLimit { max_pages: 1 }
+        ));
+        assert_eq!(requests.lock().unwrap().len(), 1);
+    }
 }
@@ -9,6 +9,7 @@ use crate::exness::types::{
 };
 use rangebar_core::fixed_point::FixedPoint;
 use rangebar_core::processor::RangeBarProcessor;
+use rangebar_core::{DataSource, RangeBar};
 
 /// Streaming range bar builder for Exness tick data
 ///
@@ -20,6 +21,7 @@ use rangebar_core::processor::RangeBarProcessor;
 /// Volume semantics:
 /// - bar.volume = 0 (Exness Raw_Spread has no volume data)
 /// - buy_volume = 0, sell_volume = 0 (direction unknown for quote data)
+/// - data_source = DataSource::Exness, so `direction_known()` is false
 /// - SpreadStats tracks spread dynamics as market stress signal
 pub struct ExnessRangeBarBuilder {
     /// Core range bar processor (stateful)
@@ -77,7 +79,8 @@ impl ExnessRangeBarBuilder {
         validation_strictness: ValidationStrictness,
     ) -> Result<Self, rangebar_core::processor::ProcessingError> {
         Ok(Self {
-            processor: RangeBarProcessor::new(threshold_decimal_bps)?,
+            processor: RangeBarProcessor::new(threshold_decimal_bps)?
+                .with_data_source(DataSource::Exness),
             tick_counter: 0,
             instrument: instrument.into(),
             validation_strictness,
@@ -161,14 +164,7 @@ impl ExnessRangeBarBuilder {
 
         // 4. If bar closed, wrap with spread stats and reset
         if let Some(mut base) = maybe_bar {
-            // Zero out buy/sell volume (Exness has no volume data)
-            // Synthetic trades use mid-price, but direction is unknown
-            base.buy_volume = FixedPoint(0);
-            base.sell_volume = FixedPoint(0);
-            base.buy_trade_count = 0;
-            base.sell_trade_count = 0;
-            base.buy_turnover = 0;
-            base.sell_turnover = 0;
+            normalize_quote_bar(&mut base);
 
             let completed_bar = ExnessRangeBar {
                 base,
@@ -196,13 +192,7 @@ impl ExnessRangeBarBuilder {
     /// `Some(ExnessRangeBar)` if bar in progress, `None` if no active bar
     pub fn get_incomplete_bar(&self) -> Option<ExnessRangeBar> {
         self.processor.get_incomplete_bar().map(|mut base| {
-            // Zero out buy/sell volume (Exness has no volume data)
-            base.buy_volume = FixedPoint(0);
-            base.sell_volume = FixedPoint(0);
-            base.buy_trade_count = 0;
-            base.sell_trade_count = 0;
-            base.buy_turnover = 0;
-            base.sell_turnover = 0;
+            normalize_quote_bar(&mut base);
 
             ExnessRangeBar {
                 base,
@@ -212,6 +202,19 @@ impl ExnessRangeBarBuilder {
     }
 }
 
+/// Zero the direction-dependent fields of an Exness bar
+///
+/// Synthetic trades use mid-price, but the aggressor side is unknown for
+/// quote data, so buy/sell splits would be fabricated.
+fn normalize_quote_bar(base: &mut RangeBar) {
+    base.buy_volume = FixedPoint(0);
+    base.sell_volume = FixedPoint(0);
+    base.buy_trade_count = 0;
+    base.sell_trade_count = 0;
+    base.buy_turnover = 0;
+    base.sell_turnover = 0;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -328,4 +331,52 @@ mod tests {
         assert_eq!(bar.base.buy_volume.0, 0);
         assert_eq!(bar.base.sell_volume.0, 0);
     }
+
+    #[test]
+    fn test_cross_provider_source_and_direction() {
+        let mut builder =
+            ExnessRangeBarBuilder::new(250, "EURUSD_Raw_Spread", ValidationStrictness::Strict)
+                .unwrap();
+        let ticks = [(1.0800, 1.0810), (1.0828, 1.0838)];
+        let exness_bar = ticks
+            .iter()
+            .enumerate()
+            .find_map(|(i, &(bid, ask))| {
+                let tick = ExnessTick {
+                    bid,
+                    ask,
+                    timestamp_ms: 1_600_000_000_000 + i as i64 * 1_000,
+                };
+                builder.process_tick(&tick).unwrap()
+            })
+            .unwrap()
+            .base;
+
+        let trade = |id: i64, price: &str, is_buyer_maker: bool| rangebar_core::AggTrade {
+            agg_trade_id: id,
+            price: FixedPoint::from_str(price).unwrap(),
+            volume: FixedPoint::from_str("1.0").unwrap(),
+            first_trade_id: id,
+            last_trade_id: id,
+            timestamp: 1_600_000_000_000_000 + id,
+            is_buyer_maker,
+            is_best_match: None,
+        };
+        let binance_bar = RangeBarProcessor::new(250)
+            .unwrap()
+            .process_agg_trade_records(&[trade(1, "50000.0", false), trade(2, "50200.0", true)])
+            .unwrap()
+            .remove(0);
+
+        assert_eq!(exness_bar.data_source, DataSource::Exness);
+        assert!(!exness_bar.direction_known());
+        assert_eq!(exness_bar.buy_trade_count + exness_bar.sell_trade_count, 0);
+
+        assert_eq!(binance_bar.data_source, DataSource::BinanceFuturesUM);
+        assert!(binance_bar.direction_known());
+        assert_eq!(
+            binance_bar.buy_trade_count + binance_bar.sell_trade_count,
+            2
+        );
+    }
 }
@@ -12,7 +12,7 @@ use rangebar_core::types::AggTrade;
 /// Validate tick data
 ///
 /// Validation levels (configurable strictness):
-/// - Permissive: Basic checks (bid > 0, ask > 0, bid < ask)
+/// - Permissive: Basic checks (finite, bid > 0, ask > 0, bid < ask)
 /// - Strict: + Spread < 10% (catches obvious errors) [DEFAULT]
 /// - Paranoid: + Spread < 1% (flags suspicious patterns)
 ///
@@ -32,7 +32,15 @@ pub fn validate_tick(
     tick: &ExnessTick,
     strictness: ValidationStrictness,
 ) -> Result<(), ConversionError> {
-    // Critical checks (all levels)
+    // Critical checks (all levels); NaN would slip past the comparisons below
+    if !tick.bid.is_finite() {
+        return Err(ConversionError::NonFinite { field: "bid" });
+    }
+
+    if !tick.ask.is_finite() {
+        return Err(ConversionError::NonFinite { field: "ask" });
+    }
+
     if tick.bid <= 0.0 {
         return Err(ConversionError::InvalidBid { bid: tick.bid });
     }
@@ -269,6 +277,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_non_finite_quotes() {
+        let nan_bid = ExnessTick {
+            bid: f64::NAN,
+            ask: 1.0815,
+            timestamp_ms: 1_600_000_000_000,
+        };
+        let inf_ask = ExnessTick {
+            bid: 1.0800,
+            ask: f64::INFINITY,
+            timestamp_ms: 1_600_000_000_000,
+        };
+
+        assert!(matches!(
+            tick_to_synthetic_trade(
+                &nan_bid,
+                "EURUSD_Raw_Spread",
+                1,
+                ValidationStrictness::Permissive
+            ),
+            Err(ConversionError::NonFinite { field: "bid" })
+        ));
+        assert!(matches!(
+            validate_tick(&inf_ask, ValidationStrictness::Permissive),
+            Err(ConversionError::NonFinite { field: "ask" })
+        ));
+    }
+
     #[test]
     fn test_invalid_ask() {
         let tick = ExnessTick {
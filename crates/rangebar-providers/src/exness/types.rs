@@ -53,6 +53,10 @@ pub enum ExnessError {
 /// No skipping, no error rate thresholds - strict fail-fast.
 #[derive(Error, Debug)]
 pub enum ConversionError {
+    /// Bid or ask is NaN or infinite (corrupt row)
+    #[error("Non-finite {field}")]
+    NonFinite { field: &'static str },
+
     /// Bid price <= 0 (invalid quote)
     #[error("Invalid bid price: {bid} (must be > 0)")]
     InvalidBid { bid: f64 },
@@ -0,0 +1,544 @@
+//! Column mapping and CSV parsing into AggTrade
+
+use rangebar_core::timestamp::{TimeUnit, TimestampError, normalize_timestamp_with_unit};
+use rangebar_core::{
+    AggTrade, DataSource, FixedPoint, FixedPointParseError, ProcessingError, RangeBarProcessor,
+};
+use std::io::Read;
+use std::path::Path;
+use thiserror::Error;
+
+/// Data source to record on bars built from CSV-loaded trades
+pub const DATA_SOURCE: DataSource = DataSource::GenericCsv;
+
+/// Location of a field in a CSV record
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ColumnRef {
+    /// Header name (requires a header row)
+    Name(String),
+    /// Zero-based column index
+    Index(usize),
+}
+
+impl From<&str> for ColumnRef {
+    fn from(name: &str) -> Self {
+        ColumnRef::Name(name.to_string())
+    }
+}
+
+impl From<usize> for ColumnRef {
+    fn from(index: usize) -> Self {
+        ColumnRef::Index(index)
+    }
+}
+
+/// Where each AggTrade field lives in the CSV
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnMap {
+    pub price: ColumnRef,
+    pub volume: ColumnRef,
+    pub timestamp: ColumnRef,
+    pub side: Option<ColumnRef>,
+    pub trade_id: Option<ColumnRef>,
+}
+
+impl ColumnMap {
+    /// Map the required price, volume and timestamp columns
+    pub fn new(
+        price: impl Into<ColumnRef>,
+        volume: impl Into<ColumnRef>,
+        timestamp: impl Into<ColumnRef>,
+    ) -> Self {
+        Self {
+            price: price.into(),
+            volume: volume.into(),
+            timestamp: timestamp.into(),
+            side: None,
+            trade_id: None,
+        }
+    }
+
+    /// Map the trade direction column
+    pub fn with_side(mut self, side: impl Into<ColumnRef>) -> Self {
+        self.side = Some(side.into());
+        self
+    }
+
+    /// Map the trade ID column
+    pub fn with_trade_id(mut self, trade_id: impl Into<ColumnRef>) -> Self {
+        self.trade_id = Some(trade_id.into());
+        self
+    }
+}
+
+/// Generic CSV loading errors
+#[derive(Error, Debug)]
+pub enum ConversionError {
+    /// File could not be opened or read
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Malformed CSV
+    #[error("CSV error: {0}")]
+    Csv(#[from] csv::Error),
+
+    /// Named column is not present in the header row
+    #[error("Column '{column}' not found in header")]
+    MissingColumn { column: String },
+
+    /// Named column used with `has_headers(false)`
+    #[error("Column '{column}' referenced by name but the CSV has no header row")]
+    NamedColumnWithoutHeader { column: String },
+
+    /// Record is shorter than a mapped column index
+    #[error("Row {row}: no value for {field} (column {index})")]
+    MissingField {
+        row: usize,
+        field: &'static str,
+        index: usize,
+    },
+
+    /// Value could not be parsed for its field
+    #[error("Row {row}: invalid {field} '{value}'")]
+    InvalidValue {
+        row: usize,
+        field: &'static str,
+        value: String,
+    },
+
+    /// Price or volume is not a valid fixed-point decimal
+    #[error("Row {row}: invalid {field}: {source}")]
+    InvalidNumber {
+        row: usize,
+        field: &'static str,
+        #[source]
+        source: FixedPointParseError,
+    },
+
+    /// Price or volume is NaN or infinite (e.g. "NaN", "inf" from f64 exports)
+    #[error("Row {row}: {field} is not finite")]
+    NonFinite { row: usize, field: &'static str },
+
+    /// Timestamp out of range for the configured unit
+    #[error("Row {row}: {source}")]
+    Timestamp {
+        row: usize,
+        #[source]
+        source: TimestampError,
+    },
+}
+
+impl ConversionError {
+    /// Whether the error is confined to a single malformed row
+    ///
+    /// Row-level defects (missing fields, unparseable values, empty or
+    /// non-numeric decimals) can be skipped. Out-of-range or over-precise
+    /// decimals, timestamp range errors and I/O or mapping failures point at
+    /// the file as a whole and should abort the load.
+    pub fn is_skippable(&self) -> bool {
+        match self {
+            ConversionError::MissingField { .. }
+            | ConversionError::InvalidValue { .. }
+            | ConversionError::NonFinite { .. } => true,
+            ConversionError::InvalidNumber { source, .. } => source.is_skippable(),
+            _ => false,
+        }
+    }
+}
+
+/// Whether `value` is a float spelling of NaN or ±infinity
+fn is_non_finite(value: &str) -> bool {
+    value.parse::<f64>().is_ok_and(|v| !v.is_finite())
+}
+
+/// Column map with every reference resolved to an index
+struct ResolvedColumns {
+    price: usize,
+    volume: usize,
+    timestamp: usize,
+    side: Option<usize>,
+    trade_id: Option<usize>,
+}
+
+/// Loader for CSV trade files with a caller-supplied [`ColumnMap`]
+#[derive(Debug, Clone)]
+pub struct GenericCsvLoader {
+    time_unit: TimeUnit,
+    side_is_maker: bool,
+    has_headers: bool,
+    delimiter: u8,
+}
+
+impl Default for GenericCsvLoader {
+    fn default() -> Self {
+        Self {
+            time_unit: TimeUnit::Millis,
+            side_is_maker: false,
+            has_headers: true,
+            delimiter: b',',
+        }
+    }
+}
+
+impl GenericCsvLoader {
+    /// Comma-delimited with a header row and millisecond timestamps
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Unit of the timestamp column (default milliseconds)
+    pub fn with_time_unit(mut self, time_unit: TimeUnit) -> Self {
+        self.time_unit = time_unit;
+        self
+    }
+
+    /// Interpret the side column as the maker side instead of the taker side
+    pub fn with_side_is_maker(mut self, side_is_maker: bool) -> Self {
+        self.side_is_maker = side_is_maker;
+        self
+    }
+
+    /// Whether the first row is a header (default true)
+    pub fn with_headers(mut self, has_headers: bool) -> Self {
+        self.has_headers = has_headers;
+        self
+    }
+
+    /// Field delimiter (default `,`)
+    pub fn with_delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Range bar processor that records [`DATA_SOURCE`] on every bar
+    pub fn processor(
+        &self,
+        threshold_decimal_bps: u32,
+    ) -> Result<RangeBarProcessor, ProcessingError> {
+        Ok(RangeBarProcessor::new(threshold_decimal_bps)?.with_data_source(DATA_SOURCE))
+    }
+
+    /// Load trades from a CSV file, in file order
+    pub fn load(
+        &self,
+        path: impl AsRef<Path>,
+        map: &ColumnMap,
+    ) -> Result<Vec<AggTrade>, ConversionError> {
+        self.load_from_reader(std::fs::File::open(path)?, map)
+    }
+
+    /// Load trades from any CSV source, in input order
+    pub fn load_from_reader(
+        &self,
+        reader: impl Read,
+        map: &ColumnMap,
+    ) -> Result<Vec<AggTrade>, ConversionError> {
+        let mut reader = csv::ReaderBuilder::new()
+            .has_headers(self.has_headers)
+            .delimiter(self.delimiter)
+            .from_reader(reader);
+
+        let headers = if self.has_headers {
+            Some(reader.headers()?.clone())
+        } else {
+            None
+        };
+        let columns = resolve_columns(map, headers.as_ref())?;
+
+        let mut trades = Vec::new();
+        for (offset, record) in reader.records().enumerate() {
+            let row = offset + 1;
+            trades.push(self.parse_record(&record?, row, &columns)?);
+        }
+        Ok(trades)
+    }
+
+    fn parse_record(
+        &self,
+        record: &csv::StringRecord,
+        row: usize,
+        columns: &ResolvedColumns,
+    ) -> Result<AggTrade, ConversionError> {
+        let field = |name: &'static str, index: usize| {
+            record
+                .get(index)
+                .map(str::trim)
+                .ok_or(ConversionError::MissingField {
+                    row,
+                    field: name,
+                    index,
+                })
+        };
+        let invalid = |name: &'static str, value: &str| ConversionError::InvalidValue {
+            row,
+            field: name,
+            value: value.to_string(),
+        };
+
+        let decimal = |name: &'static str, index: usize| {
+            let value = field(name, index)?;
+            FixedPoint::from_str(value).map_err(|source| {
+                if is_non_finite(value) {
+                    ConversionError::NonFinite { row, field: name }
+                } else {
+                    ConversionError::InvalidNumber {
+                        row,
+                        field: name,
+                        source,
+                    }
+                }
+            })
+        };
+
+        let price = decimal("price", columns.price)?;
+        let volume = decimal("volume", columns.volume)?;
+
+        let timestamp_str = field("timestamp", columns.timestamp)?;
+        let raw_timestamp: i64 = timestamp_str
+            .parse()
+            .map_err(|_| invalid("timestamp", timestamp_str))?;
+        let timestamp = normalize_timestamp_with_unit(raw_timestamp, self.time_unit)
+            .map_err(|source| ConversionError::Timestamp { row, source })?;
+
+        let is_buyer_maker = match columns.side {
+            Some(index) => {
+                let side = field("side", index)?;
+                let is_sell = match side.to_ascii_lowercase().as_str() {
+                    "buy" | "b" => false,
+                    "sell" | "s" => true,
+                    _ => return Err(invalid("side", side)),
+                };
+                // Taker sell => buyer was maker; a maker-side column flips that
+                is_sell != self.side_is_maker
+            }
+            None => false,
+        };
+
+        let trade_id = match columns.trade_id {
+            Some(index) => {
+                let id = field("trade_id", index)?;
+                id.parse().map_err(|_| invalid("trade_id", id))?
+            }
+            None => row as i64,
+        };
+
+        Ok(AggTrade {
+            agg_trade_id: trade_id,
+            price,
+            volume,
+            first_trade_id: trade_id,
+            last_trade_id: trade_id,
+            timestamp,
+            is_buyer_maker,
+            is_best_match: None,
+        })
+    }
+}
+
+fn resolve_columns(
+    map: &ColumnMap,
+    headers: Option<&csv::StringRecord>,
+) -> Result<ResolvedColumns, ConversionError> {
+    let resolve = |column: &ColumnRef| match column {
+        ColumnRef::Index(index) => Ok(*index),
+        ColumnRef::Name(name) => {
+            let headers = headers.ok_or_else(|| ConversionError::NamedColumnWithoutHeader {
+                column: name.clone(),
+            })?;
+            headers
+                .iter()
+                .position(|header| header.trim() == name)
+                .ok_or_else(|| ConversionError::MissingColumn {
+                    column: name.clone(),
+                })
+        }
+    };
+
+    Ok(ResolvedColumns {
+        price: resolve(&map.price)?,
+        volume: resolve(&map.volume)?,
+        timestamp: resolve(&map.timestamp)?,
+        side: map.side.as_ref().map(resolve).transpose()?,
+        trade_id: map.trade_id.as_ref().map(resolve).transpose()?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Comparable projection of an AggTrade
+    fn key(trade: &AggTrade) -> (i64, i64, i64, i64, bool) {
+        (
+            trade.agg_trade_id,
+            trade.price.0,
+            trade.volume.0,
+            trade.timestamp,
+            trade.is_buyer_maker,
+        )
+    }
+
+    #[test]
+    fn test_different_layouts_produce_identical_trades() {
+        let by_name = "id,px,qty,ts,taker\n\
+                       1,50000.5,0.25,1705276800123,buy\n\
+                       2,50001.0,1.5,1705276800456,SELL\n";
+        let named_map = ColumnMap::new("px", "qty", "ts")
+            .with_side("taker")
+            .with_trade_id("id");
+        let named = GenericCsvLoader::new()
+            .load_from_reader(by_name.as_bytes(), &named_map)
+            .unwrap();
+
+        // Reordered columns, microsecond timestamps, maker-side direction,
+        // semicolon delimiter and no header row
+        let by_index = "1705276800123000;s;0.25;1;50000.5\n\
+                        1705276800456000;b;1.5;2;50001.0\n";
+        let index_map = ColumnMap::new(4, 2, 0).with_side(1).with_trade_id(3);
+        let indexed = GenericCsvLoader::new()
+            .with_headers(false)
+            .with_delimiter(b';')
+            .with_time_unit(TimeUnit::Micros)
+            .with_side_is_maker(true)
+            .load_from_reader(by_index.as_bytes(), &index_map)
+            .unwrap();
+
+        assert_eq!(named.len(), 2);
+        assert_eq!(
+            named.iter().map(key).collect::<Vec<_>>(),
+            indexed.iter().map(key).collect::<Vec<_>>()
+        );
+        assert_eq!(named[0].timestamp, 1_705_276_800_123_000);
+        assert!(!named[0].is_buyer_maker);
+        assert!(named[1].is_buyer_maker);
+    }
+
+    #[test]
+    fn test_load_from_path_and_errors() {
+        let path =
+            std::env::temp_dir().join(format!("rangebar_generic_csv_{}.csv", std::process::id()));
+        std::fs::write(&path, "price,volume,time\n100.5,2,1705276800\n").unwrap();
+
+        let map = ColumnMap::new("price", "volume", "time");
+        let trades = GenericCsvLoader::new()
+            .with_time_unit(TimeUnit::Seconds)
+            .load(&path, &map)
+            .unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(trades[0].agg_trade_id, 1);
+        assert_eq!(trades[0].timestamp, 1_705_276_800_000_000);
+
+        let missing = ColumnMap::new("price", "size", "time");
+        assert!(matches!(
+            GenericCsvLoader::new().load_from_reader("price,volume,time\n".as_bytes(), &missing),
+            Err(ConversionError::MissingColumn { .. })
+        ));
+
+        let bad_side = ColumnMap::new(0, 1, 2).with_side(3);
+        assert!(matches!(
+            GenericCsvLoader::new()
+                .with_headers(false)
+                .load_from_reader("1.0,1.0,1705276800000,hold\n".as_bytes(), &bad_side),
+            Err(ConversionError::InvalidValue {
+                row: 1,
+                field: "side",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_non_finite_rows_are_rejected() {
+        let map = ColumnMap::new(0, 1, 2);
+        let load = |csv: &str| {
+            GenericCsvLoader::new()
+                .with_headers(false)
+                .load_from_reader(csv.as_bytes(), &map)
+        };
+
+        let nan_price = load("100.0,1.0,1705276800000\nNaN,1.0,1705276800001\n").unwrap_err();
+        assert!(matches!(
+            nan_price,
+            ConversionError::NonFinite {
+                row: 2,
+                field: "price"
+            }
+        ));
+        assert!(nan_price.is_skippable());
+
+        for volume in ["inf", "-Infinity"] {
+            assert!(matches!(
+                load(&format!("100.0,{volume},1705276800000\n")),
+                Err(ConversionError::NonFinite {
+                    row: 1,
+                    field: "volume"
+                })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_number_errors_are_classified() {
+        let map = ColumnMap::new(0, 1, 2);
+        let load = |csv: &str| {
+            GenericCsvLoader::new()
+                .with_headers(false)
+                .load_from_reader(csv.as_bytes(), &map)
+                .unwrap_err()
+        };
+
+        let empty = load("100.0,,1705276800000\n");
+        assert!(matches!(
+            empty,
+            ConversionError::InvalidNumber {
+                row: 1,
+                field: "volume",
+                source: FixedPointParseError::Empty,
+            }
+        ));
+        assert!(empty.is_skippable());
+
+        let garbage = load("100.0,1.0,1705276800000\nn/a,1.0,1705276800001\n");
+        assert!(matches!(
+            garbage,
+            ConversionError::InvalidNumber {
+                row: 2,
+                field: "price",
+                source: FixedPointParseError::NonNumeric { .. },
+            }
+        ));
+        assert!(garbage.is_skippable());
+
+        let too_large = load("123456789012345,1.0,1705276800000\n");
+        assert!(matches!(
+            too_large,
+            ConversionError::InvalidNumber {
+                source: FixedPointParseError::OutOfRange { .. },
+                ..
+            }
+        ));
+        assert!(!too_large.is_skippable());
+    }
+
+    #[test]
+    fn test_processor_stamps_generic_csv_data_source() {
+        let csv = "price,volume,timestamp\n\
+                   50000.0,1.0,1705276800000\n\
+                   50200.0,1.0,1705276800100\n";
+        let loader = GenericCsvLoader::new();
+        let trades = loader
+            .load_from_reader(
+                csv.as_bytes(),
+                &ColumnMap::new("price", "volume", "timestamp"),
+            )
+            .unwrap();
+
+        let bars = loader
+            .processor(250)
+            .unwrap()
+            .process_agg_trade_records(&trades)
+            .unwrap();
+        assert_eq!(bars.len(), 1);
+        assert_eq!(bars[0].data_source, DataSource::GenericCsv);
+    }
+}
@@ -0,0 +1,38 @@
+//! Generic CSV trade loader with configurable column mapping
+//!
+//! Loads in-house tick data in arbitrary CSV layouts without writing a
+//! dedicated provider. Describe where each field lives with a [`ColumnMap`],
+//! by header name or zero-based index, and configure timestamp unit and side
+//! semantics on the [`GenericCsvLoader`].
+//!
+//! ## Quick Start
+//!
+//! ```rust,no_run
+//! use rangebar_core::TimeUnit;
+//! use rangebar_providers::generic_csv::{ColumnMap, GenericCsvLoader};
+//!
+//! # fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let map = ColumnMap::new("px", "qty", "ts")
+//!     .with_side("taker_side")
+//!     .with_trade_id("id");
+//! let trades = GenericCsvLoader::new()
+//!     .with_time_unit(TimeUnit::Millis)
+//!     .load("ticks.csv", &map)?;
+//! println!("Loaded {} trades", trades.len());
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Field Semantics
+//!
+//! - `price`, `volume`: decimal strings, up to 8 decimal places
+//! - `timestamp`: integer in the loader's [`TimeUnit`](rangebar_core::TimeUnit),
+//!   normalized to microseconds
+//! - `side` (optional): `buy`/`b` or `sell`/`s`, case-insensitive. By default
+//!   this is the taker side; set `side_is_maker` when it names the maker side.
+//!   Without a side column `is_buyer_maker` is `false`.
+//! - `trade_id` (optional): integer; defaults to the 1-based data row number
+
+pub mod loader;
+
+pub use loader::{ColumnMap, ColumnRef, ConversionError, DATA_SOURCE, GenericCsvLoader};
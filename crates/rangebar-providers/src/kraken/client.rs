@@ -0,0 +1,210 @@
+//! Kraken `Trades` REST client with cursor pagination
+
+use super::conversion::DATA_SOURCE;
+use super::types::{KrakenError, KrakenTrade, KrakenTradesPage};
+use crate::rate_limit::RateLimiter;
+use rangebar_core::{ProcessingError, RangeBarProcessor};
+use reqwest::Client;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::time::Duration;
+
+const DEFAULT_BASE_URL: &str = "https://api.kraken.com";
+
+/// Public endpoints allow roughly one call per second
+const DEFAULT_PAGE_DELAY: Duration = Duration::from_secs(1);
+
+/// Raw `Trades` response envelope
+#[derive(Debug, Deserialize)]
+struct TradesResponse {
+    error: Vec<String>,
+    #[serde(default)]
+    result: Option<HashMap<String, Value>>,
+}
+
+/// Kraken public trade history client
+pub struct KrakenClient {
+    client: Client,
+    pair: String,
+    base_url: String,
+    page_delay: Duration,
+    rate_limiter: Option<RateLimiter>,
+}
+
+impl KrakenClient {
+    /// Create a client for a Kraken pair (e.g. `XBTUSD`, `ETHUSD`)
+    pub fn new(pair: &str) -> Self {
+        const KRAKEN_REQUEST_TIMEOUT_SECS: u64 = 30;
+
+        Self {
+            client: Client::builder()
+                .timeout(Duration::from_secs(KRAKEN_REQUEST_TIMEOUT_SECS))
+                .build()
+                .expect("Failed to build Kraken HTTP client"),
+            pair: pair.to_uppercase(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            page_delay: DEFAULT_PAGE_DELAY,
+            rate_limiter: None,
+        }
+    }
+
+    /// Override the API host (mirrors, test servers)
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Delay between consecutive page requests (default 1s)
+    pub fn with_page_delay(mut self, page_delay: Duration) -> Self {
+        self.page_delay = page_delay;
+        self
+    }
+
+    /// Wait for a permit from `rate_limiter` before every page request
+    ///
+    /// Applies on top of the page delay; set that to zero to pace requests by
+    /// the limiter alone.
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Range bar processor that records [`DATA_SOURCE`] on every bar
+    pub fn processor(
+        &self,
+        threshold_decimal_bps: u32,
+    ) -> Result<RangeBarProcessor, ProcessingError> {
+        Ok(RangeBarProcessor::new(threshold_decimal_bps)?.with_data_source(DATA_SOURCE))
+    }
+
+    /// Fetch one page of trades starting at the `since` cursor (nanoseconds)
+    pub async fn fetch_page(&self, since: &str) -> Result<KrakenTradesPage, KrakenError> {
+        let url = format!(
+            "{}/0/public/Trades?pair={}&since={}",
+            self.base_url, self.pair, since
+        );
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.acquire().await;
+        }
+        let body = self.client.get(&url).send().await?.text().await?;
+        parse_trades_page(&body)
+    }
+
+    /// Fetch up to `max_pages` pages from `since_ns`, deduplicated on trade ID
+    ///
+    /// Stops early when a page is empty or the cursor stops advancing.
+    pub async fn fetch_trades(
+        &self,
+        since_ns: u64,
+        max_pages: usize,
+    ) -> Result<Vec<KrakenTrade>, KrakenError> {
+        let mut trades = Vec::new();
+        let mut cursor = since_ns.to_string();
+
+        for page_number in 0..max_pages {
+            if page_number > 0 {
+                tokio::time::sleep(self.page_delay).await;
+            }
+
+            let page = self.fetch_page(&cursor).await?;
+            if page.trades.is_empty() || page.last == cursor {
+                break;
+            }
+            append_deduplicated(&mut trades, page.trades);
+            cursor = page.last;
+        }
+
+        Ok(trades)
+    }
+}
+
+/// Parse a `Trades` response body into a page
+pub(crate) fn parse_trades_page(body: &str) -> Result<KrakenTradesPage, KrakenError> {
+    let response: TradesResponse = serde_json::from_str(body)?;
+    if !response.error.is_empty() {
+        return Err(KrakenError::Api(response.error));
+    }
+
+    let mut result = response
+        .result
+        .ok_or(KrakenError::MalformedResponse("missing result"))?;
+    let last = match result.remove("last") {
+        Some(Value::String(last)) => last,
+        Some(Value::Number(last)) => last.to_string(),
+        _ => return Err(KrakenError::MalformedResponse("missing last cursor")),
+    };
+    // The only remaining key is the (normalized) pair name, e.g. XXBTZUSD
+    let trades = result
+        .into_values()
+        .next()
+        .ok_or(KrakenError::MalformedResponse("missing trade array"))?;
+
+    Ok(KrakenTradesPage {
+        trades: serde_json::from_value(trades)?,
+        last,
+    })
+}
+
+/// Append `page`, skipping trades already covered by the previous page
+fn append_deduplicated(trades: &mut Vec<KrakenTrade>, page: Vec<KrakenTrade>) {
+    let last_id = trades.last().map_or(i64::MIN, |trade| trade.trade_id);
+    trades.extend(page.into_iter().filter(|trade| trade.trade_id > last_id));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_page_and_deduplicate_overlap() {
+        let first = parse_trades_page(
+            r#"{"error":[],"result":{"XXBTZUSD":[
+                ["30000.0","0.1",1688669448.1,"b","m","",100],
+                ["30001.0","0.2",1688669448.2,"s","l","",101]
+            ],"last":"1688669448200000000"}}"#,
+        )
+        .unwrap();
+        assert_eq!(first.last, "1688669448200000000");
+
+        let second = parse_trades_page(
+            r#"{"error":[],"result":{"XXBTZUSD":[
+                ["30001.0","0.2",1688669448.2,"s","l","",101],
+                ["30002.0","0.3",1688669448.3,"b","m","",102]
+            ],"last":"1688669448300000000"}}"#,
+        )
+        .unwrap();
+
+        let mut trades = Vec::new();
+        append_deduplicated(&mut trades, first.trades);
+        append_deduplicated(&mut trades, second.trades);
+        let ids: Vec<i64> = trades.iter().map(|t| t.trade_id).collect();
+        assert_eq!(ids, vec![100, 101, 102]);
+    }
+
+    #[test]
+    fn test_api_error_is_surfaced() {
+        let err = parse_trades_page(r#"{"error":["EQuery:Unknown asset pair"]}"#).unwrap_err();
+        assert!(matches!(err, KrakenError::Api(ref messages) if messages.len() == 1));
+    }
+
+    #[test]
+    fn test_processor_stamps_kraken_data_source() {
+        let page = parse_trades_page(
+            r#"{"error":[],"result":{"XXBTZUSD":[
+                ["30000.0","0.1",1688669448.1,"b","m","",100],
+                ["30100.0","0.2",1688669448.2,"s","l","",101]
+            ],"last":"1688669448200000000"}}"#,
+        )
+        .unwrap();
+        let trades = crate::kraken::trades_to_agg_trades(&page.trades).unwrap();
+
+        let bars = KrakenClient::new("XBTUSD")
+            .processor(250)
+            .unwrap()
+            .process_agg_trade_records(&trades)
+            .unwrap();
+        assert_eq!(bars.len(), 1);
+        assert_eq!(bars[0].data_source, rangebar_core::DataSource::Kraken);
+    }
+}
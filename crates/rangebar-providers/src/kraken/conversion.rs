@@ -0,0 +1,86 @@
+//! Conversion of Kraken trades to AggTrade
+
+use super::types::{KrakenError, KrakenSide, KrakenTrade};
+use rangebar_core::fixed_point::FixedPoint;
+use rangebar_core::types::{AggTrade, DataSource};
+
+/// Data source to record on bars built from Kraken trades
+pub const DATA_SOURCE: DataSource = DataSource::Kraken;
+
+/// Convert a Kraken trade to AggTrade
+///
+/// - `is_buyer_maker` is true for taker sells (`"s"`): the resting buyer was the maker
+/// - `time` (fractional seconds) becomes microseconds, the core timestamp unit
+/// - `trade_id` fills `agg_trade_id`, `first_trade_id` and `last_trade_id`
+pub fn trade_to_agg_trade(trade: &KrakenTrade) -> Result<AggTrade, KrakenError> {
+    let parse = |field: &'static str, value: &str| {
+        FixedPoint::from_str(value).map_err(|source| KrakenError::InvalidNumber {
+            field,
+            value: value.to_string(),
+            trade_id: trade.trade_id,
+            source,
+        })
+    };
+
+    if !trade.time.is_finite() || trade.time < 0.0 {
+        return Err(KrakenError::InvalidTime {
+            time: trade.time,
+            trade_id: trade.trade_id,
+        });
+    }
+
+    Ok(AggTrade {
+        agg_trade_id: trade.trade_id,
+        price: parse("price", &trade.price)?,
+        volume: parse("volume", &trade.volume)?,
+        first_trade_id: trade.trade_id,
+        last_trade_id: trade.trade_id,
+        timestamp: (trade.time * 1_000_000.0).round() as i64,
+        is_buyer_maker: trade.side == KrakenSide::Sell,
+        is_best_match: None,
+    })
+}
+
+/// Convert a batch of Kraken trades, failing on the first invalid trade
+pub fn trades_to_agg_trades(trades: &[KrakenTrade]) -> Result<Vec<AggTrade>, KrakenError> {
+    trades.iter().map(trade_to_agg_trade).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kraken::types::KrakenOrderType;
+
+    #[test]
+    fn test_trade_tuple_to_agg_trade() {
+        let json = r#"[
+            ["30243.40000","0.34507674",1688669448.2945,"b","m","",61049987],
+            ["30243.30000","0.00100000",1688669449.5,"s","l","",61049988]
+        ]"#;
+        let trades: Vec<KrakenTrade> = serde_json::from_str(json).unwrap();
+        assert_eq!(trades[0].side, KrakenSide::Buy);
+        assert_eq!(trades[1].order_type, KrakenOrderType::Limit);
+
+        let agg = trades_to_agg_trades(&trades).unwrap();
+        assert_eq!(agg[0].agg_trade_id, 61049987);
+        assert_eq!(agg[0].first_trade_id, agg[0].last_trade_id);
+        assert_eq!(agg[0].price.to_string(), "30243.40000000");
+        assert_eq!(agg[0].volume.to_string(), "0.34507674");
+        assert_eq!(agg[0].timestamp, 1_688_669_448_294_500);
+        assert!(!agg[0].is_buyer_maker);
+        assert!(agg[1].is_buyer_maker);
+        assert_eq!(agg[1].timestamp, 1_688_669_449_500_000);
+    }
+
+    #[test]
+    fn test_invalid_price_is_rejected() {
+        let json = r#"["abc","1.0",1688669448.0,"b","m","",1]"#;
+        let trade: KrakenTrade = serde_json::from_str(json).unwrap();
+        let err = trade_to_agg_trade(&trade).unwrap_err();
+        assert!(matches!(
+            err,
+            KrakenError::InvalidNumber { field: "price", .. }
+        ));
+        assert!(err.is_skippable());
+    }
+}
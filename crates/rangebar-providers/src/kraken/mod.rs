@@ -0,0 +1,47 @@
+//! Kraken public trade history
+//!
+//! Fetches raw trades from Kraken's public `Trades` REST endpoint and converts
+//! them to [`AggTrade`](rangebar_core::AggTrade) for range bar construction.
+//! Kraken publishes individual trades (no aggregation), so each trade maps to
+//! one AggTrade with `first_trade_id == last_trade_id == trade_id`.
+//!
+//! ## Quick Start
+//!
+//! ```rust,no_run
+//! use rangebar_providers::kraken::{KrakenClient, trades_to_agg_trades};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = KrakenClient::new("XBTUSD");
+//! let trades = client.fetch_trades(1_700_000_000_000_000_000, 10).await?;
+//! let agg_trades = trades_to_agg_trades(&trades)?;
+//! println!("Fetched {} Kraken trades", agg_trades.len());
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Data Format
+//!
+//! **API**: `https://api.kraken.com/0/public/Trades?pair={PAIR}&since={CURSOR}`
+//!
+//! Each trade is a JSON array:
+//! `[price, volume, time, buy/sell, market/limit, misc, trade_id]`
+//!
+//! - `price`, `volume`: decimal strings
+//! - `time`: Unix seconds with fractional part
+//! - `buy/sell`: `"b"` or `"s"`, the taker side
+//! - `trade_id`: monotonically increasing integer
+//!
+//! ## Pagination
+//!
+//! Responses carry a `last` cursor (nanoseconds) to pass as the next `since`.
+//! Consecutive pages can overlap at the boundary, so trades are deduplicated on
+//! `trade_id` while paging.
+
+pub mod client;
+pub mod conversion;
+pub mod types;
+
+// Re-export main types for convenience
+pub use client::KrakenClient;
+pub use conversion::{DATA_SOURCE, trade_to_agg_trade, trades_to_agg_trades};
+pub use types::{KrakenError, KrakenOrderType, KrakenSide, KrakenTrade, KrakenTradesPage};
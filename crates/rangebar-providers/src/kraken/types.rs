@@ -0,0 +1,102 @@
+//! Kraken trade payloads and errors
+
+use rangebar_core::FixedPointParseError;
+use serde::Deserialize;
+use thiserror::Error;
+
+/// Taker side of a Kraken trade
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum KrakenSide {
+    /// Taker bought (lifted the ask)
+    #[serde(rename = "b")]
+    Buy,
+    /// Taker sold (hit the bid)
+    #[serde(rename = "s")]
+    Sell,
+}
+
+/// Order type of the taker
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum KrakenOrderType {
+    #[serde(rename = "m")]
+    Market,
+    #[serde(rename = "l")]
+    Limit,
+}
+
+/// Single Kraken trade, deserialized from the positional array
+/// `[price, volume, time, buy/sell, market/limit, misc, trade_id]`
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct KrakenTrade {
+    /// Price as a decimal string
+    pub price: String,
+    /// Volume as a decimal string
+    pub volume: String,
+    /// Unix time in seconds with fractional part
+    pub time: f64,
+    /// Taker side
+    pub side: KrakenSide,
+    /// Taker order type
+    pub order_type: KrakenOrderType,
+    /// Miscellaneous flags
+    pub misc: String,
+    /// Monotonically increasing trade ID
+    pub trade_id: i64,
+}
+
+/// One page of the `Trades` endpoint
+#[derive(Debug, Clone, PartialEq)]
+pub struct KrakenTradesPage {
+    /// Trades in this page, oldest first
+    pub trades: Vec<KrakenTrade>,
+    /// Cursor (nanoseconds) to pass as `since` for the next page
+    pub last: String,
+}
+
+/// Kraken provider errors
+#[derive(Error, Debug)]
+pub enum KrakenError {
+    /// HTTP request failure
+    #[error("HTTP error: {0}")]
+    Http(#[from] reqwest::Error),
+
+    /// Response body is not the expected JSON shape
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    /// Kraken returned a non-empty `error` array
+    #[error("Kraken API error: {}", .0.join(", "))]
+    Api(Vec<String>),
+
+    /// Response `result` lacked the trade array or `last` cursor
+    #[error("Malformed Trades response: {0}")]
+    MalformedResponse(&'static str),
+
+    /// Price or volume string could not be parsed as fixed-point
+    #[error("Invalid {field} '{value}' in trade {trade_id}: {source}")]
+    InvalidNumber {
+        field: &'static str,
+        value: String,
+        trade_id: i64,
+        #[source]
+        source: FixedPointParseError,
+    },
+
+    /// Trade time is negative or not finite
+    #[error("Invalid time {time} in trade {trade_id}")]
+    InvalidTime { time: f64, trade_id: i64 },
+}
+
+impl KrakenError {
+    /// Whether the error concerns one trade that can be dropped from a page
+    ///
+    /// Empty or non-numeric values and bad times are per-trade defects;
+    /// out-of-range or over-precise decimals and transport errors are fatal.
+    pub fn is_skippable(&self) -> bool {
+        match self {
+            KrakenError::InvalidNumber { source, .. } => source.is_skippable(),
+            KrakenError::InvalidTime { .. } => true,
+            _ => false,
+        }
+    }
+}
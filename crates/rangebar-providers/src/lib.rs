@@ -6,6 +6,12 @@
 //!
 //! - `binance` - Binance spot and futures markets (primary - crypto)
 //! - `exness` - Exness EURUSD Standard tick data (primary - forex)
+//! - `kraken` - Kraken public trade history (crypto)
+//! - `okx` - OKX spot and swap daily trade archives (crypto)
+//! - `generic_csv` - In-house CSV tick data with a configurable column mapping
+//!
+//! [`HistoricalProvider`] abstracts over the archive-backed providers for
+//! callers that fetch one UTC day at a time.
 //!
 //! ## Provider Selection
 //!
@@ -57,12 +63,25 @@
 //! use rangebar_providers::exness::ExnessFetcher;
 //! ```
 
+pub mod provider;
+
+pub mod rate_limit;
+
 #[cfg(feature = "binance")]
 pub mod binance;
 
 #[cfg(feature = "exness")]
 pub mod exness;
 
+#[cfg(feature = "kraken")]
+pub mod kraken;
+
+#[cfg(feature = "okx")]
+pub mod okx;
+
+#[cfg(feature = "generic-csv")]
+pub mod generic_csv;
+
 // ============================================================================
 // Public API Re-exports
 // ============================================================================
@@ -74,12 +93,27 @@ pub mod exness;
 //
 // Both paths are supported and equivalent.
 
+// Provider-agnostic fetch interface
+pub use provider::{HistoricalProvider, ProviderError};
+
+// Request pacing shared across providers
+pub use rate_limit::{RateLimitError, RateLimiter};
+
+#[cfg(feature = "binance")]
+pub use provider::BinanceProvider;
+
+#[cfg(feature = "exness")]
+pub use provider::ExnessProvider;
+
 // Binance provider re-exports (alphabetically sorted)
 // Includes: historical data loading, Tier-1 symbol discovery, WebSocket streaming
 #[cfg(feature = "binance")]
 pub use binance::{
-    BinanceWebSocketStream, CsvAggTrade, HistoricalDataLoader, TIER1_SYMBOLS, WebSocketError,
-    detect_csv_headers, get_tier1_symbols, get_tier1_usdt_pairs, is_tier1_symbol, python_bool,
+    BackfillFn, BinanceCombinedStream, BinanceWebSocketStream, CsvAggTrade, DataError,
+    HistoricalDataLoader, InvalidRowPolicy, MarketType, ReconnectPolicy, StreamMetrics,
+    SymbolError, TIER1_SYMBOLS, WebSocketError, detect_csv_headers, get_tier1_symbols,
+    get_tier1_usdt_pairs, is_tier1_base, is_tier1_symbol, python_bool, refresh_tier1_symbols,
+    refresh_tier1_symbols_or_fallback,
 };
 
 // Exness provider re-exports (alphabetically sorted)
@@ -89,3 +123,17 @@ pub use exness::{
     ConversionError, ExnessError, ExnessFetcher, ExnessInstrument, ExnessRangeBar,
     ExnessRangeBarBuilder, ExnessTick, SpreadStats, ValidationStrictness,
 };
+
+// Kraken provider re-exports (alphabetically sorted)
+#[cfg(feature = "kraken")]
+pub use kraken::{
+    KrakenClient, KrakenError, KrakenOrderType, KrakenSide, KrakenTrade, KrakenTradesPage,
+};
+
+// OKX provider re-exports (alphabetically sorted)
+#[cfg(feature = "okx")]
+pub use okx::{InstType, OkxClient, OkxError, OkxSide, OkxTrade};
+
+// Generic CSV re-exports (alphabetically sorted)
+#[cfg(feature = "generic-csv")]
+pub use generic_csv::{ColumnMap, ColumnRef, GenericCsvLoader};
@@ -0,0 +1,139 @@
+//! OKX daily trade archive fetcher
+
+use super::conversion::trades_to_agg_trades;
+use super::types::{InstType, OkxError, OkxTrade};
+use chrono::NaiveDate;
+use rangebar_core::{AggTrade, FixedPoint, ProcessingError, RangeBarProcessor};
+use reqwest::Client;
+use std::io::{Cursor, Read};
+use std::time::Duration;
+use zip::ZipArchive;
+
+const DEFAULT_BASE_URL: &str = "https://www.okx.com/cdn/okex/traderecords/trades/daily";
+
+/// OKX historical trade fetcher for one instrument
+pub struct OkxClient {
+    client: Client,
+    inst_id: String,
+    inst_type: InstType,
+    base_url: String,
+    contract_multiplier: Option<FixedPoint>,
+}
+
+impl OkxClient {
+    /// Create a fetcher for an instrument ID (`BTC-USDT`, `BTC-USDT-SWAP`)
+    ///
+    /// The instrument type is inferred from the ID.
+    pub fn new(inst_id: &str) -> Self {
+        const OKX_REQUEST_TIMEOUT_SECS: u64 = 60;
+
+        let inst_id = inst_id.to_uppercase();
+        Self {
+            client: Client::builder()
+                .timeout(Duration::from_secs(OKX_REQUEST_TIMEOUT_SECS))
+                .build()
+                .expect("Failed to build OKX HTTP client"),
+            inst_type: InstType::from_inst_id(&inst_id),
+            inst_id,
+            base_url: DEFAULT_BASE_URL.to_string(),
+            contract_multiplier: None,
+        }
+    }
+
+    /// Base-asset size of one contract, applied to swap sizes during conversion
+    ///
+    /// Ignored for spot instruments.
+    pub fn with_contract_multiplier(mut self, contract_multiplier: FixedPoint) -> Self {
+        self.contract_multiplier = Some(contract_multiplier);
+        self
+    }
+
+    /// Override the archive host (mirrors, local caches)
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Instrument type inferred from the ID
+    pub fn inst_type(&self) -> InstType {
+        self.inst_type
+    }
+
+    /// Range bar processor that records this instrument's type on every bar
+    pub fn processor(
+        &self,
+        threshold_decimal_bps: u32,
+    ) -> Result<RangeBarProcessor, ProcessingError> {
+        Ok(RangeBarProcessor::new(threshold_decimal_bps)?
+            .with_data_source(self.inst_type.data_source()))
+    }
+
+    /// Daily archive URL for `date`
+    pub fn daily_archive_url(&self, date: NaiveDate) -> String {
+        format!(
+            "{}/{}/{}-trades-{}.zip",
+            self.base_url,
+            date.format("%Y%m%d"),
+            self.inst_id,
+            date.format("%Y-%m-%d")
+        )
+    }
+
+    /// Download and parse one day of raw trades
+    pub async fn fetch_day(&self, date: NaiveDate) -> Result<Vec<OkxTrade>, OkxError> {
+        let url = self.daily_archive_url(date);
+        let response = self.client.get(&url).send().await?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(OkxError::HttpStatus {
+                status: status.as_u16(),
+                url,
+            });
+        }
+        let bytes = response.bytes().await?;
+
+        let mut archive = ZipArchive::new(Cursor::new(bytes))?;
+        let mut csv_content = String::new();
+        archive.by_index(0)?.read_to_string(&mut csv_content)?;
+
+        let mut reader = csv::ReaderBuilder::new()
+            .has_headers(true)
+            .from_reader(csv_content.as_bytes());
+        let mut trades = Vec::new();
+        for result in reader.deserialize() {
+            trades.push(result?);
+        }
+        Ok(trades)
+    }
+
+    /// Download one day and convert to AggTrade, sorted by (timestamp, trade ID)
+    pub async fn fetch_day_agg_trades(&self, date: NaiveDate) -> Result<Vec<AggTrade>, OkxError> {
+        let trades = self.fetch_day(date).await?;
+        let multiplier = match self.inst_type {
+            InstType::Swap => self.contract_multiplier,
+            InstType::Spot => None,
+        };
+        let mut agg_trades = trades_to_agg_trades(&trades, multiplier)?;
+        agg_trades.sort_by_key(|trade| (trade.timestamp, trade.agg_trade_id));
+        Ok(agg_trades)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_daily_archive_url_and_type() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+
+        let spot = OkxClient::new("btc-usdt");
+        assert_eq!(spot.inst_type(), InstType::Spot);
+        assert_eq!(
+            spot.daily_archive_url(date),
+            "https://www.okx.com/cdn/okex/traderecords/trades/daily/20240115/BTC-USDT-trades-2024-01-15.zip"
+        );
+
+        assert_eq!(OkxClient::new("BTC-USDT-SWAP").inst_type(), InstType::Swap);
+    }
+}
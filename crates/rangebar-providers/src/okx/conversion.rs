@@ -0,0 +1,137 @@
+//! Conversion of OKX trades to AggTrade
+
+use super::types::{OkxError, OkxSide, OkxTrade};
+use rangebar_core::fixed_point::{FixedPoint, SCALE};
+use rangebar_core::timestamp::normalize_timestamp;
+use rangebar_core::types::AggTrade;
+
+/// Convert an OKX trade to AggTrade
+///
+/// - `is_buyer_maker` is true for taker sells, matching Binance and Kraken
+/// - `ts` (milliseconds) is normalized to microseconds
+/// - `contract_multiplier` converts swap sizes from contracts to base asset;
+///   pass `None` for spot or to keep contract units
+pub fn trade_to_agg_trade(
+    trade: &OkxTrade,
+    contract_multiplier: Option<FixedPoint>,
+) -> Result<AggTrade, OkxError> {
+    let invalid = |field: &'static str, value: &str| OkxError::InvalidNumber {
+        field,
+        value: value.to_string(),
+        trade_id: trade.trade_id.clone(),
+    };
+
+    let decimal = |field: &'static str, value: &str| {
+        FixedPoint::from_str(value).map_err(|source| OkxError::InvalidDecimal {
+            field,
+            trade_id: trade.trade_id.clone(),
+            source,
+        })
+    };
+
+    let trade_id: i64 = trade
+        .trade_id
+        .parse()
+        .map_err(|_| invalid("tradeId", &trade.trade_id))?;
+    let price = decimal("px", &trade.px)?;
+    let size = decimal("sz", &trade.sz)?;
+    let ts: u64 = trade.ts.parse().map_err(|_| invalid("ts", &trade.ts))?;
+
+    let volume = match contract_multiplier {
+        Some(multiplier) => {
+            FixedPoint((size.0 as i128 * multiplier.0 as i128 / SCALE as i128) as i64)
+        }
+        None => size,
+    };
+
+    Ok(AggTrade {
+        agg_trade_id: trade_id,
+        price,
+        volume,
+        first_trade_id: trade_id,
+        last_trade_id: trade_id,
+        timestamp: normalize_timestamp(ts),
+        is_buyer_maker: trade.side == OkxSide::Sell,
+        is_best_match: None,
+    })
+}
+
+/// Convert a batch of OKX trades, failing on the first invalid trade
+pub fn trades_to_agg_trades(
+    trades: &[OkxTrade],
+    contract_multiplier: Option<FixedPoint>,
+) -> Result<Vec<AggTrade>, OkxError> {
+    trades
+        .iter()
+        .map(|trade| trade_to_agg_trade(trade, contract_multiplier))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::okx::types::InstType;
+
+    #[test]
+    fn test_parse_rest_and_archive_formats() {
+        let rest: OkxTrade = serde_json::from_str(
+            r#"{"instId":"BTC-USDT","tradeId":"242720720","px":"42219.9","sz":"0.0012","side":"buy","ts":"1705276800123"}"#,
+        )
+        .unwrap();
+
+        let csv = "instrument_name,trade_id,side,price,size,created_time\n\
+                   BTC-USDT,242720721,sell,42219.8,0.5,1705276800456\n";
+        let archive: OkxTrade = csv::Reader::from_reader(csv.as_bytes())
+            .deserialize()
+            .next()
+            .unwrap()
+            .unwrap();
+        assert_eq!(archive.inst_id, "BTC-USDT");
+
+        let agg = trades_to_agg_trades(&[rest, archive], None).unwrap();
+        assert_eq!(agg[0].agg_trade_id, 242720720);
+        assert_eq!(agg[0].price.to_string(), "42219.90000000");
+        assert_eq!(agg[0].timestamp, 1_705_276_800_123_000);
+        assert!(!agg[0].is_buyer_maker);
+        assert!(agg[1].is_buyer_maker);
+        assert_eq!(agg[1].volume.to_string(), "0.50000000");
+    }
+
+    #[test]
+    fn test_swap_contract_multiplier() {
+        let trade: OkxTrade = serde_json::from_str(
+            r#"{"instId":"BTC-USDT-SWAP","tradeId":"1","px":"42000","sz":"25","side":"sell","ts":"1705276800000"}"#,
+        )
+        .unwrap();
+        assert_eq!(InstType::from_inst_id(&trade.inst_id), InstType::Swap);
+
+        let contracts = trade_to_agg_trade(&trade, None).unwrap();
+        assert_eq!(contracts.volume.to_string(), "25.00000000");
+
+        let ct_val = FixedPoint::from_str("0.01").unwrap();
+        let base = trade_to_agg_trade(&trade, Some(ct_val)).unwrap();
+        assert_eq!(base.volume.to_string(), "0.25000000");
+    }
+
+    #[test]
+    fn test_converted_bars_carry_okx_source() {
+        let trades: Vec<OkxTrade> = [("1", "42000", "buy"), ("2", "42500", "sell")]
+            .iter()
+            .map(|(id, px, side)| {
+                serde_json::from_str(&format!(
+                    r#"{{"instId":"BTC-USDT-SWAP","tradeId":"{id}","px":"{px}","sz":"1","side":"{side}","ts":"170527680000{id}"}}"#
+                ))
+                .unwrap()
+            })
+            .collect();
+        let bars = crate::okx::OkxClient::new(&trades[0].inst_id)
+            .processor(250)
+            .unwrap()
+            .process_agg_trade_records(&trades_to_agg_trades(&trades, None).unwrap())
+            .unwrap();
+
+        assert_eq!(bars.len(), 1);
+        assert_eq!(bars[0].data_source, rangebar_core::DataSource::OkxSwap);
+        assert_eq!(bars[0].data_source.provider(), rangebar_core::Provider::Okx);
+    }
+}
@@ -0,0 +1,48 @@
+//! OKX historical trades for spot and perpetual swap instruments
+//!
+//! Downloads OKX's public daily trade archives and converts them to
+//! [`AggTrade`](rangebar_core::AggTrade). OKX publishes individual trades, so
+//! each trade maps to one AggTrade with `first_trade_id == last_trade_id`.
+//!
+//! ## Quick Start
+//!
+//! ```rust,no_run
+//! use chrono::NaiveDate;
+//! use rangebar_providers::okx::OkxClient;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = OkxClient::new("BTC-USDT");
+//! let date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+//! let trades = client.fetch_day_agg_trades(date).await?;
+//! println!("Fetched {} OKX trades", trades.len());
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Data Format
+//!
+//! **Archive**: `https://www.okx.com/cdn/okex/traderecords/trades/daily/{YYYYMMDD}/{INST_ID}-trades-{YYYY-MM-DD}.zip`
+//!
+//! Archive CSV columns are `instrument_name, trade_id, side, price, size,
+//! created_time`; the REST API uses `instId, tradeId, side, px, sz, ts`. Both
+//! spellings deserialize into [`OkxTrade`].
+//!
+//! - `side`: `buy` or `sell`, the taker side
+//! - `ts` / `created_time`: Unix milliseconds, normalized to microseconds
+//!
+//! ## Contract Size (SWAP)
+//!
+//! Swap `sz` is quoted in **contracts**, not base asset. One BTC-USDT-SWAP
+//! contract is 0.01 BTC, so raw sizes overstate volume 100×. Set the contract
+//! value with [`OkxClient::with_contract_multiplier`] to convert sizes to base
+//! asset; without it swap volumes stay in contracts. Spot sizes are already in
+//! base asset.
+
+pub mod client;
+pub mod conversion;
+pub mod types;
+
+// Re-export main types for convenience
+pub use client::OkxClient;
+pub use conversion::{trade_to_agg_trade, trades_to_agg_trades};
+pub use types::{InstType, OkxError, OkxSide, OkxTrade};
@@ -0,0 +1,144 @@
+//! OKX trade records and errors
+
+use rangebar_core::FixedPointParseError;
+use serde::Deserialize;
+use std::fmt;
+use thiserror::Error;
+
+/// OKX instrument type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum InstType {
+    /// Spot pair, sizes in base asset (e.g. `BTC-USDT`)
+    #[default]
+    Spot,
+    /// Perpetual swap, sizes in contracts (e.g. `BTC-USDT-SWAP`)
+    Swap,
+}
+
+impl InstType {
+    /// OKX API spelling (`SPOT`, `SWAP`)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InstType::Spot => "SPOT",
+            InstType::Swap => "SWAP",
+        }
+    }
+
+    /// Data source to record on bars built from this instrument type
+    pub fn data_source(&self) -> rangebar_core::DataSource {
+        match self {
+            InstType::Spot => rangebar_core::DataSource::OkxSpot,
+            InstType::Swap => rangebar_core::DataSource::OkxSwap,
+        }
+    }
+
+    /// Infer the type from an instrument ID (`-SWAP` suffix means swap)
+    pub fn from_inst_id(inst_id: &str) -> Self {
+        if inst_id.to_uppercase().ends_with("-SWAP") {
+            InstType::Swap
+        } else {
+            InstType::Spot
+        }
+    }
+}
+
+impl fmt::Display for InstType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Taker side of an OKX trade
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OkxSide {
+    Buy,
+    Sell,
+}
+
+/// Single OKX trade, from the REST API or a daily archive CSV row
+///
+/// Numeric fields are kept as strings, as OKX sends them, and parsed during
+/// conversion so no precision is lost.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct OkxTrade {
+    /// Instrument ID (e.g. `BTC-USDT`); absent in some archive layouts
+    #[serde(rename = "instId", alias = "instrument_name", default)]
+    pub inst_id: String,
+
+    /// Trade ID
+    #[serde(rename = "tradeId", alias = "trade_id")]
+    pub trade_id: String,
+
+    /// Price
+    #[serde(alias = "price")]
+    pub px: String,
+
+    /// Size in base asset (SPOT) or contracts (SWAP)
+    #[serde(alias = "size")]
+    pub sz: String,
+
+    /// Taker side
+    pub side: OkxSide,
+
+    /// Unix milliseconds
+    #[serde(alias = "created_time")]
+    pub ts: String,
+}
+
+/// OKX provider errors
+#[derive(Error, Debug)]
+pub enum OkxError {
+    /// HTTP request failure
+    #[error("HTTP error: {0}")]
+    Http(#[from] reqwest::Error),
+
+    /// Non-success HTTP status (404 means no archive for that day)
+    #[error("HTTP {status} fetching {url}")]
+    HttpStatus { status: u16, url: String },
+
+    /// ZIP archive extraction failure
+    #[error("ZIP error: {0}")]
+    Zip(#[from] zip::result::ZipError),
+
+    /// CSV parsing failure
+    #[error("CSV error: {0}")]
+    Csv(#[from] csv::Error),
+
+    /// I/O error
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Numeric field could not be parsed
+    #[error("Invalid {field} '{value}' in trade {trade_id}")]
+    InvalidNumber {
+        field: &'static str,
+        value: String,
+        trade_id: String,
+    },
+
+    /// Price or size is not a valid fixed-point decimal
+    #[error("Invalid {field} in trade {trade_id}: {source}")]
+    InvalidDecimal {
+        field: &'static str,
+        trade_id: String,
+        #[source]
+        source: FixedPointParseError,
+    },
+}
+
+impl OkxError {
+    /// Whether the error concerns one trade that can be dropped from a batch
+    ///
+    /// Unparseable IDs/timestamps and empty or non-numeric decimals are
+    /// per-trade defects; out-of-range or over-precise decimals and transport
+    /// or archive errors are fatal.
+    pub fn is_skippable(&self) -> bool {
+        match self {
+            OkxError::InvalidNumber { .. } => true,
+            OkxError::InvalidDecimal { source, .. } => source.is_skippable(),
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,258 @@
+//! Provider-agnostic historical fetch interface
+//!
+//! [`HistoricalProvider`] lets callers fetch one UTC day of normalized trades
+//! without knowing which exchange backs it. Implementations return trades in
+//! `(timestamp, agg_trade_id)` order, ready for
+//! [`RangeBarProcessor`](rangebar_core::RangeBarProcessor).
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use rangebar_core::AggTrade;
+#[cfg(any(feature = "binance", feature = "exness"))]
+use rangebar_core::sort_trades_stable;
+use thiserror::Error;
+
+/// Errors returned by [`HistoricalProvider::fetch_day`]
+#[derive(Error, Debug)]
+pub enum ProviderError {
+    /// No archive has been published for this symbol and date
+    #[error("No data for {symbol} on {date}")]
+    NotFound { symbol: String, date: NaiveDate },
+
+    /// Symbol is not valid for this provider
+    #[error("Invalid symbol for {provider}: {symbol}")]
+    InvalidSymbol {
+        provider: &'static str,
+        symbol: String,
+    },
+
+    /// Download, decode or conversion failure
+    #[error("{provider} fetch failed: {message}")]
+    Fetch {
+        provider: &'static str,
+        message: String,
+    },
+}
+
+/// Source of historical trades, one UTC day at a time
+#[async_trait]
+pub trait HistoricalProvider: Send + Sync {
+    /// Short provider name for logs and errors (e.g. "binance")
+    fn name(&self) -> &'static str;
+
+    /// Fetch all trades for `symbol` on `date` (UTC), sorted and normalized
+    async fn fetch_day(
+        &self,
+        symbol: &str,
+        date: NaiveDate,
+    ) -> Result<Vec<AggTrade>, ProviderError>;
+}
+
+/// Binance aggTrades archives for one market
+#[cfg(feature = "binance")]
+#[derive(Debug, Clone, Copy)]
+pub struct BinanceProvider {
+    market_type: crate::binance::MarketType,
+}
+
+#[cfg(feature = "binance")]
+impl BinanceProvider {
+    /// Create a provider for the given market
+    pub fn new(market_type: crate::binance::MarketType) -> Self {
+        Self { market_type }
+    }
+}
+
+#[cfg(feature = "binance")]
+#[async_trait]
+impl HistoricalProvider for BinanceProvider {
+    fn name(&self) -> &'static str {
+        "binance"
+    }
+
+    async fn fetch_day(
+        &self,
+        symbol: &str,
+        date: NaiveDate,
+    ) -> Result<Vec<AggTrade>, ProviderError> {
+        use crate::binance::{DataError, HistoricalDataLoader};
+
+        let loader =
+            HistoricalDataLoader::with_market_type(symbol, self.market_type).map_err(|_| {
+                ProviderError::InvalidSymbol {
+                    provider: self.name(),
+                    symbol: symbol.to_string(),
+                }
+            })?;
+
+        let mut trades = match loader.load_single_day_trades(date).await {
+            Ok(trades) => trades,
+            Err(e) => {
+                return Err(match e.downcast_ref::<DataError>() {
+                    Some(DataError::DataNotFound { .. }) => ProviderError::NotFound {
+                        symbol: symbol.to_string(),
+                        date,
+                    },
+                    _ => ProviderError::Fetch {
+                        provider: self.name(),
+                        message: e.to_string(),
+                    },
+                });
+            }
+        };
+        sort_trades_stable(&mut trades);
+        Ok(trades)
+    }
+}
+
+/// Exness Raw_Spread tick archives as synthetic mid-price trades
+///
+/// Exness publishes monthly archives, so each call downloads the month and
+/// keeps the requested day. Synthetic trade IDs are numbered from 1 within the
+/// day.
+#[cfg(feature = "exness")]
+#[derive(Debug, Clone, Copy)]
+pub struct ExnessProvider {
+    strictness: crate::exness::ValidationStrictness,
+}
+
+#[cfg(feature = "exness")]
+impl ExnessProvider {
+    /// Create a provider validating ticks at the given strictness
+    pub fn new(strictness: crate::exness::ValidationStrictness) -> Self {
+        Self { strictness }
+    }
+}
+
+#[cfg(feature = "exness")]
+#[async_trait]
+impl HistoricalProvider for ExnessProvider {
+    fn name(&self) -> &'static str {
+        "exness"
+    }
+
+    async fn fetch_day(
+        &self,
+        symbol: &str,
+        date: NaiveDate,
+    ) -> Result<Vec<AggTrade>, ProviderError> {
+        use crate::exness::ExnessFetcher;
+        use crate::exness::conversion::tick_to_synthetic_trade;
+        use chrono::Datelike;
+
+        let fetch_error = |message: String| ProviderError::Fetch {
+            provider: "exness",
+            message,
+        };
+
+        let ticks = ExnessFetcher::new(symbol)
+            .fetch_month(date.year() as u16, date.month() as u8)
+            .await
+            .map_err(|e| fetch_error(e.to_string()))?;
+
+        let day_start_ms = date
+            .and_time(chrono::NaiveTime::MIN)
+            .and_utc()
+            .timestamp_millis();
+        let day_end_ms = day_start_ms + 86_400_000;
+
+        let mut trades = ticks
+            .iter()
+            .filter(|tick| (day_start_ms..day_end_ms).contains(&tick.timestamp_ms))
+            .zip(1..)
+            .map(|(tick, id)| tick_to_synthetic_trade(tick, symbol, id, self.strictness))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| fetch_error(e.to_string()))?;
+        sort_trades_stable(&mut trades);
+        Ok(trades)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rangebar_core::FixedPoint;
+    use std::collections::HashMap;
+
+    /// In-memory provider keyed by (symbol, date)
+    struct MockProvider {
+        days: HashMap<(String, NaiveDate), Vec<AggTrade>>,
+    }
+
+    #[async_trait]
+    impl HistoricalProvider for MockProvider {
+        fn name(&self) -> &'static str {
+            "mock"
+        }
+
+        async fn fetch_day(
+            &self,
+            symbol: &str,
+            date: NaiveDate,
+        ) -> Result<Vec<AggTrade>, ProviderError> {
+            self.days
+                .get(&(symbol.to_string(), date))
+                .cloned()
+                .ok_or_else(|| ProviderError::NotFound {
+                    symbol: symbol.to_string(),
+                    date,
+                })
+        }
+    }
+
+    fn trade(id: i64, timestamp: i64) -> AggTrade {
+        AggTrade {
+            agg_trade_id: id,
+            price: FixedPoint::from_str("100.0").unwrap(),
+            volume: FixedPoint::from_str("1.0").unwrap(),
+            first_trade_id: id,
+            last_trade_id: id,
+            timestamp,
+            is_buyer_maker: false,
+            is_best_match: None,
+        }
+    }
+
+    /// Consumer that only sees the trait object, skipping missing days
+    async fn count_trades(
+        provider: &dyn HistoricalProvider,
+        symbol: &str,
+        dates: &[NaiveDate],
+    ) -> Result<usize, ProviderError> {
+        let mut total = 0;
+        for &date in dates {
+            match provider.fetch_day(symbol, date).await {
+                Ok(trades) => total += trades.len(),
+                Err(ProviderError::NotFound { .. }) => continue,
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(total)
+    }
+
+    #[tokio::test]
+    async fn test_generic_consumer_over_dyn_provider() {
+        let day1 = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let day2 = NaiveDate::from_ymd_opt(2024, 1, 2).unwrap();
+        let day3 = NaiveDate::from_ymd_opt(2024, 1, 3).unwrap();
+
+        let provider = MockProvider {
+            days: HashMap::from([
+                (
+                    ("BTCUSDT".to_string(), day1),
+                    vec![trade(1, 1_000), trade(2, 2_000)],
+                ),
+                (("BTCUSDT".to_string(), day3), vec![trade(3, 3_000)]),
+            ]),
+        };
+
+        let total = count_trades(&provider, "BTCUSDT", &[day1, day2, day3])
+            .await
+            .unwrap();
+        assert_eq!(total, 3);
+        assert_eq!(provider.name(), "mock");
+
+        let err = provider.fetch_day("ETHUSDT", day1).await.unwrap_err();
+        assert!(matches!(err, ProviderError::NotFound { ref symbol, .. } if symbol == "ETHUSDT"));
+    }
+}
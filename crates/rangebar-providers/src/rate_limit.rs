@@ -0,0 +1,146 @@
+//! Request pacing shared across provider clients
+//!
+//! [`RateLimiter`] is a token bucket (implemented as GCRA): each request waits
+//! for a permit, so a fixed 2s spacing and a higher per-second budget are
+//! expressed the same way. Clones share one budget, letting several loaders
+//! against the same host stay under a single limit.
+
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+use thiserror::Error;
+use tokio::time::Instant;
+
+/// Invalid [`RateLimiter`] configuration
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum RateLimitError {
+    #[error("Rate must be finite and positive with a representable interval, got {per_second}/s")]
+    InvalidRate { per_second: f64 },
+}
+
+/// Async token-bucket limiter
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    /// Sustained permits per second
+    per_second: f64,
+
+    /// Permits available back-to-back after an idle period
+    burst: u32,
+
+    /// Theoretical arrival time of the next permit, shared between clones
+    next_permit: Arc<Mutex<Option<Instant>>>,
+}
+
+impl RateLimiter {
+    /// Create a limiter allowing `per_second` requests per second, burst 1
+    ///
+    /// Fails with [`RateLimitError::InvalidRate`] if `per_second` is not finite
+    /// and positive, or so small that the interval overflows a [`Duration`].
+    pub fn new(per_second: f64) -> Result<Self, RateLimitError> {
+        let valid = per_second.is_finite()
+            && per_second > 0.0
+            && Duration::try_from_secs_f64(1.0 / per_second).is_ok();
+        if !valid {
+            return Err(RateLimitError::InvalidRate { per_second });
+        }
+
+        Ok(Self {
+            per_second,
+            burst: 1,
+            next_permit: Arc::new(Mutex::new(None)),
+        })
+    }
+
+    /// Create a limiter spacing requests at least `interval` apart
+    ///
+    /// Fails with [`RateLimitError::InvalidRate`] if `interval` is zero.
+    pub fn from_interval(interval: Duration) -> Result<Self, RateLimitError> {
+        Self::new(1.0 / interval.as_secs_f64())
+    }
+
+    /// Allow up to `burst` requests without waiting after an idle period
+    ///
+    /// Values below 1 are treated as 1.
+    pub fn with_burst(mut self, burst: u32) -> Self {
+        self.burst = burst.max(1);
+        self
+    }
+
+    /// Sustained permits per second
+    pub fn per_second(&self) -> f64 {
+        self.per_second
+    }
+
+    /// Minimum spacing between permits once the burst is spent
+    pub fn interval(&self) -> Duration {
+        Duration::from_secs_f64(1.0 / self.per_second)
+    }
+
+    /// Wait until a request may be sent
+    ///
+    /// Permits are reserved in call order, so concurrent callers are served
+    /// first come, first served.
+    pub async fn acquire(&self) {
+        let interval = self.interval();
+        let ready_at = {
+            let mut next_permit = self
+                .next_permit
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+            let now = Instant::now();
+            let start = next_permit.map_or(now, |at| at.max(now));
+            let tolerance = interval * (self.burst - 1);
+            *next_permit = Some(start + interval);
+            start.checked_sub(tolerance).map_or(now, |at| at.max(now))
+        };
+        tokio::time::sleep_until(ready_at).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_requests_are_spaced_by_interval() {
+        let limiter = RateLimiter::from_interval(Duration::from_millis(2_000)).unwrap();
+        let start = Instant::now();
+        for _ in 0..5 {
+            limiter.acquire().await;
+        }
+        // First permit is immediate, the remaining four wait one interval each
+        assert!(start.elapsed() >= Duration::from_millis(8_000));
+        assert!(start.elapsed() < Duration::from_millis(8_100));
+
+        // Clones share the budget
+        let shared = limiter.clone();
+        let before = Instant::now();
+        shared.acquire().await;
+        assert!(before.elapsed() >= Duration::from_millis(1_900));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_burst_then_sustained_rate() {
+        let limiter = RateLimiter::new(10.0).unwrap().with_burst(3);
+        let start = Instant::now();
+        for _ in 0..3 {
+            limiter.acquire().await;
+        }
+        assert!(start.elapsed() < Duration::from_millis(1));
+
+        for _ in 0..10 {
+            limiter.acquire().await;
+        }
+        assert!(start.elapsed() >= Duration::from_millis(999));
+    }
+
+    #[test]
+    fn test_invalid_rates_are_rejected() {
+        for per_second in [0.0, -1.0, f64::NAN, f64::INFINITY, 1e-300] {
+            assert!(matches!(
+                RateLimiter::new(per_second),
+                Err(RateLimitError::InvalidRate { .. })
+            ));
+        }
+        assert!(RateLimiter::from_interval(Duration::ZERO).is_err());
+    }
+}
@@ -0,0 +1,138 @@
+//! Bar-time versus wall-clock instrumentation for event-driven backtests
+//!
+//! [`BarClock`] is read-only: it observes completed bars and the [`Instant`] at
+//! which each was handled, and reports how fast simulated market time advances
+//! relative to real processing time. It never delays or reorders anything.
+
+use rangebar_core::RangeBar;
+use std::time::Instant;
+
+/// Tracks the mapping between bar `close_time` and processing [`Instant`]
+///
+/// All rates are measured from the first recorded bar to the most recent one.
+#[derive(Debug, Clone, Default)]
+pub struct BarClock {
+    /// Close time (microseconds) and processing instant of the first bar
+    first: Option<(i64, Instant)>,
+
+    /// Close time (microseconds) and processing instant of the latest bar
+    last: Option<(i64, Instant)>,
+
+    /// Bars recorded
+    bar_count: u64,
+}
+
+impl BarClock {
+    /// Create an empty clock
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a bar processed now
+    pub fn record(&mut self, bar: &RangeBar) {
+        self.record_at(bar, Instant::now());
+    }
+
+    /// Record a bar processed at `processed_at`
+    pub fn record_at(&mut self, bar: &RangeBar, processed_at: Instant) {
+        let point = (bar.close_time, processed_at);
+        self.first.get_or_insert(point);
+        self.last = Some(point);
+        self.bar_count += 1;
+    }
+
+    /// Number of bars recorded
+    pub fn bar_count(&self) -> u64 {
+        self.bar_count
+    }
+
+    /// Market time elapsed across recorded bars, in milliseconds
+    pub fn bar_elapsed_ms(&self) -> Option<i64> {
+        let ((first_close, _), (last_close, _)) = self.first.zip(self.last)?;
+        Some((last_close - first_close) / 1_000)
+    }
+
+    /// Wall-clock time elapsed across recorded bars, in milliseconds
+    pub fn wall_elapsed_ms(&self) -> Option<i64> {
+        let ((_, first_at), (_, last_at)) = self.first.zip(self.last)?;
+        Some(last_at.duration_since(first_at).as_millis() as i64)
+    }
+
+    /// Bar time elapsed minus wall time elapsed, in milliseconds
+    ///
+    /// Positive when market time runs ahead of processing (replay faster than
+    /// real time); negative when processing falls behind the market.
+    pub fn drift_ms(&self) -> Option<i64> {
+        Some(self.bar_elapsed_ms()? - self.wall_elapsed_ms()?)
+    }
+
+    /// Bars completed per second of wall-clock time
+    ///
+    /// `None` until two bars have been recorded at distinct instants.
+    pub fn bars_per_wallclock_second(&self) -> Option<f64> {
+        let ((_, first_at), (_, last_at)) = self.first.zip(self.last)?;
+        let seconds = last_at.duration_since(first_at).as_secs_f64();
+        (seconds > 0.0).then(|| (self.bar_count - 1) as f64 / seconds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rangebar_core::{AggTrade, FixedPoint};
+    use std::time::Duration;
+
+    fn bar_closing_at(close_time: i64) -> RangeBar {
+        RangeBar::new(&AggTrade {
+            agg_trade_id: 1,
+            price: FixedPoint::from_str("50000.0").unwrap(),
+            volume: FixedPoint::from_str("1.0").unwrap(),
+            first_trade_id: 1,
+            last_trade_id: 1,
+            timestamp: close_time,
+            is_buyer_maker: false,
+            is_best_match: None,
+        })
+    }
+
+    #[test]
+    fn test_replay_faster_than_real_time() {
+        let mut clock = BarClock::new();
+        assert_eq!(clock.drift_ms(), None);
+        assert_eq!(clock.bars_per_wallclock_second(), None);
+
+        // One bar per market minute, processed every 100ms of wall time
+        let start = Instant::now();
+        let base_close = 1_704_067_200_000_000_i64;
+        for i in 0..11 {
+            clock.record_at(
+                &bar_closing_at(base_close + i * 60_000_000),
+                start + Duration::from_millis(i as u64 * 100),
+            );
+        }
+
+        assert_eq!(clock.bar_count(), 11);
+        assert_eq!(clock.bar_elapsed_ms(), Some(600_000));
+        assert_eq!(clock.wall_elapsed_ms(), Some(1_000));
+        assert_eq!(clock.drift_ms(), Some(599_000));
+        assert_eq!(clock.bars_per_wallclock_second(), Some(10.0));
+    }
+
+    #[test]
+    fn test_processing_behind_market() {
+        let mut clock = BarClock::new();
+        let start = Instant::now();
+        let base_close = 1_704_067_200_000_000_i64;
+
+        // Bars 1s apart in market time, handled 2s apart
+        for i in 0..3 {
+            clock.record_at(
+                &bar_closing_at(base_close + i * 1_000_000),
+                start + Duration::from_secs(i as u64 * 2),
+            );
+        }
+
+        assert_eq!(clock.drift_ms(), Some(-2_000));
+        assert_eq!(clock.bars_per_wallclock_second(), Some(0.5));
+    }
+}
@@ -3,9 +3,87 @@
 //! High-performance streaming indicators using rolling window algorithms
 //! for live trading and dashboard applications.
 
-use rangebar_core::RangeBar;
+use rangebar_core::{FixedPoint, RangeBar};
 use serde::{Deserialize, Serialize};
 
+/// Common interface for indicators updated once per completed bar
+///
+/// Implemented by every built-in indicator and open to user types, so a
+/// [`StreamingProcessor`](crate::StreamingProcessor) can drive a mixed set
+/// through [`attach_indicator`](crate::StreamingProcessor::attach_indicator).
+/// `Sync` keeps the processor's `start_processing` future `Send`.
+pub trait Indicator: Send + Sync {
+    /// Fold a completed bar into the indicator
+    fn update(&mut self, bar: &RangeBar);
+
+    /// Current value, `None` until enough bars have been seen
+    fn value(&self) -> Option<f64>;
+}
+
+/// What policy-aware updates (`try_update`) return when no valid value exists
+///
+/// Applies during warm-up and when the computed value is not finite (e.g. a
+/// `NaN` input inside the window). Plain `update` methods are unaffected.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OutputPolicy {
+    /// Return an [`IndicatorError`]
+    #[default]
+    Error,
+    /// Emit `f64::NAN`
+    NaN,
+    /// Repeat the last valid value, or `NaN` if there has been none yet
+    HoldLast,
+}
+
+impl OutputPolicy {
+    fn resolve<T: PolicyOutput>(
+        self,
+        value: Option<T>,
+        last_valid: &mut Option<T>,
+    ) -> Result<T, IndicatorError> {
+        match value {
+            Some(v) if v.is_finite() => {
+                *last_valid = Some(v);
+                Ok(v)
+            }
+            _ => match self {
+                OutputPolicy::Error if value.is_none() => Err(IndicatorError::InsufficientData),
+                OutputPolicy::Error => Err(IndicatorError::NonFiniteValue),
+                OutputPolicy::NaN => Ok(T::NAN),
+                OutputPolicy::HoldLast => Ok(last_valid.unwrap_or(T::NAN)),
+            },
+        }
+    }
+}
+
+/// Indicator output that [`OutputPolicy`] can validate and substitute
+trait PolicyOutput: Copy {
+    /// Sentinel emitted under [`OutputPolicy::NaN`]
+    const NAN: Self;
+
+    fn is_finite(&self) -> bool;
+}
+
+impl PolicyOutput for f64 {
+    const NAN: Self = f64::NAN;
+
+    fn is_finite(&self) -> bool {
+        f64::is_finite(*self)
+    }
+}
+
+impl PolicyOutput for MACDValue {
+    const NAN: Self = MACDValue {
+        macd_line: f64::NAN,
+        signal_line: f64::NAN,
+        histogram: f64::NAN,
+    };
+
+    fn is_finite(&self) -> bool {
+        self.macd_line.is_finite() && self.signal_line.is_finite() && self.histogram.is_finite()
+    }
+}
+
 /// Simple Moving Average with fixed window size
 #[derive(Debug, Clone)]
 pub struct SimpleMovingAverage {
@@ -13,6 +91,8 @@ pub struct SimpleMovingAverage {
     values: Vec<f64>,
     current_index: usize,
     filled: bool,
+    output_policy: OutputPolicy,
+    last_valid: Option<f64>,
 }
 
 impl SimpleMovingAverage {
@@ -27,9 +107,23 @@ impl SimpleMovingAverage {
             values: vec![0.0; window_size],
             current_index: 0,
             filled: false,
+            output_policy: OutputPolicy::default(),
+            last_valid: None,
         })
     }
 
+    /// Set the policy used by [`try_update`](Self::try_update)
+    pub fn with_output_policy(mut self, policy: OutputPolicy) -> Self {
+        self.output_policy = policy;
+        self
+    }
+
+    /// Update with new price value, resolving warm-up per the output policy
+    pub fn try_update(&mut self, value: f64) -> Result<f64, IndicatorError> {
+        let sma = self.update(value);
+        self.output_policy.resolve(sma, &mut self.last_valid)
+    }
+
     /// Update with new price value and return current SMA
     pub fn update(&mut self, value: f64) -> Option<f64> {
         self.values[self.current_index] = value;
@@ -39,11 +133,12 @@ impl SimpleMovingAverage {
             self.filled = true;
         }
 
-        if self.filled {
-            Some(self.values.iter().sum::<f64>() / self.window_size as f64)
-        } else {
-            None
-        }
+        self.current()
+    }
+
+    fn current(&self) -> Option<f64> {
+        self.filled
+            .then(|| self.values.iter().sum::<f64>() / self.window_size as f64)
     }
 
     /// Update with RangeBar close price
@@ -57,6 +152,8 @@ impl SimpleMovingAverage {
 pub struct ExponentialMovingAverage {
     alpha: f64,
     current_value: Option<f64>,
+    output_policy: OutputPolicy,
+    last_valid: Option<f64>,
 }
 
 impl ExponentialMovingAverage {
@@ -70,9 +167,26 @@ impl ExponentialMovingAverage {
         Ok(Self {
             alpha,
             current_value: None,
+            output_policy: OutputPolicy::default(),
+            last_valid: None,
         })
     }
 
+    /// Set the policy used by [`try_update`](Self::try_update)
+    pub fn with_output_policy(mut self, policy: OutputPolicy) -> Self {
+        self.output_policy = policy;
+        self
+    }
+
+    /// Update with new value, resolving non-finite results per the output policy
+    ///
+    /// The EMA has no warm-up, so the policy only applies once a non-finite
+    /// input has entered the average.
+    pub fn try_update(&mut self, value: f64) -> Result<f64, IndicatorError> {
+        let ema = self.update(value);
+        self.output_policy.resolve(Some(ema), &mut self.last_valid)
+    }
+
     /// Update with new value and return current EMA
     pub fn update(&mut self, value: f64) -> f64 {
         match self.current_value {
@@ -100,6 +214,8 @@ pub struct MACD {
     fast_ema: ExponentialMovingAverage,
     slow_ema: ExponentialMovingAverage,
     signal_ema: ExponentialMovingAverage,
+    output_policy: OutputPolicy,
+    last_valid: Option<MACDValue>,
 }
 
 impl MACD {
@@ -118,9 +234,26 @@ impl MACD {
             fast_ema: ExponentialMovingAverage::new(fast_period)?,
             slow_ema: ExponentialMovingAverage::new(slow_period)?,
             signal_ema: ExponentialMovingAverage::new(signal_period)?,
+            output_policy: OutputPolicy::default(),
+            last_valid: None,
         })
     }
 
+    /// Set the policy used by [`try_update`](Self::try_update)
+    pub fn with_output_policy(mut self, policy: OutputPolicy) -> Self {
+        self.output_policy = policy;
+        self
+    }
+
+    /// Update with new price, resolving non-finite results per the output policy
+    ///
+    /// A value is valid only when all three lines are finite; `NaN` and
+    /// `HoldLast` substitute the whole [`MACDValue`].
+    pub fn try_update(&mut self, price: f64) -> Result<MACDValue, IndicatorError> {
+        let macd = self.update(price);
+        self.output_policy.resolve(Some(macd), &mut self.last_valid)
+    }
+
     /// Update with new price and return MACD values
     pub fn update(&mut self, price: f64) -> MACDValue {
         let fast = self.fast_ema.update(price);
@@ -149,7 +282,7 @@ impl Default for MACD {
 }
 
 /// MACD indicator output values
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct MACDValue {
     pub macd_line: f64,
     pub signal_line: f64,
@@ -165,6 +298,18 @@ pub struct RSI {
     current_index: usize,
     filled: bool,
     previous_price: Option<f64>,
+    divergence_lookback: usize,
+    output_policy: OutputPolicy,
+    last_valid: Option<f64>,
+}
+
+/// Direction of an RSI/price divergence
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Divergence {
+    /// Price made a lower low while RSI made a higher low (weakening selling)
+    Bullish,
+    /// Price made a higher high while RSI made a lower high (weakening buying)
+    Bearish,
 }
 
 impl RSI {
@@ -181,9 +326,93 @@ impl RSI {
             current_index: 0,
             filled: false,
             previous_price: None,
+            divergence_lookback: 14,
+            output_policy: OutputPolicy::default(),
+            last_valid: None,
         })
     }
 
+    /// Set the policy used by [`try_update`](Self::try_update)
+    pub fn with_output_policy(mut self, policy: OutputPolicy) -> Self {
+        self.output_policy = policy;
+        self
+    }
+
+    /// Update with new price, resolving warm-up per the output policy
+    pub fn try_update(&mut self, price: f64) -> Result<f64, IndicatorError> {
+        let rsi = self.update(price);
+        self.output_policy.resolve(rsi, &mut self.last_valid)
+    }
+
+    /// Number of most recent points examined by [`divergence`](Self::divergence) (default 14)
+    ///
+    /// Values below 4 are raised to 4 so each half holds at least two points.
+    pub fn with_divergence_lookback(mut self, lookback: usize) -> Self {
+        self.divergence_lookback = lookback.max(4);
+        self
+    }
+
+    /// Detect RSI/price divergence over the configured lookback
+    ///
+    /// `prices` and `rsi_values` are aligned on their most recent element (the
+    /// last entry of each refers to the same bar); only the trailing
+    /// `lookback` points of each are used.
+    ///
+    /// # Heuristic
+    ///
+    /// The window is split into an earlier and a recent half. Within each
+    /// half the price extreme is located, and RSI is read at that same point:
+    ///
+    /// - **Bearish**: the recent price high exceeds the earlier high, but RSI
+    ///   at the recent high is below RSI at the earlier high.
+    /// - **Bullish**: the recent price low undercuts the earlier low, but RSI
+    ///   at the recent low is above RSI at the earlier low.
+    ///
+    /// Bearish is checked first. Returns `None` when fewer than 4 aligned
+    /// points are available, when an RSI value at a compared extreme is not
+    /// finite, or when neither pattern is present. This is a simple swing
+    /// comparison, not a pivot detector, and is meant as a screening signal.
+    pub fn divergence(&self, prices: &[FixedPoint], rsi_values: &[f64]) -> Option<Divergence> {
+        let len = self
+            .divergence_lookback
+            .min(prices.len())
+            .min(rsi_values.len());
+        if len < 4 {
+            return None;
+        }
+        let prices = &prices[prices.len() - len..];
+        let rsi_values = &rsi_values[rsi_values.len() - len..];
+        let mid = len / 2;
+
+        // Index of the first extreme within [start, end) under `better`
+        let extreme = |start: usize, end: usize, better: fn(FixedPoint, FixedPoint) -> bool| {
+            (start + 1..end).fold(start, |best, i| {
+                if better(prices[i], prices[best]) {
+                    i
+                } else {
+                    best
+                }
+            })
+        };
+        let rsi_at = |i: usize| Some(rsi_values[i]).filter(|v| v.is_finite());
+
+        let earlier_high = extreme(0, mid, |a, b| a > b);
+        let recent_high = extreme(mid, len, |a, b| a > b);
+        if prices[recent_high] > prices[earlier_high]
+            && rsi_at(recent_high)? < rsi_at(earlier_high)?
+        {
+            return Some(Divergence::Bearish);
+        }
+
+        let earlier_low = extreme(0, mid, |a, b| a < b);
+        let recent_low = extreme(mid, len, |a, b| a < b);
+        if prices[recent_low] < prices[earlier_low] && rsi_at(recent_low)? > rsi_at(earlier_low)? {
+            return Some(Divergence::Bullish);
+        }
+
+        None
+    }
+
     /// Update with new price and return RSI value
     pub fn update(&mut self, price: f64) -> Option<f64> {
         if let Some(prev_price) = self.previous_price {
@@ -202,25 +431,28 @@ impl RSI {
                 self.filled = true;
             }
 
-            if self.filled {
-                let avg_gain = self.gains.iter().sum::<f64>() / self.period as f64;
-                let avg_loss = self.losses.iter().sum::<f64>() / self.period as f64;
-
-                if avg_loss == 0.0 {
-                    Some(100.0)
-                } else {
-                    let rs = avg_gain / avg_loss;
-                    Some(100.0 - (100.0 / (1.0 + rs)))
-                }
-            } else {
-                None
-            }
+            self.current()
         } else {
             self.previous_price = Some(price);
             None
         }
     }
 
+    fn current(&self) -> Option<f64> {
+        if !self.filled {
+            return None;
+        }
+        let avg_gain = self.gains.iter().sum::<f64>() / self.period as f64;
+        let avg_loss = self.losses.iter().sum::<f64>() / self.period as f64;
+
+        if avg_loss == 0.0 {
+            Some(100.0)
+        } else {
+            let rs = avg_gain / avg_loss;
+            Some(100.0 - (100.0 / (1.0 + rs)))
+        }
+    }
+
     /// Update with RangeBar close price
     pub fn update_from_bar(&mut self, bar: &RangeBar) -> Option<f64> {
         self.update(bar.close.to_f64())
@@ -234,6 +466,8 @@ pub struct CCI {
     typical_prices: Vec<f64>,
     current_index: usize,
     filled: bool,
+    output_policy: OutputPolicy,
+    last_valid: Option<f64>,
 }
 
 impl CCI {
@@ -248,9 +482,23 @@ impl CCI {
             typical_prices: vec![0.0; period],
             current_index: 0,
             filled: false,
+            output_policy: OutputPolicy::default(),
+            last_valid: None,
         })
     }
 
+    /// Set the policy used by [`try_update`](Self::try_update)
+    pub fn with_output_policy(mut self, policy: OutputPolicy) -> Self {
+        self.output_policy = policy;
+        self
+    }
+
+    /// Update with new OHLC values, resolving warm-up per the output policy
+    pub fn try_update(&mut self, high: f64, low: f64, close: f64) -> Result<f64, IndicatorError> {
+        let cci = self.update(high, low, close);
+        self.output_policy.resolve(cci, &mut self.last_valid)
+    }
+
     /// Update with new OHLC values and return CCI value
     pub fn update(&mut self, high: f64, low: f64, close: f64) -> Option<f64> {
         let typical_price = (high + low + close) / 3.0;
@@ -262,22 +510,27 @@ impl CCI {
             self.filled = true;
         }
 
-        if self.filled {
-            let sma = self.typical_prices.iter().sum::<f64>() / self.period as f64;
-            let mean_deviation = self
-                .typical_prices
-                .iter()
-                .map(|&tp| (tp - sma).abs())
-                .sum::<f64>()
-                / self.period as f64;
+        self.current()
+    }
 
-            if mean_deviation == 0.0 {
-                Some(0.0)
-            } else {
-                Some((typical_price - sma) / (0.015 * mean_deviation))
-            }
+    fn current(&self) -> Option<f64> {
+        if !self.filled {
+            return None;
+        }
+        let typical_price =
+            self.typical_prices[(self.current_index + self.period - 1) % self.period];
+        let sma = self.typical_prices.iter().sum::<f64>() / self.period as f64;
+        let mean_deviation = self
+            .typical_prices
+            .iter()
+            .map(|&tp| (tp - sma).abs())
+            .sum::<f64>()
+            / self.period as f64;
+
+        if mean_deviation == 0.0 {
+            Some(0.0)
         } else {
-            None
+            Some((typical_price - sma) / (0.015 * mean_deviation))
         }
     }
 
@@ -287,6 +540,57 @@ impl CCI {
     }
 }
 
+impl Indicator for SimpleMovingAverage {
+    fn update(&mut self, bar: &RangeBar) {
+        self.update_from_bar(bar);
+    }
+
+    fn value(&self) -> Option<f64> {
+        self.current()
+    }
+}
+
+impl Indicator for ExponentialMovingAverage {
+    fn update(&mut self, bar: &RangeBar) {
+        self.update_from_bar(bar);
+    }
+
+    fn value(&self) -> Option<f64> {
+        self.current_value
+    }
+}
+
+/// Value is the MACD line (fast EMA minus slow EMA)
+impl Indicator for MACD {
+    fn update(&mut self, bar: &RangeBar) {
+        self.update_from_bar(bar);
+    }
+
+    fn value(&self) -> Option<f64> {
+        Some(self.fast_ema.current_value? - self.slow_ema.current_value?)
+    }
+}
+
+impl Indicator for RSI {
+    fn update(&mut self, bar: &RangeBar) {
+        self.update_from_bar(bar);
+    }
+
+    fn value(&self) -> Option<f64> {
+        self.current()
+    }
+}
+
+impl Indicator for CCI {
+    fn update(&mut self, bar: &RangeBar) {
+        self.update_from_bar(bar);
+    }
+
+    fn value(&self) -> Option<f64> {
+        self.current()
+    }
+}
+
 /// Indicator computation errors
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum IndicatorError {
@@ -294,6 +598,8 @@ pub enum IndicatorError {
     InvalidWindowSize,
     #[error("Insufficient data points for calculation")]
     InsufficientData,
+    #[error("Indicator value is not finite")]
+    NonFiniteValue,
 }
 
 #[cfg(test)]
@@ -310,6 +616,48 @@ mod tests {
         assert_eq!(sma.update(4.0), Some(3.0));
     }
 
+    #[test]
+    fn test_sma_output_policy_error() {
+        let mut sma = SimpleMovingAverage::new(2).unwrap();
+
+        assert!(matches!(
+            sma.try_update(1.0),
+            Err(IndicatorError::InsufficientData)
+        ));
+        assert_eq!(sma.try_update(3.0).unwrap(), 2.0);
+        assert!(matches!(
+            sma.try_update(f64::NAN),
+            Err(IndicatorError::NonFiniteValue)
+        ));
+    }
+
+    #[test]
+    fn test_sma_output_policy_nan() {
+        let mut sma = SimpleMovingAverage::new(2)
+            .unwrap()
+            .with_output_policy(OutputPolicy::NaN);
+
+        assert!(sma.try_update(1.0).unwrap().is_nan());
+        assert_eq!(sma.try_update(3.0).unwrap(), 2.0);
+        assert!(sma.try_update(f64::NAN).unwrap().is_nan());
+    }
+
+    #[test]
+    fn test_sma_output_policy_hold_last() {
+        let mut sma = SimpleMovingAverage::new(2)
+            .unwrap()
+            .with_output_policy(OutputPolicy::HoldLast);
+
+        // Nothing to hold before the first full window
+        assert!(sma.try_update(1.0).unwrap().is_nan());
+        assert_eq!(sma.try_update(3.0).unwrap(), 2.0);
+
+        // NaN stays in the window for two updates; the last SMA is repeated
+        assert_eq!(sma.try_update(f64::NAN).unwrap(), 2.0);
+        assert_eq!(sma.try_update(5.0).unwrap(), 2.0);
+        assert_eq!(sma.try_update(7.0).unwrap(), 6.0);
+    }
+
     #[test]
     fn test_ema_basic() {
         let mut ema = ExponentialMovingAverage::new(2).unwrap();
@@ -322,6 +670,52 @@ mod tests {
         assert!((result2 - 16.666666666666668).abs() < 1e-10);
     }
 
+    #[test]
+    fn test_ema_output_policy() {
+        let mut ema = ExponentialMovingAverage::new(2).unwrap();
+        assert_eq!(ema.try_update(10.0).unwrap(), 10.0);
+        assert!(matches!(
+            ema.try_update(f64::NAN),
+            Err(IndicatorError::NonFiniteValue)
+        ));
+
+        let mut ema = ExponentialMovingAverage::new(2)
+            .unwrap()
+            .with_output_policy(OutputPolicy::NaN);
+        assert_eq!(ema.try_update(10.0).unwrap(), 10.0);
+        assert!(ema.try_update(f64::INFINITY).unwrap().is_nan());
+
+        let mut ema = ExponentialMovingAverage::new(2)
+            .unwrap()
+            .with_output_policy(OutputPolicy::HoldLast);
+        assert_eq!(ema.try_update(10.0).unwrap(), 10.0);
+        assert_eq!(ema.try_update(f64::NAN).unwrap(), 10.0);
+    }
+
+    #[test]
+    fn test_macd_output_policy() {
+        let mut macd = MACD::new().unwrap();
+        assert_eq!(macd.try_update(100.0).unwrap().macd_line, 0.0);
+        assert!(matches!(
+            macd.try_update(f64::NAN),
+            Err(IndicatorError::NonFiniteValue)
+        ));
+
+        let mut macd = MACD::new().unwrap().with_output_policy(OutputPolicy::NaN);
+        macd.try_update(100.0).unwrap();
+        let value = macd.try_update(f64::NAN).unwrap();
+        assert!(value.macd_line.is_nan() && value.signal_line.is_nan() && value.histogram.is_nan());
+
+        let mut macd = MACD::new()
+            .unwrap()
+            .with_output_policy(OutputPolicy::HoldLast);
+        let first = macd.try_update(100.0).unwrap();
+        let held = macd.try_update(f64::NAN).unwrap();
+        assert_eq!(held.macd_line, first.macd_line);
+        assert_eq!(held.signal_line, first.signal_line);
+        assert_eq!(held.histogram, first.histogram);
+    }
+
     #[test]
     fn test_macd_basic() {
         let mut macd = MACD::new().unwrap();
@@ -344,6 +738,38 @@ mod tests {
         assert!(result.is_some());
     }
 
+    #[test]
+    fn test_rsi_divergence() {
+        let rsi = RSI::new(14).unwrap().with_divergence_lookback(8);
+        let prices = |values: &[f64]| -> Vec<FixedPoint> {
+            values
+                .iter()
+                .map(|v| FixedPoint::from_str(&format!("{v:.2}")).unwrap())
+                .collect()
+        };
+
+        // Higher price high with a lower RSI high
+        let bearish = prices(&[100.0, 104.0, 110.0, 106.0, 105.0, 108.0, 112.0, 109.0]);
+        let bearish_rsi = [55.0, 65.0, 78.0, 60.0, 58.0, 64.0, 70.0, 62.0];
+        assert_eq!(
+            rsi.divergence(&bearish, &bearish_rsi),
+            Some(Divergence::Bearish)
+        );
+
+        // Lower price low with a higher RSI low
+        let bullish = prices(&[100.0, 96.0, 90.0, 94.0, 95.0, 92.0, 88.0, 91.0]);
+        let bullish_rsi = [45.0, 35.0, 22.0, 40.0, 42.0, 36.0, 30.0, 38.0];
+        assert_eq!(
+            rsi.divergence(&bullish, &bullish_rsi),
+            Some(Divergence::Bullish)
+        );
+
+        // RSI confirming the new high is not a divergence
+        let confirming_rsi = [55.0, 65.0, 70.0, 60.0, 58.0, 64.0, 78.0, 62.0];
+        assert_eq!(rsi.divergence(&bearish, &confirming_rsi), None);
+        assert_eq!(rsi.divergence(&bearish[..3], &bearish_rsi[..3]), None);
+    }
+
     #[test]
     fn test_cci_basic() {
         let mut cci = CCI::new(2).unwrap();
@@ -4,9 +4,13 @@
 //! range bars from live data sources with support for replay, statistics,
 //! and indicators.
 
+pub mod clock;
 pub mod processor;
 pub mod replay_buffer;
+pub mod router;
 
+#[cfg(feature = "stats")]
+mod quantile;
 #[cfg(feature = "stats")]
 pub mod stats;
 
@@ -17,15 +21,18 @@ pub mod indicators;
 pub mod universal;
 
 // Re-export commonly used types
+pub use clock::BarClock;
 pub use processor::StreamingProcessor;
 pub use replay_buffer::{ReplayBuffer, ReplayBufferStats};
+pub use router::StreamingRouter;
 
 #[cfg(feature = "stats")]
-pub use stats::{StatisticsSnapshot, StreamingConfig, StreamingStatsEngine};
+pub use stats::{ClosePercentiles, StatisticsSnapshot, StreamingConfig, StreamingStatsEngine};
 
 #[cfg(feature = "indicators")]
 pub use indicators::{
-    CCI, ExponentialMovingAverage, IndicatorError, MACD, MACDValue, RSI, SimpleMovingAverage,
+    CCI, Divergence, ExponentialMovingAverage, Indicator, IndicatorError, MACD, MACDValue,
+    OutputPolicy, RSI, SimpleMovingAverage,
 };
 
 #[cfg(feature = "binance-integration")]
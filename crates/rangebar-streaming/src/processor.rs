@@ -8,6 +8,9 @@ use futures::Stream;
 /// - Maintains temporal integrity for financial data
 use rangebar_core::processor::ExportRangeBarProcessor;
 use rangebar_core::{AggTrade, RangeBar};
+
+#[cfg(feature = "indicators")]
+use crate::indicators::Indicator;
 use std::pin::Pin;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
@@ -70,6 +73,19 @@ pub struct StreamingProcessor {
 
     /// Circuit breaker state
     circuit_breaker: CircuitBreaker,
+
+    /// Optional alert on abnormally wide completed bars
+    anomaly_hook: Option<AnomalyHook>,
+
+    /// Indicators updated with every completed bar
+    #[cfg(feature = "indicators")]
+    indicators: Vec<Box<dyn Indicator>>,
+}
+
+/// Callback fired for completed bars wider than a range limit
+struct AnomalyHook {
+    max_range_decimal_bps: u32,
+    callback: Box<dyn FnMut(&RangeBar) + Send + Sync>,
 }
 
 /// Circuit breaker implementation
@@ -133,9 +149,45 @@ impl StreamingProcessor {
                 circuit_breaker_threshold,
                 circuit_breaker_timeout,
             ),
+            anomaly_hook: None,
+            #[cfg(feature = "indicators")]
+            indicators: Vec::new(),
         })
     }
 
+    /// Register an indicator to be updated with each completed bar
+    ///
+    /// Indicators are updated in attachment order, before the bar is sent.
+    #[cfg(feature = "indicators")]
+    pub fn attach_indicator(&mut self, indicator: Box<dyn Indicator>) {
+        self.indicators.push(indicator);
+    }
+
+    /// Attached indicators, in attachment order
+    #[cfg(feature = "indicators")]
+    pub fn indicators(&self) -> &[Box<dyn Indicator>] {
+        &self.indicators
+    }
+
+    /// Invoke `callback` for each completed bar whose high-low range exceeds
+    /// `max_range_decimal_bps` (see [`RangeBar::range_decimal_bps`])
+    ///
+    /// Intended for real-time alerts on data glitches or flash moves. The
+    /// callback runs inline on the processing path before the bar is sent, so
+    /// it should be cheap. Replaces any previously registered callback; with
+    /// none registered the check is skipped entirely. The callback must be
+    /// `Sync` so the processor can still be moved into `tokio::spawn`.
+    pub fn on_anomaly(
+        &mut self,
+        max_range_decimal_bps: u32,
+        callback: impl FnMut(&RangeBar) + Send + Sync + 'static,
+    ) {
+        self.anomaly_hook = Some(AnomalyHook {
+            max_range_decimal_bps,
+            callback: Box::new(callback),
+        });
+    }
+
     /// Get trade sender for external components
     pub fn trade_sender(&mut self) -> Option<mpsc::Sender<AggTrade>> {
         self.trade_sender.take()
@@ -204,6 +256,11 @@ impl StreamingProcessor {
         &mut self,
         trade: AggTrade,
     ) -> Result<Option<RangeBar>, StreamingError> {
+        Ok(self.process_trade(trade))
+    }
+
+    /// Synchronous core of trade processing, shared with [`StreamingRouter`](crate::StreamingRouter)
+    pub(crate) fn process_trade(&mut self, trade: AggTrade) -> Option<RangeBar> {
         // Update metrics
         self.metrics
             .trades_processed
@@ -232,9 +289,21 @@ impl StreamingProcessor {
             }
 
             self.metrics.bars_generated.fetch_add(1, Ordering::Relaxed);
-            Ok(Some(completed_bar))
+
+            if let Some(hook) = self.anomaly_hook.as_mut()
+                && completed_bar.range_decimal_bps() > hook.max_range_decimal_bps as f64
+            {
+                (hook.callback)(&completed_bar);
+            }
+
+            #[cfg(feature = "indicators")]
+            for indicator in &mut self.indicators {
+                indicator.update(&completed_bar);
+            }
+
+            Some(completed_bar)
         } else {
-            Ok(None)
+            None
         }
     }
 
@@ -266,7 +335,7 @@ impl StreamingProcessor {
     }
 
     /// Extract final incomplete bar when stream ends (for algorithmic consistency)
-    pub fn get_final_incomplete_bar(&mut self) -> Option<RangeBar> {
+    pub fn get_final_incomplete_bar(&self) -> Option<RangeBar> {
         self.processor.get_incomplete_bar()
     }
 
@@ -495,6 +564,65 @@ mod tests {
         assert_eq!(circuit_breaker.state, CircuitBreakerState::Closed);
     }
 
+    #[test]
+    fn test_anomaly_callback_fires_only_for_wide_bars() {
+        use std::sync::Mutex;
+
+        let mut processor = StreamingProcessor::new(25).unwrap();
+        let alerts = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&alerts);
+        processor.on_anomaly(200, move |bar| sink.lock().unwrap().push(bar.open_time));
+
+        // Small steps close a normal ~0.025% bar
+        let mut completed = Vec::new();
+        for i in 0..100 {
+            let trade = create_test_trade(i, 23000.0 + i as f64, 1_000 + i);
+            completed.extend(processor.process_trade(trade));
+        }
+        assert!(!completed.is_empty());
+        assert!(alerts.lock().unwrap().is_empty());
+
+        // A 5% gap closes the open bar far beyond the limit
+        let open_time = processor.get_final_incomplete_bar().unwrap().open_time;
+        let monster = processor
+            .process_trade(create_test_trade(100, 24_150.0, 1_100))
+            .unwrap();
+        assert!(monster.range_decimal_bps() > 200.0);
+        assert_eq!(*alerts.lock().unwrap(), vec![open_time]);
+    }
+
+    #[cfg(feature = "indicators")]
+    #[test]
+    fn test_attached_indicators_update_per_bar() {
+        use crate::indicators::SimpleMovingAverage;
+
+        struct BarCounter(u32);
+
+        impl Indicator for BarCounter {
+            fn update(&mut self, _bar: &RangeBar) {
+                self.0 += 1;
+            }
+
+            fn value(&self) -> Option<f64> {
+                Some(self.0 as f64)
+            }
+        }
+
+        let mut processor = StreamingProcessor::new(25).unwrap();
+        processor.attach_indicator(Box::new(BarCounter(0)));
+        processor.attach_indicator(Box::new(SimpleMovingAverage::new(2).unwrap()));
+
+        let mut bars = 0;
+        for i in 0..100 {
+            let trade = create_test_trade(i, 23000.0 + i as f64, 1_000 + i);
+            bars += processor.process_trade(trade).is_some() as u32;
+        }
+
+        assert!(bars >= 2);
+        assert_eq!(processor.indicators()[0].value(), Some(bars as f64));
+        assert!(processor.indicators()[1].value().is_some());
+    }
+
     #[test]
     fn test_metrics_calculations() {
         let metrics = MetricsSummary {
@@ -0,0 +1,134 @@
+//! Bounded-memory quantile sketch for unbounded streams
+//!
+//! Implements the Greenwald-Khanna summary (SIGMOD 2001): a sorted list of
+//! `(value, g, delta)` tuples whose rank uncertainty is kept below `2εn`, so
+//! any quantile can be answered within `ε·n` ranks while memory grows as
+//! `O(1/ε · log(εn))` instead of with the number of observations.
+
+/// One summary entry: `g` is the rank gap to the previous entry and `delta`
+/// the uncertainty of this entry's maximum rank
+#[derive(Debug, Clone, Copy)]
+struct Tuple {
+    value: f64,
+    g: u64,
+    delta: u64,
+}
+
+/// ε-approximate quantile sketch over `f64` observations
+#[derive(Debug, Clone)]
+pub(crate) struct QuantileSketch {
+    /// Rank error bound as a fraction of the observation count
+    epsilon: f64,
+    tuples: Vec<Tuple>,
+    count: u64,
+    /// Observations between compressions, `1 / (2ε)`
+    compress_every: u64,
+}
+
+impl QuantileSketch {
+    /// Create a sketch answering quantiles within `epsilon · n` ranks
+    ///
+    /// `epsilon` is clamped to `[1e-6, 0.5]`; NaN falls back to 0.001.
+    pub(crate) fn new(epsilon: f64) -> Self {
+        let epsilon = if epsilon.is_nan() {
+            0.001
+        } else {
+            epsilon.clamp(1e-6, 0.5)
+        };
+        Self {
+            epsilon,
+            tuples: Vec::new(),
+            count: 0,
+            compress_every: (1.0 / (2.0 * epsilon)).floor().max(1.0) as u64,
+        }
+    }
+
+    /// Add one observation; NaN is ignored
+    pub(crate) fn observe(&mut self, value: f64) {
+        if value.is_nan() {
+            return;
+        }
+        let index = self.tuples.partition_point(|t| t.value <= value);
+        // New extremes have an exact rank; interior values inherit the
+        // uncertainty of their successor
+        let delta = match self.tuples.get(index) {
+            Some(next) if index > 0 => next.g + next.delta - 1,
+            _ => 0,
+        };
+        self.tuples.insert(index, Tuple { value, g: 1, delta });
+        self.count += 1;
+
+        if self.count.is_multiple_of(self.compress_every) {
+            self.compress();
+        }
+    }
+
+    /// Value whose rank is within `ε·n` of `phi · n`, or `None` when empty
+    pub(crate) fn quantile(&self, phi: f64) -> Option<f64> {
+        let first = self.tuples.first()?;
+        let target = phi.clamp(0.0, 1.0) * self.count as f64;
+        let tolerance = self.epsilon * self.count as f64;
+
+        let mut rank = 0;
+        let mut previous = first.value;
+        for tuple in &self.tuples {
+            rank += tuple.g;
+            if (rank + tuple.delta) as f64 > target + tolerance {
+                return Some(previous);
+            }
+            previous = tuple.value;
+        }
+        Some(previous)
+    }
+
+    /// Number of retained summary entries
+    #[cfg(test)]
+    fn len(&self) -> usize {
+        self.tuples.len()
+    }
+
+    /// Merge adjacent entries while `g + delta` stays within `⌊2εn⌋`
+    ///
+    /// The minimum and maximum are never merged away.
+    fn compress(&mut self) {
+        let max_gap = (2.0 * self.epsilon * self.count as f64).floor() as u64;
+        let mut i = self.tuples.len().saturating_sub(2);
+        while i >= 1 {
+            let (current, next) = (self.tuples[i], self.tuples[i + 1]);
+            if current.g + next.g + next.delta <= max_gap {
+                self.tuples[i + 1].g += current.g;
+                self.tuples.remove(i);
+            }
+            i -= 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sketch_rank_error_and_memory_are_bounded() {
+        let epsilon = 0.005;
+        let n = 100_000u64;
+        let mut sketch = QuantileSketch::new(epsilon);
+        assert_eq!(sketch.quantile(0.5), None);
+
+        // Deterministic permutation of 1..=n, so the value is its rank
+        for i in 0..n {
+            sketch.observe(((i * 7_919) % n + 1) as f64);
+        }
+
+        let tolerance = epsilon * n as f64 + 1.0;
+        for phi in [0.0, 0.1, 0.5, 0.9, 0.99, 1.0] {
+            let estimate = sketch.quantile(phi).unwrap();
+            let exact = (phi * n as f64).max(1.0);
+            assert!(
+                (estimate - exact).abs() <= tolerance,
+                "phi={phi}: {estimate} vs {exact}"
+            );
+        }
+        assert!(sketch.len() < 1_000, "retained {} entries", sketch.len());
+    }
+}
@@ -3,7 +3,7 @@
 //! This module provides a circular buffer that stores recent trades and allows
 //! replaying them at different speeds for testing and analysis.
 
-use rangebar_core::AggTrade;
+use rangebar_core::{AggTrade, ProcessingError, RangeBar, RangeBarProcessor};
 use std::collections::VecDeque;
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
@@ -11,6 +11,12 @@ use tokio::time::sleep;
 use tokio_stream::Stream;
 
 /// A circular buffer that stores recent trades and provides replay functionality
+///
+/// Retention is bounded by a time window (set at construction or via
+/// [`with_time_window`](Self::with_time_window)) and optionally by a maximum
+/// trade count ([`with_capacity`](Self::with_capacity)). When both are set the
+/// most restrictive bound wins: after each push the oldest trades are evicted
+/// until the buffer satisfies both.
 #[derive(Debug, Clone)]
 pub struct ReplayBuffer {
     inner: Arc<Mutex<ReplayBufferInner>>,
@@ -19,8 +25,11 @@ pub struct ReplayBuffer {
 #[derive(Debug)]
 struct ReplayBufferInner {
     capacity: Duration,
+    max_trades: Option<usize>,
     trades: VecDeque<AggTrade>,
     start_time: Option<Instant>,
+    evicted_by_time: u64,
+    evicted_by_count: u64,
 }
 
 impl ReplayBuffer {
@@ -29,12 +38,38 @@ impl ReplayBuffer {
         Self {
             inner: Arc::new(Mutex::new(ReplayBufferInner {
                 capacity,
+                max_trades: None,
                 trades: VecDeque::new(),
                 start_time: None,
+                evicted_by_time: 0,
+                evicted_by_count: 0,
             })),
         }
     }
 
+    /// Cap the buffer at `max_trades`, evicting the oldest trades beyond it
+    ///
+    /// A cap of 0 is treated as 1 so the latest trade is always retained.
+    pub fn with_capacity(self, max_trades: usize) -> Self {
+        self.lock().max_trades = Some(max_trades.max(1));
+        self
+    }
+
+    /// Replace the retention time window, in milliseconds of trade time
+    ///
+    /// Negative windows are treated as 0, keeping only trades sharing the
+    /// latest timestamp.
+    pub fn with_time_window(self, ms: i64) -> Self {
+        self.lock().capacity = Duration::from_millis(ms.max(0) as u64);
+        self
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, ReplayBufferInner> {
+        self.inner
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
     /// Add a new trade to the buffer
     pub fn push(&self, trade: AggTrade) {
         let mut inner = self
@@ -53,12 +88,19 @@ impl ReplayBuffer {
         while let Some(front_trade) = inner.trades.front() {
             if front_trade.timestamp < cutoff_timestamp {
                 inner.trades.pop_front();
+                inner.evicted_by_time += 1;
             } else {
                 break;
             }
         }
 
         inner.trades.push_back(trade);
+
+        if let Some(max_trades) = inner.max_trades {
+            let excess = inner.trades.len().saturating_sub(max_trades);
+            inner.trades.drain(..excess);
+            inner.evicted_by_count += excess as u64;
+        }
     }
 
     /// Get the number of trades currently in the buffer
@@ -125,6 +167,21 @@ impl ReplayBuffer {
         ReplayStream::new(trades, speed_multiplier)
     }
 
+    /// Rebuild range bars from the retained trades at another threshold
+    ///
+    /// Runs a fresh [`RangeBarProcessor`] over a snapshot of the buffer, so the
+    /// result reflects only trades still retained: anything already evicted by
+    /// the time window or count cap is absent, and the first bar opens on the
+    /// oldest retained trade. Only completed bars are returned.
+    pub fn rebuild_bars(
+        &self,
+        threshold_decimal_bps: u32,
+    ) -> Result<Vec<RangeBar>, ProcessingError> {
+        let mut processor = RangeBarProcessor::new(threshold_decimal_bps)?;
+        let trades: Vec<AggTrade> = self.lock().trades.iter().cloned().collect();
+        processor.process_agg_trade_records(&trades)
+    }
+
     /// Get statistics about the buffer
     pub fn stats(&self) -> ReplayBufferStats {
         let inner = self
@@ -141,10 +198,13 @@ impl ReplayBuffer {
 
         ReplayBufferStats {
             capacity: inner.capacity,
+            max_trades: inner.max_trades,
             trade_count: inner.trades.len(),
             first_timestamp,
             last_timestamp,
             memory_usage_bytes: inner.trades.len() * std::mem::size_of::<AggTrade>(),
+            evicted_by_time: inner.evicted_by_time,
+            evicted_by_count: inner.evicted_by_count,
         }
     }
 }
@@ -153,10 +213,22 @@ impl ReplayBuffer {
 #[derive(Debug, Clone)]
 pub struct ReplayBufferStats {
     pub capacity: Duration,
+    pub max_trades: Option<usize>,
     pub trade_count: usize,
     pub first_timestamp: Option<i64>,
     pub last_timestamp: Option<i64>,
     pub memory_usage_bytes: usize,
+    /// Trades dropped for falling outside the time window
+    pub evicted_by_time: u64,
+    /// Trades dropped for exceeding `max_trades`
+    pub evicted_by_count: u64,
+}
+
+impl ReplayBufferStats {
+    /// Total trades evicted under either bound
+    pub fn evicted_total(&self) -> u64 {
+        self.evicted_by_time + self.evicted_by_count
+    }
 }
 
 /// A stream that replays trades at a specified speed
@@ -350,6 +422,82 @@ mod tests {
         assert_eq!(first.unwrap().agg_trade_id, 1);
     }
 
+    #[test]
+    fn test_count_based_eviction() {
+        let buffer = ReplayBuffer::new(Duration::from_secs(3600)).with_capacity(50);
+        let base_time = 1_704_067_200_000_000_i64;
+
+        for i in 0..120 {
+            buffer.push(create_test_trade(i, base_time + i * 1_000_000, 50000.0));
+        }
+
+        let stats = buffer.stats();
+        assert_eq!(stats.trade_count, 50);
+        assert_eq!(stats.max_trades, Some(50));
+        assert_eq!(stats.evicted_by_count, 70);
+        assert_eq!(stats.evicted_by_time, 0);
+        assert_eq!(stats.first_timestamp, Some(base_time + 70 * 1_000_000));
+    }
+
+    #[test]
+    fn test_time_based_eviction() {
+        let buffer = ReplayBuffer::new(Duration::from_secs(3600)).with_time_window(10_000);
+        let base_time = 1_704_067_200_000_000_i64;
+
+        for i in 0..30 {
+            buffer.push(create_test_trade(i, base_time + i * 1_000_000, 50000.0));
+        }
+
+        // Window [t-10s, t] is inclusive: 11 trades survive
+        let stats = buffer.stats();
+        assert_eq!(stats.capacity, Duration::from_secs(10));
+        assert_eq!(stats.trade_count, 11);
+        assert_eq!(stats.evicted_by_time, 19);
+        assert_eq!(stats.evicted_by_count, 0);
+    }
+
+    #[test]
+    fn test_most_restrictive_bound_wins() {
+        let base_time = 1_704_067_200_000_000_i64;
+
+        // Count is tighter than the 10s window
+        let buffer = ReplayBuffer::new(Duration::from_secs(10)).with_capacity(5);
+        for i in 0..30 {
+            buffer.push(create_test_trade(i, base_time + i * 1_000_000, 50000.0));
+        }
+        assert_eq!(buffer.len(), 5);
+
+        // Window is tighter than the 100-trade cap
+        let buffer = ReplayBuffer::new(Duration::from_secs(10)).with_capacity(100);
+        for i in 0..30 {
+            buffer.push(create_test_trade(i, base_time + i * 1_000_000, 50000.0));
+        }
+        let stats = buffer.stats();
+        assert_eq!(stats.trade_count, 11);
+        assert_eq!(stats.evicted_total(), 19);
+    }
+
+    #[test]
+    fn test_rebuild_bars_at_different_thresholds() {
+        let buffer = ReplayBuffer::new(Duration::from_secs(3600));
+        let base_time = 1_704_067_200_000_000_i64;
+        for i in 0..2_000 {
+            let price = 50_000.0 + ((i % 200) as f64 - 100.0).abs() * 5.0 + i as f64 * 0.5;
+            buffer.push(create_test_trade(i, base_time + i * 1_000_000, price));
+        }
+
+        let fine = buffer.rebuild_bars(100).unwrap();
+        let coarse = buffer.rebuild_bars(250).unwrap();
+        assert!(!coarse.is_empty());
+        assert!(fine.len() > coarse.len());
+        assert_eq!(fine[0].open_time, base_time);
+
+        assert!(matches!(
+            buffer.rebuild_bars(0),
+            Err(ProcessingError::InvalidThreshold { .. })
+        ));
+    }
+
     #[test]
     fn test_get_trades_from_empty_buffer() {
         let buffer = ReplayBuffer::new(Duration::from_secs(60));
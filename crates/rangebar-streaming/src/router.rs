@@ -0,0 +1,142 @@
+//! Per-symbol routing of live trades to independent streaming processors
+//!
+//! A dashboard subscribed to a whole basket receives one interleaved trade feed.
+//! [`StreamingRouter`] owns one [`StreamingProcessor`] per symbol, created on the
+//! first trade seen for that symbol, so bar state never leaks across symbols.
+
+use crate::processor::{StreamingProcessor, StreamingProcessorConfig};
+use rangebar_core::processor::{ExportRangeBarProcessor, ProcessingError};
+use rangebar_core::{AggTrade, RangeBar};
+use std::collections::BTreeMap;
+
+/// Routes trades to per-symbol [`StreamingProcessor`]s sharing one configuration
+pub struct StreamingRouter {
+    /// Threshold in decimal basis points applied to every symbol
+    threshold_decimal_bps: u32,
+
+    /// Configuration cloned into each lazily created processor
+    config: StreamingProcessorConfig,
+
+    /// Processors keyed by symbol
+    processors: BTreeMap<String, StreamingProcessor>,
+}
+
+impl StreamingRouter {
+    /// Create a router; the threshold is validated up front so lazy creation cannot fail
+    pub fn new(
+        threshold_decimal_bps: u32,
+        config: StreamingProcessorConfig,
+    ) -> Result<Self, ProcessingError> {
+        ExportRangeBarProcessor::new(threshold_decimal_bps)?;
+
+        Ok(Self {
+            threshold_decimal_bps,
+            config,
+            processors: BTreeMap::new(),
+        })
+    }
+
+    /// Route a trade to its symbol's processor
+    ///
+    /// Returns the symbol together with the bar this trade completed, if any.
+    pub fn process(&mut self, symbol: &str, trade: AggTrade) -> Option<(String, RangeBar)> {
+        if !self.processors.contains_key(symbol) {
+            let processor =
+                StreamingProcessor::with_config(self.threshold_decimal_bps, self.config.clone())
+                    .expect("threshold validated in StreamingRouter::new");
+            self.processors.insert(symbol.to_string(), processor);
+        }
+
+        let processor = self.processors.get_mut(symbol)?;
+        processor
+            .process_trade(trade)
+            .map(|bar| (symbol.to_string(), bar))
+    }
+
+    /// Symbols that have received at least one trade, in sorted order
+    pub fn symbols(&self) -> Vec<&str> {
+        self.processors.keys().map(String::as_str).collect()
+    }
+
+    /// Bar currently under construction for `symbol`
+    pub fn incomplete_bar(&self, symbol: &str) -> Option<RangeBar> {
+        self.processors
+            .get(symbol)
+            .and_then(StreamingProcessor::get_final_incomplete_bar)
+    }
+
+    /// Processor for `symbol`, e.g. to read its metrics
+    pub fn processor(&self, symbol: &str) -> Option<&StreamingProcessor> {
+        self.processors.get(symbol)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rangebar_core::FixedPoint;
+
+    fn trade(id: i64, price: &str, timestamp: i64) -> AggTrade {
+        AggTrade {
+            agg_trade_id: id,
+            price: FixedPoint::from_str(price).unwrap(),
+            volume: FixedPoint::from_str("1.0").unwrap(),
+            first_trade_id: id,
+            last_trade_id: id,
+            timestamp,
+            is_buyer_maker: false,
+            is_best_match: None,
+        }
+    }
+
+    #[test]
+    fn test_interleaved_symbols_build_independent_bars() {
+        let mut router = StreamingRouter::new(250, StreamingProcessorConfig::default()).unwrap();
+
+        // BTC breaches +0.25% on its third trade; ETH stays inside its range
+        let feed = [
+            ("BTCUSDT", trade(1, "50000.0", 1_000)),
+            ("ETHUSDT", trade(1, "3000.0", 1_001)),
+            ("BTCUSDT", trade(2, "50050.0", 2_000)),
+            ("ETHUSDT", trade(2, "3005.0", 2_001)),
+            ("BTCUSDT", trade(3, "50125.0", 3_000)),
+            ("ETHUSDT", trade(3, "2995.0", 3_001)),
+        ];
+
+        let completed: Vec<_> = feed
+            .into_iter()
+            .filter_map(|(symbol, trade)| router.process(symbol, trade))
+            .collect();
+
+        assert_eq!(completed.len(), 1);
+        let (symbol, bar) = &completed[0];
+        assert_eq!(symbol, "BTCUSDT");
+        assert_eq!(bar.open.to_string(), "50000.00000000");
+        assert_eq!(bar.close.to_string(), "50125.00000000");
+        assert_eq!(bar.agg_record_count, 3);
+
+        assert_eq!(router.symbols(), vec!["BTCUSDT", "ETHUSDT"]);
+
+        let eth = router.incomplete_bar("ETHUSDT").unwrap();
+        assert_eq!(eth.open.to_string(), "3000.00000000");
+        assert_eq!(eth.high.to_string(), "3005.00000000");
+        assert_eq!(eth.low.to_string(), "2995.00000000");
+        assert_eq!(eth.agg_record_count, 3);
+
+        assert!(router.incomplete_bar("SOLUSDT").is_none());
+        assert_eq!(
+            router
+                .processor("BTCUSDT")
+                .unwrap()
+                .metrics()
+                .summary()
+                .trades_processed,
+            3
+        );
+    }
+
+    #[test]
+    fn test_invalid_threshold_rejected_up_front() {
+        assert!(StreamingRouter::new(0, StreamingProcessorConfig::default()).is_err());
+    }
+}
@@ -4,10 +4,12 @@
 //! - tdigests: t-digest algorithm for streaming percentiles
 //! - rolling-stats: Welford's algorithm for numerically stable variance
 //! - online-statistics: Comprehensive streaming statistics with serialization
+//! - Greenwald-Khanna sketch: bounded-memory percentiles of bar closes
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 
+use crate::quantile::QuantileSketch;
 use rangebar_core::{AggTrade, RangeBar};
 
 /// Core streaming statistics engine optimized for range bar processing
@@ -37,6 +39,25 @@ pub struct StreamingConfig {
 
     /// T-digest compression parameter (higher = more accurate, more memory)
     pub tdigest_compression: f64,
+
+    /// Number of recent bars in the volume/range correlation window
+    #[serde(default = "default_correlation_window_size")]
+    pub correlation_window_size: usize,
+
+    /// Rank error of the bar close percentiles, as a fraction of the bar count
+    ///
+    /// 0.001 means p50 is within ±0.1% of bars of the true median. Memory grows
+    /// with `1 / quantile_epsilon` and only logarithmically with the bar count.
+    #[serde(default = "default_quantile_epsilon")]
+    pub quantile_epsilon: f64,
+}
+
+fn default_correlation_window_size() -> usize {
+    100
+}
+
+fn default_quantile_epsilon() -> f64 {
+    0.001
 }
 
 impl Default for StreamingConfig {
@@ -46,6 +67,8 @@ impl Default for StreamingConfig {
             enable_rolling_stats: true,
             rolling_window_size: 1000,
             tdigest_compression: 100.0, // Good balance of accuracy/memory
+            correlation_window_size: default_correlation_window_size(),
+            quantile_epsilon: default_quantile_epsilon(),
         }
     }
 }
@@ -76,6 +99,28 @@ pub struct BarStats {
 
     #[cfg(feature = "stats")]
     rolling_ohlc: HashMap<String, rolling_stats::Stats<f64>>,
+
+    /// Rolling correlation between bar volume and bar range
+    volume_range: RollingCorrelation,
+
+    /// Running sum of bar volume deltas, in FixedPoint units (exact)
+    cumulative_volume_delta: i128,
+
+    /// Bounded-memory sketch of bar close prices, `None` if percentiles are disabled
+    close_quantiles: Option<QuantileSketch>,
+}
+
+/// Rolling Pearson correlation over the last `window` (x, y) pairs
+///
+/// Keeps running sums so each update is O(1); memory is bounded by the window.
+struct RollingCorrelation {
+    window: usize,
+    pairs: VecDeque<(f64, f64)>,
+    sum_x: f64,
+    sum_y: f64,
+    sum_xx: f64,
+    sum_yy: f64,
+    sum_xy: f64,
 }
 
 /// Serializable statistics snapshot
@@ -96,10 +141,54 @@ pub struct StatisticsSnapshot {
     /// OHLC statistics
     pub ohlc_stats: OhlcStatistics,
 
+    /// Pearson correlation between bar `volume` and range (high - low, in
+    /// decimal bps of open) over the configured window
+    ///
+    /// `f64::NAN` when fewer than two bars are in the window or either series
+    /// has zero variance (e.g. every bar had the same volume), since the
+    /// correlation is undefined there. JSON has no NaN, so it is written as
+    /// `null` and read back as NaN.
+    #[serde(default = "undefined_correlation", deserialize_with = "nan_from_null")]
+    pub volume_range_correlation: f64,
+
+    /// Cumulative volume delta (buy minus sell volume) over all bars since
+    /// creation or the last [`StreamingStatsEngine::reset_cvd`]
+    #[serde(default)]
+    pub cumulative_volume_delta: f64,
+
+    /// Estimated percentiles of bar close prices over all bars
+    ///
+    /// `None` before the first bar or when `enable_percentiles` is off.
+    /// Accurate to [`StreamingConfig::quantile_epsilon`] in rank.
+    #[serde(default)]
+    pub close_percentiles: Option<ClosePercentiles>,
+
     /// Timestamp of snapshot
     pub timestamp: String,
 }
 
+fn undefined_correlation() -> f64 {
+    f64::NAN
+}
+
+/// Read `null` (how serde_json writes NaN) back as NaN
+fn nan_from_null<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<f64, D::Error> {
+    Ok(Option::<f64>::deserialize(deserializer)?.unwrap_or(f64::NAN))
+}
+
+/// Streaming percentiles of bar close prices
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ClosePercentiles {
+    /// Median close
+    pub p50: f64,
+
+    /// 90th percentile close
+    pub p90: f64,
+
+    /// 99th percentile close
+    pub p99: f64,
+}
+
 /// Price streaming statistics
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PriceStatistics {
@@ -183,6 +272,11 @@ impl StreamingStatsEngine {
         self.bar_stats.update(bar);
     }
 
+    /// Restart the cumulative volume delta from zero (e.g. at a session open)
+    pub fn reset_cvd(&mut self) {
+        self.bar_stats.cumulative_volume_delta = 0;
+    }
+
     /// Get current statistics snapshot (serializable)
     pub fn snapshot(&self) -> StatisticsSnapshot {
         StatisticsSnapshot {
@@ -191,6 +285,10 @@ impl StreamingStatsEngine {
             price_stats: self.trade_stats.price_statistics(),
             volume_stats: self.trade_stats.volume_statistics(),
             ohlc_stats: self.bar_stats.ohlc_statistics(),
+            volume_range_correlation: self.bar_stats.volume_range.correlation(),
+            cumulative_volume_delta: self.bar_stats.cumulative_volume_delta as f64
+                / rangebar_core::fixed_point::SCALE as f64,
+            close_percentiles: self.bar_stats.close_percentiles(),
             timestamp: chrono::Utc::now().to_rfc3339(),
         }
     }
@@ -362,7 +460,7 @@ impl TradeStats {
 
 impl BarStats {
     #[cfg(feature = "stats")]
-    fn new(_config: &StreamingConfig) -> Self {
+    fn new(config: &StreamingConfig) -> Self {
         let mut ohlc_values = HashMap::new();
         let mut rolling_ohlc = HashMap::new();
 
@@ -375,24 +473,38 @@ impl BarStats {
             count: 0,
             ohlc_values,
             rolling_ohlc,
+            volume_range: RollingCorrelation::new(config.correlation_window_size),
+            cumulative_volume_delta: 0,
+            close_quantiles: close_sketch(config),
         }
     }
 
     #[cfg(not(feature = "stats"))]
-    fn new(_config: &StreamingConfig) -> Self {
-        Self { count: 0 }
+    fn new(config: &StreamingConfig) -> Self {
+        Self {
+            count: 0,
+            volume_range: RollingCorrelation::new(config.correlation_window_size),
+            cumulative_volume_delta: 0,
+            close_quantiles: close_sketch(config),
+        }
     }
 
-    fn update(&mut self, _bar: &RangeBar) {
+    fn update(&mut self, bar: &RangeBar) {
         self.count += 1;
+        self.volume_range
+            .update(bar.volume.to_f64(), bar.range_decimal_bps());
+        self.cumulative_volume_delta += bar.volume_delta().0 as i128;
+        if let Some(sketch) = &mut self.close_quantiles {
+            sketch.observe(bar.close.to_f64());
+        }
 
         #[cfg(feature = "stats")]
         {
             let values = [
-                ("open", _bar.open.to_f64()),
-                ("high", _bar.high.to_f64()),
-                ("low", _bar.low.to_f64()),
-                ("close", _bar.close.to_f64()),
+                ("open", bar.open.to_f64()),
+                ("high", bar.high.to_f64()),
+                ("low", bar.low.to_f64()),
+                ("close", bar.close.to_f64()),
             ];
 
             for (field, value) in values {
@@ -406,6 +518,15 @@ impl BarStats {
         }
     }
 
+    fn close_percentiles(&self) -> Option<ClosePercentiles> {
+        let sketch = self.close_quantiles.as_ref()?;
+        Some(ClosePercentiles {
+            p50: sketch.quantile(0.5)?,
+            p90: sketch.quantile(0.9)?,
+            p99: sketch.quantile(0.99)?,
+        })
+    }
+
     fn ohlc_statistics(&self) -> OhlcStatistics {
         #[cfg(feature = "stats")]
         {
@@ -484,11 +605,79 @@ impl BarStats {
     }
 }
 
+fn close_sketch(config: &StreamingConfig) -> Option<QuantileSketch> {
+    config
+        .enable_percentiles
+        .then(|| QuantileSketch::new(config.quantile_epsilon))
+}
+
+impl RollingCorrelation {
+    fn new(window: usize) -> Self {
+        let window = window.max(2);
+        Self {
+            window,
+            pairs: VecDeque::with_capacity(window),
+            sum_x: 0.0,
+            sum_y: 0.0,
+            sum_xx: 0.0,
+            sum_yy: 0.0,
+            sum_xy: 0.0,
+        }
+    }
+
+    fn update(&mut self, x: f64, y: f64) {
+        if self.pairs.len() == self.window
+            && let Some((old_x, old_y)) = self.pairs.pop_front()
+        {
+            self.accumulate(old_x, old_y, -1.0);
+        }
+        self.pairs.push_back((x, y));
+        self.accumulate(x, y, 1.0);
+    }
+
+    fn accumulate(&mut self, x: f64, y: f64, sign: f64) {
+        self.sum_x += sign * x;
+        self.sum_y += sign * y;
+        self.sum_xx += sign * x * x;
+        self.sum_yy += sign * y * y;
+        self.sum_xy += sign * x * y;
+    }
+
+    /// Pearson correlation, `NAN` if undefined
+    fn correlation(&self) -> f64 {
+        let n = self.pairs.len() as f64;
+        if n < 2.0 {
+            return f64::NAN;
+        }
+        let var_x = n * self.sum_xx - self.sum_x * self.sum_x;
+        let var_y = n * self.sum_yy - self.sum_y * self.sum_y;
+        // Running sums leave rounding residue where the true variance is zero
+        if var_x <= f64::EPSILON * n * self.sum_xx || var_y <= f64::EPSILON * n * self.sum_yy {
+            return f64::NAN;
+        }
+        let cov = n * self.sum_xy - self.sum_x * self.sum_y;
+        (cov / (var_x.sqrt() * var_y.sqrt())).clamp(-1.0, 1.0)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use rangebar_core::{DataSource, FixedPoint};
 
+    fn bar_at(price: &str, volume: &str) -> RangeBar {
+        RangeBar::new(&AggTrade {
+            agg_trade_id: 1,
+            price: FixedPoint::from_str(price).unwrap(),
+            volume: FixedPoint::from_str(volume).unwrap(),
+            first_trade_id: 1,
+            last_trade_id: 1,
+            timestamp: 1_000,
+            is_buyer_maker: false,
+            is_best_match: None,
+        })
+    }
+
     #[test]
     fn test_streaming_stats_engine_creation() {
         let engine = StreamingStatsEngine::new();
@@ -544,11 +733,137 @@ mod tests {
             buy_trade_count: 20,
             sell_trade_count: 22,
             vwap: FixedPoint::from_str("50025.0").unwrap(),
+            threshold_upper: None,
+            threshold_lower: None,
+            close_reason: None,
         };
 
         engine.process_bar(&bar);
         let snapshot = engine.snapshot();
 
         assert_eq!(snapshot.bar_count, 1);
+        assert!(snapshot.volume_range_correlation.is_nan());
+    }
+
+    #[test]
+    fn test_snapshot_json_round_trip_keeps_nan_correlation() {
+        let snapshot = StreamingStatsEngine::new().snapshot();
+        let json = serde_json::to_string(&snapshot).unwrap();
+        assert!(json.contains(r#""volume_range_correlation":null"#));
+
+        let restored: StatisticsSnapshot = serde_json::from_str(&json).unwrap();
+        assert!(restored.volume_range_correlation.is_nan());
+
+        // Snapshots written before the field existed
+        let mut legacy: serde_json::Value = serde_json::from_str(&json).unwrap();
+        legacy
+            .as_object_mut()
+            .unwrap()
+            .remove("volume_range_correlation");
+        let restored: StatisticsSnapshot = serde_json::from_value(legacy).unwrap();
+        assert!(restored.volume_range_correlation.is_nan());
+    }
+
+    #[test]
+    fn test_volume_range_correlation() {
+        let mut engine = StreamingStatsEngine::with_config(StreamingConfig {
+            correlation_window_size: 20,
+            ..Default::default()
+        });
+
+        // Range grows linearly with volume; older uncorrelated bars fall out of the window
+        for i in 0..50_i64 {
+            let (volume, spread) = if i < 10 {
+                (1.0, (i % 3) as f64)
+            } else {
+                (i as f64, i as f64)
+            };
+            let mut bar = bar_at("50000.0", &format!("{volume}"));
+            bar.high = FixedPoint::from_str(&format!("{}", 50_000.0 + spread)).unwrap();
+            bar.low = FixedPoint::from_str(&format!("{}", 50_000.0 - spread)).unwrap();
+            engine.process_bar(&bar);
+        }
+
+        let correlation = engine.snapshot().volume_range_correlation;
+        assert!(
+            (correlation - 1.0).abs() < 1e-9,
+            "correlation = {correlation}"
+        );
+
+        // Constant volume has zero variance, so correlation is undefined
+        let mut flat = StreamingStatsEngine::new();
+        for _ in 0..5 {
+            flat.process_bar(&bar_at("50000.0", "1.0"));
+        }
+        assert!(flat.snapshot().volume_range_correlation.is_nan());
+    }
+
+    #[test]
+    fn test_cumulative_volume_delta() {
+        let mut engine = StreamingStatsEngine::new();
+        let bar = |buy: &str, sell: &str| {
+            let mut bar = bar_at("50000.0", "1.0");
+            bar.buy_volume = FixedPoint::from_str(buy).unwrap();
+            bar.sell_volume = FixedPoint::from_str(sell).unwrap();
+            bar
+        };
+
+        // Deltas: +1.5, -3.25, +0.5
+        engine.process_bar(&bar("2.0", "0.5"));
+        assert_eq!(engine.snapshot().cumulative_volume_delta, 1.5);
+        engine.process_bar(&bar("0.75", "4.0"));
+        engine.process_bar(&bar("1.0", "0.5"));
+        assert_eq!(engine.snapshot().cumulative_volume_delta, -1.25);
+
+        engine.reset_cvd();
+        assert_eq!(engine.snapshot().cumulative_volume_delta, 0.0);
+        engine.process_bar(&bar("3.0", "1.0"));
+        assert_eq!(engine.snapshot().cumulative_volume_delta, 2.0);
+
+        // Snapshots written before the field existed
+        let mut legacy = serde_json::to_value(engine.snapshot()).unwrap();
+        legacy
+            .as_object_mut()
+            .unwrap()
+            .remove("cumulative_volume_delta");
+        let restored: StatisticsSnapshot = serde_json::from_value(legacy).unwrap();
+        assert_eq!(restored.cumulative_volume_delta, 0.0);
+    }
+
+    #[test]
+    fn test_close_percentiles() {
+        let mut engine = StreamingStatsEngine::with_config(StreamingConfig {
+            quantile_epsilon: 0.005,
+            ..Default::default()
+        });
+        assert_eq!(engine.snapshot().close_percentiles, None);
+
+        // Closes 50_001..=60_000 in a scrambled order: uniform, median 55_000
+        for i in 0..10_000_i64 {
+            let close = 50_000 + (i * 7_919) % 10_000 + 1;
+            engine.process_bar(&bar_at(&close.to_string(), "1.0"));
+        }
+
+        // Rank error of 0.5% of 10_000 bars is 50 price units here
+        let percentiles = engine.snapshot().close_percentiles.unwrap();
+        assert!(
+            (percentiles.p50 - 55_000.0).abs() <= 51.0,
+            "{percentiles:?}"
+        );
+        assert!(
+            (percentiles.p90 - 59_000.0).abs() <= 51.0,
+            "{percentiles:?}"
+        );
+        assert!(
+            (percentiles.p99 - 59_900.0).abs() <= 51.0,
+            "{percentiles:?}"
+        );
+
+        let mut disabled = StreamingStatsEngine::with_config(StreamingConfig {
+            enable_percentiles: false,
+            ..Default::default()
+        });
+        disabled.process_bar(&bar_at("50000.0", "1.0"));
+        assert_eq!(disabled.snapshot().close_percentiles, None);
     }
 }
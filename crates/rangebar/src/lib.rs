@@ -22,7 +22,7 @@
 //! with v4.0.0. New code should depend on specific sub-crates directly:
 //!
 //! - `rangebar-core` - Core algorithm and types
-//! - `rangebar-providers` - Data providers (Binance, Exness)
+//! - `rangebar-providers` - Data providers (Binance, Exness, Kraken, OKX)
 //! - `rangebar-config` - Configuration management
 //! - `rangebar-io` - I/O operations and Polars integration
 //! - `rangebar-streaming` - Real-time streaming processor
@@ -32,7 +32,7 @@
 //! ## Features
 //!
 //! - `core` - Core algorithm (always enabled)
-//! - `providers` - Data providers (Binance, Exness)
+//! - `providers` - Data providers (Binance, Exness, Kraken, OKX)
 //! - `config` - Configuration management
 //! - `io` - I/O operations and Polars integration
 //! - `streaming` - Real-time streaming processor
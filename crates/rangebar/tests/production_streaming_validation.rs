@@ -301,6 +301,9 @@ fn create_test_bar(id: u64) -> RangeBar {
         vwap: base_price,
         buy_turnover: turnover / 2,
         sell_turnover: turnover / 2,
+        threshold_upper: None,
+        threshold_lower: None,
+        close_reason: None,
     }
 }
 